@@ -0,0 +1,168 @@
+use core::time::Duration;
+
+use bevy_ecs::{component::ComponentId, prelude::*};
+use bevy_observed_timers::prelude::*;
+use bevy_tasks::{ComputeTaskPool, TaskPool};
+use bevy_time::{Time, Timer, TimerMode};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use indexmap::IndexMap;
+
+#[derive(Component)]
+struct Cooldown;
+
+#[derive(Component)]
+struct Buff;
+
+#[derive(Component)]
+struct Regen;
+
+/// Timers stored in an [`IndexMap`], as [`Timers`] did before storing them
+/// inline, for comparison.
+#[derive(Component)]
+struct IndexTimers(IndexMap<ComponentId, Timer>);
+
+const ENTITIES: usize = 50_000;
+
+fn setup(batch_size: Option<usize>) -> (World, Schedule) {
+    ComputeTaskPool::get_or_init(TaskPool::default);
+
+    let mut world = World::new();
+    world.init_resource::<Time>();
+    world.insert_resource(TimerSettings {
+        batch_size,
+        ..Default::default()
+    });
+    for i in 0..ENTITIES {
+        let mut commands = world.commands();
+        commands
+            .spawn_empty()
+            .start_timer::<Cooldown>(Timer::from_seconds(
+                1. + i as f32 * 0.001,
+                TimerMode::Repeating,
+            ))
+            .start_timer::<Buff>(Timer::from_seconds(3600., TimerMode::Once));
+    }
+    world.flush();
+
+    let mut schedule = Schedule::default();
+    schedule.add_systems(tick_entity_timers);
+    (world, schedule)
+}
+
+fn tick(c: &mut Criterion) {
+    let mut group = c.benchmark_group("tick_entity_timers");
+    for batch_size in [None, Some(64), Some(1024), Some(16384)] {
+        let (mut world, mut schedule) = setup(batch_size);
+        let label = batch_size.map_or("auto".to_string(), |size| size.to_string());
+        group.bench_function(BenchmarkId::new("batch_size", label), |b| {
+            b.iter(|| {
+                world
+                    .resource_mut::<Time>()
+                    .advance_by(Duration::from_millis(16));
+                schedule.run(&mut world);
+            });
+        });
+    }
+    group.finish();
+}
+
+/// Ticks entities holding one, two and three timers, the common case.
+fn few_timers(c: &mut Criterion) {
+    ComputeTaskPool::get_or_init(TaskPool::default);
+
+    let mut group = c.benchmark_group("few_timers");
+    for timers in 1..=3 {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        for _ in 0..ENTITIES {
+            let mut commands = world.commands();
+            let mut entity = commands.spawn_empty();
+            entity.start_timer::<Cooldown>(Timer::from_seconds(3600., TimerMode::Once));
+            if timers >= 2 {
+                entity.start_timer::<Buff>(Timer::from_seconds(3600., TimerMode::Once));
+            }
+            if timers >= 3 {
+                entity.start_timer::<Regen>(Timer::from_seconds(3600., TimerMode::Once));
+            }
+        }
+        world.flush();
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(tick_entity_timers);
+        group.bench_function(BenchmarkId::new("tick", timers), |b| {
+            b.iter(|| {
+                world
+                    .resource_mut::<Time>()
+                    .advance_by(Duration::from_millis(16));
+                schedule.run(&mut world);
+            });
+        });
+
+        let regen = world.register_component::<Regen>();
+        let mut query = world.query::<&Timers>();
+        group.bench_function(BenchmarkId::new("get", timers), |b| {
+            b.iter(|| {
+                for timers in query.iter(&world) {
+                    black_box(timers.get(regen));
+                }
+            });
+        });
+
+        let mut query = world.query::<&mut Timers>();
+        group.bench_function(BenchmarkId::new("iter_mut", timers), |b| {
+            b.iter(|| {
+                for mut timers in query.iter_mut(&mut world) {
+                    for (_, timer) in timers.iter_mut() {
+                        timer.tick(Duration::from_millis(16));
+                    }
+                }
+            });
+        });
+    }
+    group.finish();
+}
+
+/// Baseline for [`few_timers`], storing the same timers in an [`IndexMap`].
+fn few_timers_indexmap(c: &mut Criterion) {
+    let mut group = c.benchmark_group("few_timers_indexmap");
+    for timers in 1..=3 {
+        let mut world = World::new();
+        let ids = [
+            world.register_component::<Cooldown>(),
+            world.register_component::<Buff>(),
+            world.register_component::<Regen>(),
+        ];
+        for _ in 0..ENTITIES {
+            let map = ids[..timers]
+                .iter()
+                .map(|&id| (id, Timer::from_seconds(3600., TimerMode::Once)))
+                .collect();
+            world.spawn(IndexTimers(map));
+        }
+
+        let regen = ids[2];
+        let mut query = world.query::<&IndexTimers>();
+        group.bench_function(BenchmarkId::new("get", timers), |b| {
+            b.iter(|| {
+                for timers in query.iter(&world) {
+                    black_box(timers.0.get(&regen));
+                }
+            });
+        });
+
+        let mut query = world.query::<&mut IndexTimers>();
+        group.bench_function(BenchmarkId::new("iter_mut", timers), |b| {
+            b.iter(|| {
+                for mut timers in query.iter_mut(&mut world) {
+                    for timer in timers.0.values_mut() {
+                        timer.tick(Duration::from_millis(16));
+                    }
+                }
+            });
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, tick, few_timers, few_timers_indexmap);
+criterion_main!(benches);
@@ -0,0 +1,126 @@
+//! Stores the per-timer finish callbacks of
+//! [`EntityCommandTimersExt::start_timer_with_callback`].
+//!
+//! [`EntityCommandTimersExt::start_timer_with_callback`]: crate::command::EntityCommandTimersExt::start_timer_with_callback
+
+use bevy_ecs::{
+    component::ComponentId,
+    entity::Entity,
+    observer::Trigger,
+    system::{ResMut, Resource},
+    world::World,
+};
+use bevy_time::TimerMode;
+use bevy_utils::{synccell::SyncCell, HashMap};
+
+use crate::{core::Timers, event::OnTimerCancelled};
+
+/// A callback run when a timer finishes.
+pub(crate) type TimerCallback = Box<dyn FnMut(&mut World, Entity) + Send>;
+
+/// [`Resource`] holding the callbacks of timers, by entity and tag.
+#[derive(Resource, Default)]
+struct TimerCallbacks(HashMap<(Entity, ComponentId), SyncCell<TimerCallback>>);
+
+/// Sets the callback of a timer, replacing any previous one.
+pub(crate) fn insert(
+    world: &mut World,
+    entity: Entity,
+    component: ComponentId,
+    callback: TimerCallback,
+) {
+    if !world.contains_resource::<TimerCallbacks>() {
+        world.init_resource::<TimerCallbacks>();
+        world.add_observer(
+            |t: Trigger<OnTimerCancelled>, mut callbacks: ResMut<TimerCallbacks>| {
+                for &component in t.components() {
+                    callbacks.0.remove(&(t.entity(), component));
+                }
+            },
+        );
+    }
+    world
+        .resource_mut::<TimerCallbacks>()
+        .0
+        .insert((entity, component), SyncCell::new(callback));
+}
+
+/// Drops the callback of a timer, if any.
+pub(crate) fn remove(world: &mut World, entity: Entity, component: ComponentId) {
+    if let Some(mut callbacks) = world.get_resource_mut::<TimerCallbacks>() {
+        callbacks.0.remove(&(entity, component));
+    }
+}
+
+/// Moves the callback of the timer `from` to the timer `to`, replacing the
+/// callback of `to`, or dropping it if `from` has none.
+pub(crate) fn transfer(world: &mut World, from: (Entity, ComponentId), to: (Entity, ComponentId)) {
+    let Some(mut callbacks) = world.get_resource_mut::<TimerCallbacks>() else {
+        return;
+    };
+    match callbacks.0.remove(&from) {
+        Some(callback) => {
+            callbacks.0.insert(to, callback);
+        }
+        None => {
+            callbacks.0.remove(&to);
+        }
+    }
+}
+
+/// Exchanges the callbacks of the timers `a` and `b` of an entity.
+pub(crate) fn swap(world: &mut World, entity: Entity, a: ComponentId, b: ComponentId) {
+    let Some(mut callbacks) = world.get_resource_mut::<TimerCallbacks>() else {
+        return;
+    };
+    let callback_a = callbacks.0.remove(&(entity, a));
+    let callback_b = callbacks.0.remove(&(entity, b));
+    if let Some(callback) = callback_a {
+        callbacks.0.insert((entity, b), callback);
+    }
+    if let Some(callback) = callback_b {
+        callbacks.0.insert((entity, a), callback);
+    }
+}
+
+/// Runs the callbacks of the given timers of an entity, which finished this
+/// tick.
+pub(crate) fn run_finished(world: &mut World, entity: Entity, components: &[ComponentId]) {
+    if !world.contains_resource::<TimerCallbacks>() {
+        return;
+    }
+    for &component in components {
+        run(world, (entity, component));
+    }
+}
+
+/// Runs the callback of a timer that finished, keeping it for the next finish
+/// unless the timer will not finish again.
+fn run(world: &mut World, (entity, component): (Entity, ComponentId)) {
+    let Some(mut callbacks) = world.get_resource_mut::<TimerCallbacks>() else {
+        return;
+    };
+    let Some(callback) = callbacks.0.remove(&(entity, component)) else {
+        return;
+    };
+    if world.get_entity(entity).is_err() {
+        return;
+    }
+    let mut callback = SyncCell::to_inner(callback);
+    callback(world, entity);
+
+    let repeats = world
+        .get::<Timers>(entity)
+        .and_then(|timers| timers.get(component))
+        .is_some_and(|timer| timer.mode() == TimerMode::Repeating || !timer.finished());
+    if !repeats {
+        return;
+    }
+    if let Some(mut callbacks) = world.get_resource_mut::<TimerCallbacks>() {
+        // Unless the callback set a new one.
+        callbacks
+            .0
+            .entry((entity, component))
+            .or_insert_with(|| SyncCell::new(callback));
+    }
+}
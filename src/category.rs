@@ -0,0 +1,82 @@
+//! Provides timer categories: named sets of timer tags, such as buffs and
+//! debuffs, whose timers can be cancelled together.
+
+use bevy_ecs::{
+    component::{Component, ComponentId},
+    system::Resource,
+    world::World,
+};
+use bevy_utils::{HashMap, HashSet};
+
+/// Identifies a category of timer tags, see [`TimerCategories`].
+///
+/// Categories are usually declared as constants:
+///
+/// ```
+/// # use bevy_observed_timers::category::TimerCategory;
+/// const DEBUFF: TimerCategory = TimerCategory("debuff");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TimerCategory(pub &'static str);
+
+/// [`Resource`] mapping timer tags to the [`TimerCategory`]s they belong to.
+///
+/// Tags are distinct types, so this crate cannot tell on its own which ones
+/// belong together: each tag must be registered in its categories with
+/// [`TimerCategories::register`], or `App::tag_category` with the `bevy_app`
+/// feature, before
+/// [`cancel_category`](crate::command::EntityCommandTimersExt::cancel_category)
+/// can find its timers. A tag can belong to several categories.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_observed_timers::{category::{TimerCategories, TimerCategory}, prelude::*};
+/// const DEBUFF: TimerCategory = TimerCategory("debuff");
+///
+/// #[derive(Component)]
+/// struct Poisoned;
+/// #[derive(Component)]
+/// struct Slowed;
+///
+/// # let mut world = World::new();
+/// TimerCategories::register::<Poisoned>(&mut world, DEBUFF);
+/// TimerCategories::register::<Slowed>(&mut world, DEBUFF);
+///
+/// # let e1 = world.spawn_empty().id();
+/// // Cleanse: cancels both the poison and the slow.
+/// world.commands().entity(e1).cancel_category(DEBUFF);
+/// ```
+#[derive(Resource, Default)]
+pub struct TimerCategories {
+    tags: HashMap<TimerCategory, HashSet<ComponentId>>,
+}
+
+impl TimerCategories {
+    /// Adds the timer tag `T` to the given category, and returns the
+    /// [`ComponentId`] of `T`.
+    pub fn register<T: Component>(world: &mut World, category: TimerCategory) -> ComponentId {
+        let tag = world.register_component::<T>();
+        world
+            .get_resource_or_init::<TimerCategories>()
+            .tags
+            .entry(category)
+            .or_default()
+            .insert(tag);
+        tag
+    }
+
+    /// Returns `true` if the given timer tag belongs to the category.
+    pub fn contains(&self, category: TimerCategory, tag: ComponentId) -> bool {
+        self.tags
+            .get(&category)
+            .is_some_and(|tags| tags.contains(&tag))
+    }
+
+    /// Returns an iterator over the timer tags of the category, in no
+    /// particular order.
+    pub fn tags(&self, category: TimerCategory) -> impl Iterator<Item = ComponentId> + '_ {
+        self.tags.get(&category).into_iter().flatten().copied()
+    }
+}
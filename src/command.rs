@@ -1,16 +1,58 @@
 //! [`EntityCommand`]s for managing [`Timer`]s on entities.
+//!
+//! Each method of [`EntityCommandTimersExt`] queues one of the public command
+//! types of this module, such as [`StartTimer`] or [`CancelTimer`]. They can
+//! also be built and queued directly, which is useful to build higher-level
+//! commands on top of them, or to pass them around before picking the entity.
+//!
+//! # Example
+//!
+//! ```
+//! # use bevy_ecs::prelude::*;
+//! # use bevy_ecs::system::EntityCommand;
+//! # use bevy_observed_timers::{command::{CancelTimer, StartTimer}, prelude::*};
+//! # use bevy_time::prelude::*;
+//! # #[derive(Component)]
+//! # struct Stun;
+//! # let mut world = World::new();
+//! # let mut commands = world.commands();
+//! # let e1 = commands.spawn_empty().id();
+//! # let e2 = commands.spawn_empty().id();
+//! // Same as `start_timer_delayed::<Stun>(..)`.
+//! let start = StartTimer::<Stun>::new(Timer::from_seconds(2., TimerMode::Once))
+//!     .delayed(std::time::Duration::from_millis(500));
+//! commands.entity(e1).queue(start);
+//!
+//! // Entity commands bound to an entity are regular commands.
+//! commands.queue(CancelTimer::<Stun>::default().with_entity(e2));
+//! # world.flush();
+//! # assert!(world.get::<Timers>(e1).is_some());
+//! ```
 
-use core::marker::PhantomData;
+use core::{marker::PhantomData, time::Duration};
+use std::collections::HashSet;
 
 use bevy_ecs::{
-    component::Component,
+    component::{Component, ComponentId},
     entity::Entity,
-    system::{EntityCommand, EntityCommands},
-    world::World,
+    observer::{Observer, Trigger},
+    system::{Commands, EntityCommand, EntityCommands, Query, Resource},
+    world::{OnAdd, OnRemove, World},
 };
-use bevy_time::Timer;
+use bevy_time::{Time, Timer, TimerMode};
+use bevy_utils::tracing::warn;
 
-use crate::{core::Timers, event::OnTimerCancelled, TargetBoth};
+use crate::{
+    callback::{self, TimerCallback},
+    category::{TimerCategories, TimerCategory},
+    cooldown::CooldownGroups,
+    core::{Boost, StartDelay, TimerClock, TimerEntry, TimerProgress, Timers},
+    event::{CancelReason, OnTimerCancelled, OnTimerStarted},
+    lifecycle,
+    settings::TimerSettings,
+    targets::TargetBoth,
+    tracking,
+};
 
 /// [`EntityCommands`] extension trait that provides methods for starting,
 /// resetting, pausing, unpausing, and cancelling timers on entities.
@@ -19,6 +61,7 @@ pub trait EntityCommandTimersExt {
     /// a tag to identify the timer.
     ///
     /// If a [`Timer`] with the same tag already exists, it will be replaced.
+    /// Triggers [`OnTimerStarted`] once the timer is inserted.
     ///
     /// # Example
     ///
@@ -36,11 +79,18 @@ pub trait EntityCommandTimersExt {
     /// ```
     fn start_timer<T: Component>(&mut self, timer: Timer) -> &mut Self;
 
-    /// Reset a [`Timer`] on the target entity. The [`Component`] `T` is used as
-    /// a tag to identify the timer.
+    /// Start a [`Timer`] lasting the given number of seconds on the target
+    /// entity. The [`Component`] `T` is used as a tag to identify the timer.
     ///
-    /// If the timer does not exist, this command does nothing.
-    /// Calls [`Timer::reset`] on the timer.
+    /// Behaves like [`start_timer`](Self::start_timer), but accepts a
+    /// computed duration that may be invalid, which
+    /// [`Timer::from_seconds`] would panic on:
+    /// - `NaN` is rejected with a warning, and no timer is started.
+    /// - Negative durations are clamped to zero.
+    /// - Durations too large to be represented saturate to [`Duration::MAX`].
+    ///
+    /// Passing `None` as the mode uses [`TimerSettings::default_mode`], which
+    /// is [`TimerMode::Once`] unless configured otherwise.
     ///
     /// # Example
     ///
@@ -49,19 +99,32 @@ pub trait EntityCommandTimersExt {
     /// # use bevy_observed_timers::prelude::*;
     /// # use bevy_time::prelude::*;
     /// # #[derive(Component)]
-    /// # struct Regenerate;
+    /// # struct Respawn;
     /// # let mut world = World::new();
     /// # let mut commands = world.commands();
     /// # let e1 = commands.spawn_empty().id();
-    /// commands.entity(e1).reset_timer::<Regenerate>();
+    /// # let level = 3.;
+    /// commands.entity(e1)
+    ///     .start_timer_secs::<Respawn>(10. - level * 2., TimerMode::Once);
     /// ```
-    fn reset_timer<T: Component>(&mut self) -> &mut Self;
+    fn start_timer_secs<T: Component>(
+        &mut self,
+        seconds: f32,
+        mode: impl Into<Option<TimerMode>>,
+    ) -> &mut Self;
 
-    /// Pause a [`Timer`] on the target entity. The [`Component`] `T` is used as
-    /// a tag to identify the timer.
+    /// Start a [`Timer`] lasting the given number of milliseconds on the
+    /// target entity. The [`Component`] `T` is used as a tag to identify the
+    /// timer.
     ///
-    /// If the timer does not exist, this command does nothing.
-    /// Calls [`Timer::pause`] on the timer.
+    /// Behaves like [`start_timer`](Self::start_timer). The duration is built
+    /// with [`Duration::from_millis`], avoiding float rounding entirely,
+    /// unlike [`Timer::from_seconds`]. Timers are ticked with [`Duration`]s, so
+    /// given identical integer deltas, such as a fixed timestep, they finish
+    /// on the same tick on every platform, as lockstep simulations require.
+    ///
+    /// Passing `None` as the mode uses [`TimerSettings::default_mode`], as
+    /// with [`start_timer_secs`](Self::start_timer_secs).
     ///
     /// # Example
     ///
@@ -70,19 +133,208 @@ pub trait EntityCommandTimersExt {
     /// # use bevy_observed_timers::prelude::*;
     /// # use bevy_time::prelude::*;
     /// # #[derive(Component)]
-    /// # struct Regenerate;
+    /// # struct Attack;
     /// # let mut world = World::new();
     /// # let mut commands = world.commands();
     /// # let e1 = commands.spawn_empty().id();
-    /// commands.entity(e1).pause_timer::<Regenerate>();
+    /// commands.entity(e1).start_timer_millis::<Attack>(1250, TimerMode::Repeating);
     /// ```
-    fn pause_timer<T: Component>(&mut self) -> &mut Self;
+    fn start_timer_millis<T: Component>(
+        &mut self,
+        millis: u64,
+        mode: impl Into<Option<TimerMode>>,
+    ) -> &mut Self;
 
-    /// Unpause a [`Timer`] on the target entity. The [`Component`] `T` is used
-    /// as a tag to identify the timer.
+    /// Start a persistent [`Timer`] on the target entity. The [`Component`]
+    /// `T` is used as a tag to identify the timer.
     ///
-    /// If the timer does not exist, this command does nothing.
-    /// Calls [`Timer::unpause`] on the timer.
+    /// Behaves like [`start_timer`](Self::start_timer), except that a
+    /// [`TimerMode::Once`] timer is kept in [`Timers`] after it finishes,
+    /// instead of being removed. Its finished state is preserved, so
+    /// [`Timer::finished`] keeps returning `true`, and it does not trigger
+    /// [`OnTimerFinished`] again.
+    ///
+    /// Persistent timers stay in memory until they are cancelled, so they
+    /// must be cleaned up manually with [`cancel_timer`](Self::cancel_timer).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_observed_timers::prelude::*;
+    /// # use bevy_time::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Intro;
+    /// # let mut world = World::new();
+    /// # let mut commands = world.commands();
+    /// # let e1 = commands.spawn_empty().id();
+    /// commands.entity(e1)
+    ///     .start_timer_persistent::<Intro>(Timer::from_seconds(5., TimerMode::Once));
+    /// ```
+    ///
+    /// [`TimerMode::Once`]: bevy_time::TimerMode::Once
+    /// [`OnTimerFinished`]: crate::event::OnTimerFinished
+    fn start_timer_persistent<T: Component>(&mut self, timer: Timer) -> &mut Self;
+
+    /// Start a gated [`Timer`] on the target entity. The [`Component`] `T` is
+    /// used as a tag to identify the timer.
+    ///
+    /// Behaves like [`start_timer`](Self::start_timer), except that the timer
+    /// only ticks while the entity has the [`Component`] `G`, and is frozen
+    /// otherwise. Manually pausing the timer takes precedence: a paused timer
+    /// stays frozen even while `G` is present.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_observed_timers::prelude::*;
+    /// # use bevy_time::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct StunDamage;
+    /// # #[derive(Component)]
+    /// # struct Stunned;
+    /// # let mut world = World::new();
+    /// # let mut commands = world.commands();
+    /// # let e1 = commands.spawn_empty().id();
+    /// commands.entity(e1)
+    ///     .start_timer_gated::<StunDamage, Stunned>(Timer::from_seconds(1., TimerMode::Repeating));
+    /// ```
+    fn start_timer_gated<T: Component, G: Component>(&mut self, timer: Timer) -> &mut Self;
+
+    /// Start a [`Timer`] with a cooldown gap on the target entity. The
+    /// [`Component`] `T` is used as a tag to identify the timer.
+    ///
+    /// The timer alternates between an active phase lasting `active` and a gap
+    /// phase lasting `gap`. [`OnTimerFinished`] is triggered at the end of each
+    /// active phase, after which the gap must elapse before the next active
+    /// phase starts. It repeats until cancelled.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_observed_timers::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Burst;
+    /// # let mut world = World::new();
+    /// # let mut commands = world.commands();
+    /// # let e1 = commands.spawn_empty().id();
+    /// // Fires at 1s, 4s, 7s, ...
+    /// commands.entity(e1)
+    ///     .start_timer_with_gap::<Burst>(Duration::from_secs(1), Duration::from_secs(2));
+    /// ```
+    ///
+    /// [`OnTimerFinished`]: crate::event::OnTimerFinished
+    fn start_timer_with_gap<T: Component>(&mut self, active: Duration, gap: Duration) -> &mut Self;
+
+    /// Start a repeating [`Timer`] on the target entity that finishes on
+    /// multiples of `period` of the total elapsed [`Time`]. The [`Component`]
+    /// `T` is used as a tag to identify the timer.
+    ///
+    /// When the command is applied at an elapsed time `t`, the timer starts
+    /// with `t % period` already elapsed, so that its first finish lands on
+    /// the next multiple of `period`, and every following finish on the next
+    /// ones. Starting exactly on a multiple finishes a whole `period` later.
+    /// Without a [`Time`] resource, or with a zero `period`, the timer is not
+    /// aligned.
+    ///
+    /// Alignment is computed once, when starting. Pausing the [`Time`] clock
+    /// keeps the timer aligned, since both stop advancing, but pausing the
+    /// timer itself, gating it, or pausing all timers with [`TimersPaused`]
+    /// shifts its finishes by the paused time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_observed_timers::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Heartbeat;
+    /// # let mut world = World::new();
+    /// # let mut commands = world.commands();
+    /// # let e1 = commands.spawn_empty().id();
+    /// // Fires on every whole second of elapsed time.
+    /// commands.entity(e1).start_timer_aligned::<Heartbeat>(Duration::from_secs(1));
+    /// ```
+    ///
+    /// [`Time`]: bevy_time::Time
+    /// [`TimersPaused`]: crate::core::TimersPaused
+    fn start_timer_aligned<T: Component>(&mut self, period: Duration) -> &mut Self;
+
+    /// Start a [`Timer`] on the target entity that repeats a limited number of
+    /// times. The [`Component`] `T` is used as a tag to identify the timer.
+    ///
+    /// The timer is set to [`TimerMode::Repeating`], finishes `repetitions`
+    /// times, and is then removed like a finished one-shot timer. Each
+    /// [`OnTimerFinished`] reports the number of finishes left in
+    /// [`OnTimerFinished::repetitions_remaining`], down to `Some(0)` for the
+    /// last one. A `repetitions` of `0` is treated as `1`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_observed_timers::prelude::*;
+    /// # use bevy_time::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Bleed;
+    /// # let mut world = World::new();
+    /// # let mut commands = world.commands();
+    /// # let e1 = commands.spawn_empty().id();
+    /// // Deals damage 5 times, once per second.
+    /// commands.entity(e1)
+    ///     .start_timer_repeating_n::<Bleed>(Timer::from_seconds(1., TimerMode::Repeating), 5);
+    /// ```
+    ///
+    /// [`OnTimerFinished`]: crate::event::OnTimerFinished
+    /// [`OnTimerFinished::repetitions_remaining`]: crate::event::OnTimerFinished::repetitions_remaining
+    fn start_timer_repeating_n<T: Component>(
+        &mut self,
+        timer: Timer,
+        repetitions: u32,
+    ) -> &mut Self;
+
+    /// Start a [`Timer`] linked to its tag on the target entity. The
+    /// [`Component`] `T` is used as a tag to identify the timer.
+    ///
+    /// Behaves like [`start_timer`](Self::start_timer), except that the timer
+    /// is cancelled, triggering [`OnTimerCancelled`] with
+    /// [`CancelReason::TagRemoved`], when the [`Component`] `T` is removed from
+    /// the entity. The cancellation is applied when commands are flushed. If
+    /// the entity is despawned, the reason is [`CancelReason::Despawn`]
+    /// instead, like for any other timer.
+    /// The link lasts as long as the timer: restarting the timer with
+    /// [`start_timer`](Self::start_timer) replaces it with an unlinked one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_observed_timers::prelude::*;
+    /// # use bevy_time::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Burning;
+    /// # let mut world = World::new();
+    /// # let mut commands = world.commands();
+    /// # let e1 = commands.spawn(Burning).id();
+    /// commands.entity(e1)
+    ///     .start_timer_linked::<Burning>(Timer::from_seconds(1., TimerMode::Repeating));
+    /// // Later, removing `Burning` also cancels the timer.
+    /// commands.entity(e1).remove::<Burning>();
+    /// ```
+    fn start_timer_linked<T: Component>(&mut self, timer: Timer) -> &mut Self;
+
+    /// Start a [`Timer`] with a dispatch priority on the target entity. The
+    /// [`Component`] `T` is used as a tag to identify the timer.
+    ///
+    /// Behaves like [`start_timer`](Self::start_timer), except that when
+    /// several timers of the entity finish during the same tick, their
+    /// [`OnTimerFinished`] triggers run from the highest to the lowest
+    /// priority. Timers started without a priority have priority `0`, and
+    /// timers with equal priorities are dispatched in insertion order.
     ///
     /// # Example
     ///
@@ -91,19 +343,289 @@ pub trait EntityCommandTimersExt {
     /// # use bevy_observed_timers::prelude::*;
     /// # use bevy_time::prelude::*;
     /// # #[derive(Component)]
+    /// # struct Death;
+    /// # let mut world = World::new();
+    /// # let mut commands = world.commands();
+    /// # let e1 = commands.spawn_empty().id();
+    /// // Handled before any other timer finishing on the same tick.
+    /// commands.entity(e1)
+    ///     .start_timer_with_priority::<Death>(Timer::from_seconds(3., TimerMode::Once), 100);
+    /// ```
+    ///
+    /// [`OnTimerFinished`]: crate::event::OnTimerFinished
+    fn start_timer_with_priority<T: Component>(&mut self, timer: Timer, priority: i32)
+        -> &mut Self;
+
+    /// Start a [`Timer`] ticked by the given [`TimerClock`] on the target
+    /// entity. The [`Component`] `T` is used as a tag to identify the timer.
+    ///
+    /// Behaves like [`start_timer`](Self::start_timer). The clock only
+    /// matters when timers are ticked per clock, see
+    /// [`tick_entity_timers_on_clock`]. Timers started otherwise use
+    /// [`TimerClock::Virtual`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_observed_timers::{core::TimerClock, prelude::*};
+    /// # use bevy_time::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct MenuFade;
+    /// # let mut world = World::new();
+    /// # let mut commands = world.commands();
+    /// # let e1 = commands.spawn_empty().id();
+    /// // Keeps fading while the game is paused.
+    /// commands.entity(e1).start_timer_on_clock::<MenuFade>(
+    ///     Timer::from_seconds(0.5, TimerMode::Once),
+    ///     TimerClock::Real,
+    /// );
+    /// ```
+    ///
+    /// [`tick_entity_timers_on_clock`]: crate::core::tick_entity_timers_on_clock
+    fn start_timer_on_clock<T: Component>(&mut self, timer: Timer, clock: TimerClock) -> &mut Self;
+
+    /// Start every timer of a [`TimerBundle`] on the target entity, in a
+    /// single command.
+    ///
+    /// Each timer is started as by its [`StartTimer`] command, in order, so
+    /// the typed group can be defined once and reused, for example as the
+    /// timers every spawned unit starts with.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_observed_timers::{command::StartTimer, prelude::*};
+    /// # use bevy_time::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct SpawnShield;
+    /// # #[derive(Component)]
     /// # struct Regenerate;
     /// # let mut world = World::new();
     /// # let mut commands = world.commands();
     /// # let e1 = commands.spawn_empty().id();
-    /// commands.entity(e1).unpause_timer::<Regenerate>();
+    /// let loadout = || {
+    ///     (
+    ///         StartTimer::<SpawnShield>::new(Timer::from_seconds(3., TimerMode::Once)),
+    ///         StartTimer::<Regenerate>::new(Timer::from_seconds(1., TimerMode::Repeating)),
+    ///     )
+    /// };
+    /// commands.entity(e1).start_timer_bundle(loadout());
     /// ```
-    fn unpause_timer<T: Component>(&mut self) -> &mut Self;
+    fn start_timer_bundle<B: TimerBundle>(&mut self, bundle: B) -> &mut Self;
 
-    /// Cancel a [`Timer`] on the target entity. The [`Component`] `T` is used
-    /// as a tag to identify the timer.
+    /// Start a [`Timer`] whose progress is tracked in [`TimerProgress`] on the
+    /// target entity. The [`Component`] `T` is used as a tag to identify the
+    /// timer.
+    ///
+    /// Behaves like [`start_timer`](Self::start_timer), and also records the
+    /// timer's [`Timer::fraction`] in [`TimerProgress`], rounded down to a
+    /// multiple of `step`. The component is only changed when the rounded
+    /// progress changes, so with a `step` of `0.05` it changes at most 20
+    /// times over the timer's duration. `step` is clamped between
+    /// [`f32::EPSILON`] and `1.0`, and `NaN` is treated as `1.0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_observed_timers::prelude::*;
+    /// # use bevy_time::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Fireball;
+    /// # let mut world = World::new();
+    /// # let mut commands = world.commands();
+    /// # let e1 = commands.spawn_empty().id();
+    /// commands.entity(e1)
+    ///     .start_timer_tracked::<Fireball>(Timer::from_seconds(8., TimerMode::Once), 0.05);
+    ///
+    /// fn redraw_cooldowns(changed: Query<&TimerProgress, Changed<TimerProgress>>) {
+    ///     // ...
+    /// }
+    /// ```
+    fn start_timer_tracked<T: Component>(&mut self, timer: Timer, step: f32) -> &mut Self;
+
+    /// Start a [`Timer`] that triggers [`OnTimerThreshold`] as it crosses
+    /// each of the given fractions of its duration. The [`Component`] `T` is
+    /// used as a tag to identify the timer.
+    ///
+    /// Behaves like [`start_timer`](Self::start_timer). A threshold is crossed
+    /// once the timer's [`Timer::fraction`] reaches it, so a threshold of
+    /// `1.0` is crossed when the timer finishes, and its event is triggered
+    /// before the timer's [`OnTimerFinished`]. A delta crossing several
+    /// thresholds at once triggers an event for each of them, in ascending
+    /// order. Repeating timers cross their thresholds again on every cycle,
+    /// except for whole cycles skipped by a single large delta.
+    ///
+    /// Thresholds are clamped between [`f32::EPSILON`] and `1.0`, and `NaN`
+    /// thresholds are ignored. Timers with a cooldown gap never cross
+    /// thresholds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_observed_timers::prelude::*;
+    /// # use bevy_time::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct ChargeUp;
+    /// # let mut world = World::new();
+    /// # let mut commands = world.commands();
+    /// # let e1 = commands.spawn_empty().id();
+    /// // Half charged after 1 second, fully charged after 2.
+    /// commands.entity(e1).start_timer_with_thresholds::<ChargeUp>(
+    ///     Timer::from_seconds(2., TimerMode::Once),
+    ///     vec![0.5, 1.0],
+    /// );
+    /// ```
+    ///
+    /// [`OnTimerThreshold`]: crate::event::OnTimerThreshold
+    /// [`OnTimerFinished`]: crate::event::OnTimerFinished
+    fn start_timer_with_thresholds<T: Component>(
+        &mut self,
+        timer: Timer,
+        thresholds: Vec<f32>,
+    ) -> &mut Self;
+
+    /// Start a [`Timer`] that triggers [`OnTimerSecondTick`] each time its
+    /// remaining whole seconds decrease, such as for a countdown. The
+    /// [`Component`] `T` is used as a tag to identify the timer.
+    ///
+    /// Behaves like [`start_timer`](Self::start_timer). A delta skipping
+    /// several seconds at once triggers an event for each of them, in
+    /// descending order. No event is triggered for zero: the last one of a
+    /// cycle reports `1`, and the finish is reported by [`OnTimerFinished`].
+    /// Repeating timers count down again on every cycle, except for whole
+    /// cycles skipped by a single large delta. Timers with a cooldown gap
+    /// never trigger the event.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_observed_timers::prelude::*;
+    /// # use bevy_time::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Countdown;
+    /// # let mut world = World::new();
+    /// # let mut commands = world.commands();
+    /// # let e1 = commands.spawn_empty().id();
+    /// // Beeps at 3, 2 and 1, then finishes.
+    /// commands
+    ///     .entity(e1)
+    ///     .start_timer_with_second_ticks::<Countdown>(Timer::from_seconds(4., TimerMode::Once));
+    /// ```
+    ///
+    /// [`OnTimerSecondTick`]: crate::event::OnTimerSecondTick
+    /// [`OnTimerFinished`]: crate::event::OnTimerFinished
+    fn start_timer_with_second_ticks<T: Component>(&mut self, timer: Timer) -> &mut Self;
+
+    /// Start a [`Timer`] on the target entity after a delay. The [`Component`]
+    /// `T` is used as a tag to identify the timer.
+    ///
+    /// Behaves like [`start_timer`](Self::start_timer), except that the timer
+    /// only starts ticking once `delay` has elapsed, and [`OnTimerStarted`] is
+    /// triggered at that point rather than when the command is applied. The
+    /// part of the tick that exceeds the delay is carried into the timer, so a
+    /// large delta can both end the delay and finish the timer. The delay
+    /// counts down like the timer would: it is frozen while the timer is
+    /// paused or gated. A zero delay starts the timer on the next tick.
+    ///
+    /// The timer is stored in [`Timers`] during the delay, and a timer with
+    /// the same tag is replaced right away.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_observed_timers::prelude::*;
+    /// # use bevy_time::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Spawner;
+    /// # let mut world = World::new();
+    /// # let mut commands = world.commands();
+    /// # let e1 = commands.spawn_empty().id();
+    /// // Fires at 7s, 12s, 17s, ...
+    /// commands.entity(e1).start_timer_delayed::<Spawner>(
+    ///     Duration::from_secs(2),
+    ///     Timer::from_seconds(5., TimerMode::Repeating),
+    /// );
+    /// ```
+    fn start_timer_delayed<T: Component>(&mut self, delay: Duration, timer: Timer) -> &mut Self;
+
+    /// Start a [`Timer`] on the target entity that removes its tag when it
+    /// finishes. The [`Component`] `T` is used as a tag to identify the timer.
+    ///
+    /// Behaves like [`start_timer`](Self::start_timer), except that `T` is
+    /// removed from the entity each time the timer finishes, automating the
+    /// "effect expires, remove its marker" pattern. The removal is queued by
+    /// the tick system after the finish events, whichever
+    /// [`FinishedEvents`] are triggered, so observers of [`OnTimerFinished`]
+    /// still see the tag.
+    ///
+    /// Repeating timers keep running and remove the tag on every finish,
+    /// including finishes after the tag was added back, which is rarely
+    /// wanted: prefer one-shot timers.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_observed_timers::prelude::*;
+    /// # use bevy_time::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Stunned;
+    /// # let mut world = World::new();
+    /// # let mut commands = world.commands();
+    /// // `Stunned` is removed after 2 seconds.
+    /// commands.spawn(Stunned)
+    ///     .start_timer_removing_tag::<Stunned>(Timer::from_seconds(2., TimerMode::Once));
+    /// ```
+    ///
+    /// [`OnTimerFinished`]: crate::event::OnTimerFinished
+    /// [`FinishedEvents`]: crate::settings::FinishedEvents
+    fn start_timer_removing_tag<T: Component>(&mut self, timer: Timer) -> &mut Self;
+
+    /// Start a [`Timer`] on the target entity, along with the shared cooldown
+    /// of the tag's group. The [`Component`] `T` is used as a tag to identify
+    /// the timer.
+    ///
+    /// Behaves like [`start_timer`](Self::start_timer). If `T` is a member of
+    /// a group in [`CooldownGroups`], the group's shared cooldown timer is
+    /// also (re)started for the group's duration, putting every member of the
+    /// group on cooldown.
+    ///
+    /// See [`CooldownGroups`] for an example.
+    fn start_cooldown<T: Component>(&mut self, timer: Timer) -> &mut Self;
+
+    /// Start the shared cooldown timer of the group `G` on the target entity,
+    /// putting every member of the group on cooldown.
+    ///
+    /// The timer is a one-shot timer tagged with `G`, lasting `duration`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_observed_timers::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct GlobalCooldown;
+    /// # let mut world = World::new();
+    /// # let mut commands = world.commands();
+    /// # let e1 = commands.spawn_empty().id();
+    /// commands.entity(e1)
+    ///     .start_group_cooldown::<GlobalCooldown>(Duration::from_millis(1500));
+    /// ```
+    fn start_group_cooldown<G: Component>(&mut self, duration: Duration) -> &mut Self;
+
+    /// Reset a [`Timer`] on the target entity. The [`Component`] `T` is used as
+    /// a tag to identify the timer.
     ///
     /// If the timer does not exist, this command does nothing.
-    /// Removes the [`Timer`] from the entity and triggers [`OnTimerCancelled`].
+    /// Calls [`Timer::reset`] on the timer.
     ///
     /// # Example
     ///
@@ -116,72 +638,1194 @@ pub trait EntityCommandTimersExt {
     /// # let mut world = World::new();
     /// # let mut commands = world.commands();
     /// # let e1 = commands.spawn_empty().id();
-    /// commands.entity(e1).cancel_timer::<Regenerate>();
+    /// commands.entity(e1).reset_timer::<Regenerate>();
     /// ```
-    fn cancel_timer<T: Component>(&mut self) -> &mut Self;
+    fn reset_timer<T: Component>(&mut self) -> &mut Self;
+
+    /// Reset a [`Timer`] on the target entity, and unpause it if it was
+    /// paused. The [`Component`] `T` is used as a tag to identify the timer.
+    ///
+    /// Like [`reset_timer`](Self::reset_timer), the timer keeps its mode,
+    /// duration, and per-timer options, and no events are triggered; unlike
+    /// it, the timer is running afterwards. Unlike starting the timer again
+    /// with [`start_timer`](Self::start_timer), this only affects a timer
+    /// that still exists: a one-shot timer that finished and was removed is
+    /// not re-armed, and this command does nothing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_observed_timers::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Idle;
+    /// # let mut world = World::new();
+    /// # let mut commands = world.commands();
+    /// # let e1 = commands.spawn_empty().id();
+    /// // Any input counts the idle time from zero again.
+    /// commands.entity(e1).refresh_timer::<Idle>();
+    /// ```
+    fn refresh_timer<T: Component>(&mut self) -> &mut Self;
+
+    /// Change the duration of a [`Timer`] on the target entity, keeping its
+    /// elapsed time. The [`Component`] `T` is used as a tag to identify the
+    /// timer.
+    ///
+    /// If the timer does not exist, this command does nothing. Calls
+    /// [`Timer::set_duration`] on the timer.
+    ///
+    /// Shortening a timer below its elapsed time makes it finish on the next
+    /// tick, exactly like a natural finish: [`OnTimerFinished`] is triggered,
+    /// and a one-shot timer is removed. Buffs can be cut short this way.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_observed_timers::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Haste;
+    /// # let mut world = World::new();
+    /// # let mut commands = world.commands();
+    /// # let e1 = commands.spawn_empty().id();
+    /// commands.entity(e1).set_timer_duration::<Haste>(Duration::from_secs(3));
+    /// ```
+    ///
+    /// [`OnTimerFinished`]: crate::event::OnTimerFinished
+    fn set_timer_duration<T: Component>(&mut self, duration: Duration) -> &mut Self;
+
+    /// Set the elapsed time of a [`Timer`] on the target entity to a fraction
+    /// of its duration. The [`Component`] `T` is used as a tag to identify the
+    /// timer.
+    ///
+    /// If the timer does not exist, this command does nothing. The timer is
+    /// reset, then its elapsed time is set to its duration times `fraction`,
+    /// which is clamped between `0.0` and `1.0`. A `NaN` fraction does
+    /// nothing. Setting `0.5` on a timer that elapsed 80% of its duration
+    /// refunds 30% of it. A fraction of `1.0` or more makes the timer finish
+    /// on the next tick, as [`set_timer_duration`](Self::set_timer_duration)
+    /// does when shortening it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_observed_timers::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Dash;
+    /// # let mut world = World::new();
+    /// # let mut commands = world.commands();
+    /// # let e1 = commands.spawn_empty().id();
+    /// // Half of the cooldown is left.
+    /// commands.entity(e1).set_fraction_timer::<Dash>(0.5);
+    /// ```
+    fn set_fraction_timer<T: Component>(&mut self, fraction: f32) -> &mut Self;
+
+    /// Temporarily speed up or slow down a [`Timer`] on the target entity.
+    /// The [`Component`] `T` is used as a tag to identify the timer.
+    ///
+    /// The timer ticks `scale` times faster for the next `for_duration` of
+    /// ticked time, measured before scaling, and then returns to normal
+    /// speed. A tick overlapping the end of the boost is only scaled for its
+    /// boosted part. The boost does not count down while the timer is not
+    /// ticking, such as while it is paused or gated.
+    ///
+    /// Boosts do not compose: boosting an already boosted timer replaces its
+    /// boost with the latest one. Restarting the timer removes its boost. If
+    /// the timer does not exist, or `scale` is negative or `NaN`, this
+    /// command does nothing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_observed_timers::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Fireball;
+    /// # let mut world = World::new();
+    /// # let mut commands = world.commands();
+    /// # let e1 = commands.spawn_empty().id();
+    /// // Haste: the cooldown recovers twice as fast for 3 seconds.
+    /// commands.entity(e1).boost_timer::<Fireball>(2., Duration::from_secs(3));
+    /// ```
+    fn boost_timer<T: Component>(&mut self, scale: f32, for_duration: Duration) -> &mut Self;
+
+    /// Pause a [`Timer`] on the target entity. The [`Component`] `T` is used as
+    /// a tag to identify the timer.
+    ///
+    /// If the timer does not exist, this command does nothing.
+    /// Calls [`Timer::pause`] on the timer.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_observed_timers::prelude::*;
+    /// # use bevy_time::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Regenerate;
+    /// # let mut world = World::new();
+    /// # let mut commands = world.commands();
+    /// # let e1 = commands.spawn_empty().id();
+    /// commands.entity(e1).pause_timer::<Regenerate>();
+    /// ```
+    fn pause_timer<T: Component>(&mut self) -> &mut Self;
+
+    /// Unpause a [`Timer`] on the target entity. The [`Component`] `T` is used
+    /// as a tag to identify the timer.
+    ///
+    /// If the timer does not exist, this command does nothing.
+    /// Calls [`Timer::unpause`] on the timer.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_observed_timers::prelude::*;
+    /// # use bevy_time::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Regenerate;
+    /// # let mut world = World::new();
+    /// # let mut commands = world.commands();
+    /// # let e1 = commands.spawn_empty().id();
+    /// commands.entity(e1).unpause_timer::<Regenerate>();
+    /// ```
+    fn unpause_timer<T: Component>(&mut self) -> &mut Self;
+
+    /// Cancel a [`Timer`] on the target entity. The [`Component`] `T` is used
+    /// as a tag to identify the timer.
+    ///
+    /// If the timer does not exist, this command does nothing.
+    /// Removes the [`Timer`] from the entity and triggers [`OnTimerCancelled`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_observed_timers::prelude::*;
+    /// # use bevy_time::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Regenerate;
+    /// # let mut world = World::new();
+    /// # let mut commands = world.commands();
+    /// # let e1 = commands.spawn_empty().id();
+    /// commands.entity(e1).cancel_timer::<Regenerate>();
+    /// ```
+    fn cancel_timer<T: Component>(&mut self) -> &mut Self;
+
+    /// Silently remove a [`Timer`] from the target entity. The [`Component`]
+    /// `T` is used as a tag to identify the timer.
+    ///
+    /// If the timer does not exist, this command does nothing.
+    /// Unlike [`cancel_timer`](Self::cancel_timer), no event is triggered, so
+    /// observers of [`OnTimerCancelled`] do not run. This is meant for
+    /// teardown and entity pooling, where cancellation side effects are not
+    /// wanted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_observed_timers::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Regenerate;
+    /// # let mut world = World::new();
+    /// # let mut commands = world.commands();
+    /// # let e1 = commands.spawn_empty().id();
+    /// commands.entity(e1).remove_timer::<Regenerate>();
+    /// ```
+    fn remove_timer<T: Component>(&mut self) -> &mut Self;
+
+    /// Cancel every [`Timer`] on the target entity.
+    ///
+    /// Removes all timers from the entity, and triggers [`OnTimerCancelled`]
+    /// for each of them, in the entity's timer order. If the entity has no
+    /// timers, this command does nothing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_observed_timers::prelude::*;
+    /// # let mut world = World::new();
+    /// # let mut commands = world.commands();
+    /// # let e1 = commands.spawn_empty().id();
+    /// commands.entity(e1).cancel_all_timers();
+    /// ```
+    fn cancel_all_timers(&mut self) -> &mut Self;
+
+    /// Reset every [`Timer`] on the target entity.
+    ///
+    /// Resets the timers in the entity's timer order, like
+    /// [`reset_timer`](Self::reset_timer) does for a single one, which is
+    /// handy for "refresh all cooldowns" effects. No events are triggered. If
+    /// the entity has no timers, this command does nothing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_observed_timers::prelude::*;
+    /// # let mut world = World::new();
+    /// # let mut commands = world.commands();
+    /// # let e1 = commands.spawn_empty().id();
+    /// commands.entity(e1).reset_all_timers();
+    /// ```
+    fn reset_all_timers(&mut self) -> &mut Self;
+
+    /// Cancel every [`Timer`] on the target entity and all of its
+    /// descendants.
+    ///
+    /// Behaves like [`cancel_all_timers`](Self::cancel_all_timers), applied to
+    /// the entity and then to its descendants, following [`Children`] depth
+    /// first, in child order. Requires the `bevy_hierarchy` feature.
+    ///
+    /// Despawning an entity reports its running timers as cancelled with
+    /// [`CancelReason::Despawn`], after the fact. Queue this before
+    /// [`despawn_recursive`] to cancel them while the entities still exist,
+    /// with [`CancelReason::Manual`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_hierarchy::prelude::*;
+    /// # use bevy_observed_timers::prelude::*;
+    /// # let mut world = World::new();
+    /// # let mut commands = world.commands();
+    /// # let vehicle = commands.spawn_empty().id();
+    /// commands.entity(vehicle).cancel_timers_recursive();
+    /// commands.entity(vehicle).despawn_recursive();
+    /// ```
+    ///
+    /// [`Children`]: bevy_hierarchy::Children
+    /// [`despawn_recursive`]: bevy_hierarchy::DespawnRecursiveExt::despawn_recursive
+    #[cfg(feature = "bevy_hierarchy")]
+    fn cancel_timers_recursive(&mut self) -> &mut Self;
+
+    /// Cancel every [`Timer`] on the target entity whose tag belongs to the
+    /// given category.
+    ///
+    /// Behaves like [`cancel_timer`](Self::cancel_timer) for each of the
+    /// timers, in the entity's timer order. Only tags registered in the
+    /// category with [`TimerCategories::register`] are matched. If the entity
+    /// has no such timers, this command does nothing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_observed_timers::{category::TimerCategory, prelude::*};
+    /// const BUFF: TimerCategory = TimerCategory("buff");
+    ///
+    /// # let mut world = World::new();
+    /// # let mut commands = world.commands();
+    /// # let e1 = commands.spawn_empty().id();
+    /// // Dispel all buffs.
+    /// commands.entity(e1).cancel_category(BUFF);
+    /// ```
+    fn cancel_category(&mut self, category: TimerCategory) -> &mut Self;
+
+    /// Move a [`Timer`] from the target entity to another entity. The
+    /// [`Component`] `T` is used as a tag to identify the timer.
+    ///
+    /// The timer keeps its exact state, including its elapsed time, whether
+    /// it is paused, its per-timer options, and its callback (see
+    /// [`Self::start_timer_with_callback`]). It replaces any timer with the
+    /// same tag on the other entity, dropping the callback of the replaced
+    /// timer. No events are triggered; queue
+    /// [`TransferTimer::with_events`] instead to trigger [`OnTimerCancelled`]
+    /// on the target entity and [`OnTimerStarted`] on the other entity.
+    ///
+    /// If the timer does not exist, or the other entity does not exist, this
+    /// command does nothing and the timer stays on the target entity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_observed_timers::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Plague;
+    /// # let mut world = World::new();
+    /// # let mut commands = world.commands();
+    /// # let e1 = commands.spawn_empty().id();
+    /// # let e2 = commands.spawn_empty().id();
+    /// // The plague jumps to a new host.
+    /// commands.entity(e1).transfer_timer::<Plague>(e2);
+    /// ```
+    fn transfer_timer<T: Component>(&mut self, to: Entity) -> &mut Self;
+
+    /// Copy the [`Timer`] tagged with the [`Component`] `T` from another
+    /// entity to the target entity, and trigger [`OnTimerStarted`] on the
+    /// target entity.
+    ///
+    /// The copy keeps the exact state of the source timer, including its
+    /// elapsed time, whether it is paused, and its per-timer options, but not
+    /// its callback (see [`Self::start_timer_with_callback`]). It replaces any
+    /// timer with the same tag on the target entity. The source timer is left
+    /// untouched.
+    ///
+    /// If the source entity has no such timer, or either entity does not
+    /// exist, this command does nothing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_observed_timers::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Cooldown;
+    /// # let mut world = World::new();
+    /// # let mut commands = world.commands();
+    /// # let parent = commands.spawn_empty().id();
+    /// # let child = commands.spawn_empty().id();
+    /// // The child inherits the parent's cooldown.
+    /// commands.entity(child).copy_timer_from::<Cooldown>(parent);
+    /// ```
+    fn copy_timer_from<T: Component>(&mut self, source: Entity) -> &mut Self;
+
+    /// Exchange the [`Timer`]s tagged with the [`Component`]s `A` and `B` on
+    /// the target entity.
+    ///
+    /// Each timer keeps its exact state, including its elapsed time, its
+    /// per-timer options and its callback, under the other tag. If only one
+    /// of the timers exists, it moves to the other tag, leaving no timer
+    /// under its own. If neither exists, this command does nothing. No events
+    /// are triggered.
+    ///
+    /// See [`Timers::swap`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_observed_timers::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Fireball;
+    /// # #[derive(Component)]
+    /// # struct Frostbolt;
+    /// # let mut world = World::new();
+    /// # let mut commands = world.commands();
+    /// # let e1 = commands.spawn_empty().id();
+    /// // Mirror: the two abilities trade cooldowns.
+    /// commands.entity(e1).swap_timers::<Fireball, Frostbolt>();
+    /// ```
+    fn swap_timers<A: Component, B: Component>(&mut self) -> &mut Self;
+
+    /// Start a [`Timer`] on the target entity that runs `on_finish` when it
+    /// finishes. The [`Component`] `T` is used as a tag to identify the
+    /// timer.
+    ///
+    /// Behaves like [`start_timer`](Self::start_timer). The callback is given
+    /// the world and the entity, and runs as a command after the finish
+    /// observers, once per timer and tick even if the timer finished several
+    /// times in the tick, whichever [`FinishedEvents`] are triggered. It suits
+    /// one-off timers that do not warrant declaring an observer.
+    ///
+    /// The callback is stored alongside the timer, and dropped with it: after
+    /// the only finish of a one-shot timer, or the last finish of a limited
+    /// one, when the timer is cancelled or removed, or when its entity is
+    /// despawned. Restarting the timer replaces the callback, or drops it if
+    /// started without one. Transferring the timer to another entity moves
+    /// the callback with it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_observed_timers::prelude::*;
+    /// # use bevy_time::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Fuse;
+    /// # let mut world = World::new();
+    /// # let mut commands = world.commands();
+    /// # let e1 = commands.spawn_empty().id();
+    /// commands.entity(e1).start_timer_with_callback::<Fuse>(
+    ///     Timer::from_seconds(3., TimerMode::Once),
+    ///     |world: &mut World, entity| {
+    ///         world.despawn(entity);
+    ///     },
+    /// );
+    /// ```
+    ///
+    /// [`FinishedEvents`]: crate::settings::FinishedEvents
+    fn start_timer_with_callback<T: Component>(
+        &mut self,
+        timer: Timer,
+        on_finish: impl FnMut(&mut World, Entity) + Send + 'static,
+    ) -> &mut Self;
+}
+
+impl EntityCommandTimersExt for EntityCommands<'_> {
+    fn start_timer<T: Component>(&mut self, timer: Timer) -> &mut Self {
+        self.queue(StartTimer::<T>::new(timer))
+    }
+
+    fn start_timer_secs<T: Component>(
+        &mut self,
+        seconds: f32,
+        mode: impl Into<Option<TimerMode>>,
+    ) -> &mut Self {
+        self.queue(StartTimer::<T>::from_seconds(seconds, mode))
+    }
+
+    fn start_timer_millis<T: Component>(
+        &mut self,
+        millis: u64,
+        mode: impl Into<Option<TimerMode>>,
+    ) -> &mut Self {
+        let duration = Duration::from_millis(millis);
+        self.queue(match mode.into() {
+            Some(mode) => StartTimer::<T>::new(Timer::new(duration, mode)),
+            None => StartTimer::<T>::new(Timer::new(duration, TimerMode::Once)).with_default_mode(),
+        })
+    }
+
+    fn start_timer_persistent<T: Component>(&mut self, timer: Timer) -> &mut Self {
+        self.queue(StartTimer::<T>::new(timer).persistent())
+    }
+
+    fn start_timer_gated<T: Component, G: Component>(&mut self, timer: Timer) -> &mut Self {
+        self.queue(StartTimer::<T>::new(timer).gated::<G>())
+    }
+
+    fn start_timer_on_clock<T: Component>(&mut self, timer: Timer, clock: TimerClock) -> &mut Self {
+        self.queue(StartTimer::<T>::new(timer).on_clock(clock))
+    }
+
+    fn start_timer_bundle<B: TimerBundle>(&mut self, bundle: B) -> &mut Self {
+        self.queue(StartTimerBundle(bundle))
+    }
+
+    fn start_timer_with_gap<T: Component>(&mut self, active: Duration, gap: Duration) -> &mut Self {
+        self.queue(StartTimer::<T>::with_gap(active, gap))
+    }
+
+    fn start_timer_repeating_n<T: Component>(
+        &mut self,
+        timer: Timer,
+        repetitions: u32,
+    ) -> &mut Self {
+        self.queue(StartTimer::<T>::new(timer).repeating_n(repetitions))
+    }
+
+    fn start_timer_aligned<T: Component>(&mut self, period: Duration) -> &mut Self {
+        self.queue(StartTimer::<T>::new(Timer::new(period, TimerMode::Repeating)).aligned())
+    }
+
+    fn start_timer_linked<T: Component>(&mut self, timer: Timer) -> &mut Self {
+        self.queue(StartTimer::<T>::new(timer).linked())
+    }
+
+    fn start_timer_with_priority<T: Component>(
+        &mut self,
+        timer: Timer,
+        priority: i32,
+    ) -> &mut Self {
+        self.queue(StartTimer::<T>::new(timer).with_priority(priority))
+    }
+
+    fn start_timer_tracked<T: Component>(&mut self, timer: Timer, step: f32) -> &mut Self {
+        self.queue(StartTimer::<T>::new(timer).tracked(step))
+    }
+
+    fn start_timer_with_thresholds<T: Component>(
+        &mut self,
+        timer: Timer,
+        thresholds: Vec<f32>,
+    ) -> &mut Self {
+        self.queue(StartTimer::<T>::new(timer).with_thresholds(thresholds))
+    }
+
+    fn start_timer_with_second_ticks<T: Component>(&mut self, timer: Timer) -> &mut Self {
+        self.queue(StartTimer::<T>::new(timer).with_second_ticks())
+    }
+
+    fn start_timer_delayed<T: Component>(&mut self, delay: Duration, timer: Timer) -> &mut Self {
+        self.queue(StartTimer::<T>::new(timer).delayed(delay))
+    }
+
+    fn start_timer_removing_tag<T: Component>(&mut self, timer: Timer) -> &mut Self {
+        self.queue(StartTimer::<T>::new(timer).removing_tag())
+    }
+
+    fn start_cooldown<T: Component>(&mut self, timer: Timer) -> &mut Self {
+        self.queue(StartTimer::<T>::new(timer))
+            .queue(StartGroupCooldown::<T>::default())
+    }
+
+    fn start_group_cooldown<G: Component>(&mut self, duration: Duration) -> &mut Self {
+        self.queue(StartTimer::<G>::new(Timer::new(duration, TimerMode::Once)))
+    }
+
+    fn reset_timer<T: Component>(&mut self) -> &mut Self {
+        self.queue(ResetTimer::<T>::default())
+    }
+
+    fn refresh_timer<T: Component>(&mut self) -> &mut Self {
+        self.queue(RefreshTimer::<T>::default())
+    }
+
+    fn set_timer_duration<T: Component>(&mut self, duration: Duration) -> &mut Self {
+        self.queue(SetTimerDuration::<T>::new(duration))
+    }
+
+    fn set_fraction_timer<T: Component>(&mut self, fraction: f32) -> &mut Self {
+        self.queue(SetTimerFraction::<T>::new(fraction))
+    }
+
+    fn boost_timer<T: Component>(&mut self, scale: f32, for_duration: Duration) -> &mut Self {
+        self.queue(BoostTimer::<T>::new(scale, for_duration))
+    }
+
+    fn pause_timer<T: Component>(&mut self) -> &mut Self {
+        self.queue(PauseTimer::<T>::default())
+    }
+
+    fn unpause_timer<T: Component>(&mut self) -> &mut Self {
+        self.queue(UnpauseTimer::<T>::default())
+    }
+
+    fn cancel_timer<T: Component>(&mut self) -> &mut Self {
+        self.queue(CancelTimer::<T>::default())
+    }
+
+    fn remove_timer<T: Component>(&mut self) -> &mut Self {
+        self.queue(RemoveTimer::<T>::default())
+    }
+
+    fn cancel_all_timers(&mut self) -> &mut Self {
+        self.queue(CancelAllTimers::default())
+    }
+
+    fn reset_all_timers(&mut self) -> &mut Self {
+        self.queue(ResetAllTimers)
+    }
+
+    #[cfg(feature = "bevy_hierarchy")]
+    fn cancel_timers_recursive(&mut self) -> &mut Self {
+        self.queue(CancelAllTimers { recursive: true })
+    }
+
+    fn cancel_category(&mut self, category: TimerCategory) -> &mut Self {
+        self.queue(CancelCategory(category))
+    }
+
+    fn transfer_timer<T: Component>(&mut self, to: Entity) -> &mut Self {
+        self.queue(TransferTimer::<T>::new(to))
+    }
+
+    fn copy_timer_from<T: Component>(&mut self, source: Entity) -> &mut Self {
+        self.queue(CopyTimer::<T>::new(source))
+    }
+
+    fn swap_timers<A: Component, B: Component>(&mut self) -> &mut Self {
+        self.queue(SwapTimers::<A, B>::default())
+    }
+
+    fn start_timer_with_callback<T: Component>(
+        &mut self,
+        timer: Timer,
+        on_finish: impl FnMut(&mut World, Entity) + Send + 'static,
+    ) -> &mut Self {
+        self.queue(StartTimer::<T>::new(timer).with_callback(on_finish))
+    }
+}
+
+/// An [`EntityCommand`] that starts a [`Timer`] on the target entity. The
+/// [`Component`] `T` is used as a tag to identify the timer.
+///
+/// Use [`EntityCommands::start_timer`] to queue this command.
+pub struct StartTimer<T: Component> {
+    entry: TimerEntry,
+    /// The unvalidated duration in seconds, see [`StartTimer::from_seconds`].
+    seconds: Option<f32>,
+    gate: Option<fn(&mut World) -> ComponentId>,
+    /// Whether to align finishes to the elapsed [`Time`], see
+    /// [`StartTimer::aligned`].
+    ///
+    /// [`Time`]: bevy_time::Time
+    aligned: bool,
+    /// Whether to use [`TimerSettings::default_mode`] as the mode, see
+    /// [`StartTimer::with_default_mode`].
+    default_mode: bool,
+    callback: Option<TimerCallback>,
+    marker: PhantomData<T>,
+}
+
+impl<T: Component> StartTimer<T> {
+    /// Creates a new entity command.
+    pub fn new(timer: Timer) -> Self {
+        Self {
+            entry: TimerEntry::new(timer),
+            seconds: None,
+            gate: None,
+            aligned: false,
+            default_mode: false,
+            callback: None,
+            marker: PhantomData,
+        }
+    }
+
+    /// Creates a new entity command that starts a timer lasting the given
+    /// number of seconds.
+    ///
+    /// The duration is validated when the command is applied, see
+    /// [`EntityCommands::start_timer_secs`]. Without a mode, the timer uses
+    /// [`TimerSettings::default_mode`].
+    pub fn from_seconds(seconds: f32, mode: impl Into<Option<TimerMode>>) -> Self {
+        let mode = mode.into();
+        let mut command = Self::new(Timer::new(Duration::ZERO, mode.unwrap_or_default()));
+        command.seconds = Some(seconds);
+        command.default_mode = mode.is_none();
+        command
+    }
+
+    /// Creates a new entity command that starts a timer with a cooldown gap.
+    ///
+    /// See [`EntityCommands::start_timer_with_gap`].
+    pub fn with_gap(active: Duration, gap: Duration) -> Self {
+        Self {
+            entry: TimerEntry::with_gap(active, gap),
+            seconds: None,
+            gate: None,
+            aligned: false,
+            default_mode: false,
+            callback: None,
+            marker: PhantomData,
+        }
+    }
+
+    /// Replaces the mode of the timer with [`TimerSettings::default_mode`]
+    /// when the command is applied.
+    pub fn with_default_mode(mut self) -> Self {
+        self.default_mode = true;
+        self
+    }
+
+    /// Keeps the timer after it finishes in [`TimerMode::Once`].
+    ///
+    /// See [`EntityCommands::start_timer_persistent`].
+    ///
+    /// [`TimerMode::Once`]: bevy_time::TimerMode::Once
+    pub fn persistent(mut self) -> Self {
+        self.entry.persistent = true;
+        self
+    }
+
+    /// Only ticks the timer while the entity has the [`Component`] `G`.
+    ///
+    /// See [`EntityCommands::start_timer_gated`].
+    pub fn gated<G: Component>(mut self) -> Self {
+        self.gate = Some(init_gate::<G>);
+        self
+    }
+
+    /// Cancels the timer when the [`Component`] `T` is removed from the
+    /// entity.
+    ///
+    /// See [`EntityCommands::start_timer_linked`].
+    pub fn linked(mut self) -> Self {
+        self.entry.linked = true;
+        self
+    }
+
+    /// Tracks the progress of the timer in [`TimerProgress`], rounded down to
+    /// a multiple of `step`.
+    ///
+    /// See [`EntityCommands::start_timer_tracked`].
+    pub fn tracked(mut self, step: f32) -> Self {
+        let step = if step.is_nan() {
+            1.
+        } else {
+            step.clamp(f32::EPSILON, 1.)
+        };
+        self.entry.progress_step = Some(step);
+        self
+    }
+
+    /// Triggers [`OnTimerThreshold`] as the timer crosses each of the given
+    /// fractions of its duration.
+    ///
+    /// See [`EntityCommands::start_timer_with_thresholds`].
+    ///
+    /// [`OnTimerThreshold`]: crate::event::OnTimerThreshold
+    pub fn with_thresholds(mut self, mut thresholds: Vec<f32>) -> Self {
+        thresholds.retain(|t| !t.is_nan());
+        for t in &mut thresholds {
+            *t = t.clamp(f32::EPSILON, 1.);
+        }
+        thresholds.sort_by(f32::total_cmp);
+        thresholds.dedup();
+        self.entry.thresholds = thresholds;
+        self
+    }
+
+    /// Triggers [`OnTimerSecondTick`] as the remaining whole seconds of the
+    /// timer decrease.
+    ///
+    /// See [`EntityCommands::start_timer_with_second_ticks`].
+    ///
+    /// [`OnTimerSecondTick`]: crate::event::OnTimerSecondTick
+    pub fn with_second_ticks(mut self) -> Self {
+        self.entry.second_ticks = true;
+        self
+    }
+
+    /// Waits for `delay` before the timer starts ticking.
+    ///
+    /// See [`EntityCommands::start_timer_delayed`].
+    pub fn delayed(mut self, delay: Duration) -> Self {
+        self.entry.delay = Some(StartDelay {
+            remaining: delay,
+            replaced: false,
+        });
+        self
+    }
+
+    /// Runs `on_finish` once per tick in which the timer finishes, even if a
+    /// large delta makes it finish several times in that tick.
+    ///
+    /// See [`EntityCommands::start_timer_with_callback`].
+    pub fn with_callback(
+        mut self,
+        on_finish: impl FnMut(&mut World, Entity) + Send + 'static,
+    ) -> Self {
+        self.callback = Some(Box::new(on_finish));
+        self
+    }
+
+    /// Repeats the timer `repetitions` times, and then removes it.
+    ///
+    /// See [`EntityCommands::start_timer_repeating_n`].
+    pub fn repeating_n(mut self, repetitions: u32) -> Self {
+        self.entry.timer.set_mode(TimerMode::Repeating);
+        self.entry.repetitions = Some(repetitions.max(1));
+        self
+    }
+
+    /// Aligns the finishes of the timer to multiples of its duration of the
+    /// total elapsed [`Time`].
+    ///
+    /// See [`EntityCommands::start_timer_aligned`].
+    ///
+    /// [`Time`]: bevy_time::Time
+    pub fn aligned(mut self) -> Self {
+        self.aligned = true;
+        self
+    }
+
+    /// Removes the [`Component`] `T` from the entity when the timer finishes.
+    ///
+    /// See [`EntityCommands::start_timer_removing_tag`].
+    pub fn removing_tag(mut self) -> Self {
+        self.entry.removes_tag = true;
+        self
+    }
+
+    /// Sets the priority used to order finishes within the entity.
+    ///
+    /// See [`EntityCommands::start_timer_with_priority`].
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.entry.priority = priority;
+        self
+    }
+
+    /// Sets the clock ticking the timer.
+    ///
+    /// See [`EntityCommands::start_timer_on_clock`].
+    pub fn on_clock(mut self, clock: TimerClock) -> Self {
+        self.entry.clock = clock;
+        self
+    }
+}
+
+impl<T: Component> EntityCommand for StartTimer<T> {
+    fn apply(mut self, entity: Entity, world: &mut World) {
+        if self.default_mode {
+            let mode = world
+                .get_resource::<TimerSettings>()
+                .map_or(TimerMode::Once, |settings| settings.default_mode);
+            self.entry.timer.set_mode(mode);
+        }
+        if let Some(seconds) = self.seconds {
+            if seconds.is_nan() {
+                warn!(
+                    "not starting timer {} on {entity}: its duration is NaN",
+                    core::any::type_name::<T>()
+                );
+                return;
+            }
+            let duration = Duration::try_from_secs_f32(seconds.max(0.)).unwrap_or(Duration::MAX);
+            self.entry.timer.set_duration(duration);
+        }
+        if self.aligned {
+            let period = self.entry.timer.duration().as_nanos();
+            if let Some(time) = world.get_resource::<Time>().filter(|_| period > 0) {
+                let offset = time.elapsed().as_nanos() % period;
+                self.entry
+                    .timer
+                    .set_elapsed(Duration::from_nanos(offset as u64));
+            }
+        }
+
+        let component = world.register_component::<T>();
+        if self.entry.linked {
+            watch_link(world, component);
+        }
+        if let Some(gate) = self.gate {
+            self.entry.gate = Some(gate(world));
+        }
+
+        let capacity = world
+            .get_resource::<TimerSettings>()
+            .map_or(0, |settings| settings.default_capacity);
+
+        if world.get_entity(entity).is_err() {
+            world
+                .get_resource::<TimerSettings>()
+                .map(|settings| settings.missing_entity_policy)
+                .unwrap_or_default()
+                .report(entity, core::any::type_name::<T>());
+            return;
+        }
+        match self.callback.take() {
+            Some(on_finish) => callback::insert(world, entity, component, on_finish),
+            None => callback::remove(world, entity, component),
+        }
+
+        let Ok(mut emut) = world.get_entity_mut(entity) else {
+            return;
+        };
+        if let Some(gate) = self.entry.gate {
+            self.entry.gate_open = emut.contains_id(gate);
+        }
+        if let Some(step) = self.entry.progress_step {
+            let fraction = TimerProgress::quantize(self.entry.timer.fraction(), step);
+            emut.entry::<TimerProgress>()
+                .or_default()
+                .set(component, fraction);
+        }
+        let mut timers = emut
+            .entry::<Timers>()
+            .or_insert_with(|| Timers::with_capacity(capacity));
+        if let Some(delay) = &mut self.entry.delay {
+            // `OnTimerStarted` is triggered by the tick system after the delay.
+            delay.replaced = timers.contains(component);
+            timers.insert_entry(component, self.entry);
+            tracking::track(world, entity, &[component]);
+            return;
+        }
+        let replaced = timers.insert_entry(component, self.entry).is_some();
+        world.trigger_targets(
+            OnTimerStarted { replaced },
+            TargetBoth::new(entity, component),
+        );
+    }
+}
+
+/// A group of timers started together with
+/// [`EntityCommands::start_timer_bundle`].
+///
+/// It is implemented for [`StartTimer`], and for tuples of up to 12 timer
+/// bundles. Nest tuples to start more timers at once. Timers are started in
+/// the order of the tuple, so a later timer replaces an earlier one with the
+/// same tag.
+pub trait TimerBundle: Send + 'static {
+    /// Starts the timers of the bundle on the given entity.
+    fn start(self, entity: Entity, world: &mut World);
+}
+
+impl<T: Component> TimerBundle for StartTimer<T> {
+    fn start(self, entity: Entity, world: &mut World) {
+        self.apply(entity, world);
+    }
+}
+
+macro_rules! impl_timer_bundle {
+    ($($bundle:ident),*) => {
+        impl<$($bundle: TimerBundle),*> TimerBundle for ($($bundle,)*) {
+            #[allow(non_snake_case)]
+            fn start(self, entity: Entity, world: &mut World) {
+                let ($($bundle,)*) = self;
+                $($bundle.start(entity, world);)*
+            }
+        }
+    };
+}
+
+impl_timer_bundle!(B0);
+impl_timer_bundle!(B0, B1);
+impl_timer_bundle!(B0, B1, B2);
+impl_timer_bundle!(B0, B1, B2, B3);
+impl_timer_bundle!(B0, B1, B2, B3, B4);
+impl_timer_bundle!(B0, B1, B2, B3, B4, B5);
+impl_timer_bundle!(B0, B1, B2, B3, B4, B5, B6);
+impl_timer_bundle!(B0, B1, B2, B3, B4, B5, B6, B7);
+impl_timer_bundle!(B0, B1, B2, B3, B4, B5, B6, B7, B8);
+impl_timer_bundle!(B0, B1, B2, B3, B4, B5, B6, B7, B8, B9);
+impl_timer_bundle!(B0, B1, B2, B3, B4, B5, B6, B7, B8, B9, B10);
+impl_timer_bundle!(B0, B1, B2, B3, B4, B5, B6, B7, B8, B9, B10, B11);
+
+/// An [`EntityCommand`] that starts every timer of a [`TimerBundle`] on the
+/// target entity.
+///
+/// Use [`EntityCommands::start_timer_bundle`] to queue this command.
+pub struct StartTimerBundle<B: TimerBundle>(pub B);
+
+impl<B: TimerBundle> EntityCommand for StartTimerBundle<B> {
+    fn apply(self, entity: Entity, world: &mut World) {
+        self.0.start(entity, world);
+    }
 }
 
-impl EntityCommandTimersExt for EntityCommands<'_> {
-    fn start_timer<T: Component>(&mut self, timer: Timer) -> &mut Self {
-        self.queue(StartTimer::<T>::new(timer))
+/// An [`EntityCommand`] that starts the shared cooldown timer of the group of
+/// the timer tag `T`, if it belongs to one.
+///
+/// Queued by [`EntityCommands::start_cooldown`] after starting the timer
+/// itself. The shared timer is restarted as with
+/// [`EntityCommands::start_group_cooldown`].
+pub struct StartGroupCooldown<T: Component>(PhantomData<T>);
+
+impl<T: Component> Default for StartGroupCooldown<T> {
+    fn default() -> Self {
+        Self(PhantomData)
     }
+}
 
-    fn reset_timer<T: Component>(&mut self) -> &mut Self {
-        self.queue(ResetTimer::<T>::default())
+impl<T: Component> EntityCommand for StartGroupCooldown<T> {
+    fn apply(self, entity: Entity, world: &mut World) {
+        let member = world.register_component::<T>();
+        let Some(groups) = world.get_resource::<CooldownGroups>() else {
+            return;
+        };
+        let Some((start, duration)) = groups
+            .group_of(member)
+            .and_then(|group| groups.start(group))
+        else {
+            return;
+        };
+        start(entity, world, duration);
     }
+}
 
-    fn pause_timer<T: Component>(&mut self) -> &mut Self {
-        self.queue(PauseTimer::<T>::default())
+/// The kinds of observers that are lazily added once per [`Component`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum LazyObserver {
+    /// Keeps gated timers in sync with the presence of their gate.
+    Gate,
+    /// Cancels linked timers when their tag is removed.
+    Link,
+}
+
+/// Tracks which [`LazyObserver`]s were already added for which
+/// [`Component`]s, to avoid adding duplicates.
+#[derive(Resource, Default)]
+struct LazyObservers(HashSet<(LazyObserver, ComponentId)>);
+
+impl LazyObservers {
+    /// Returns `true` the first time it is called with the given observer kind
+    /// and [`ComponentId`], meaning the observer needs to be added.
+    fn first(world: &mut World, kind: LazyObserver, component: ComponentId) -> bool {
+        world
+            .get_resource_or_init::<LazyObservers>()
+            .0
+            .insert((kind, component))
     }
+}
 
-    fn unpause_timer<T: Component>(&mut self) -> &mut Self {
-        self.queue(UnpauseTimer::<T>::default())
+/// Registers the gate [`Component`] `G`, and lazily adds the observers that
+/// open and close gated timers when `G` is added to or removed from an entity.
+fn init_gate<G: Component>(world: &mut World) -> ComponentId {
+    let gate = world.register_component::<G>();
+    watch_gate(world, gate);
+    gate
+}
+
+/// Lazily adds the observers that open and close timers gated by `gate` when
+/// it is added to or removed from an entity.
+pub(crate) fn watch_gate(world: &mut World, gate: ComponentId) {
+    if LazyObservers::first(world, LazyObserver::Gate, gate) {
+        world.spawn(
+            Observer::new(move |t: Trigger<OnAdd>, mut timers: Query<&mut Timers>| {
+                if let Ok(mut timers) = timers.get_mut(t.entity()) {
+                    timers.set_gate_open(gate, true);
+                }
+            })
+            .with_component(gate),
+        );
+        world.spawn(
+            Observer::new(
+                move |t: Trigger<OnRemove>, mut timers: Query<&mut Timers>| {
+                    if let Ok(mut timers) = timers.get_mut(t.entity()) {
+                        timers.set_gate_open(gate, false);
+                    }
+                },
+            )
+            .with_component(gate),
+        );
     }
+}
 
-    fn cancel_timer<T: Component>(&mut self) -> &mut Self {
-        self.queue(CancelTimer::<T>::default())
+/// Lazily adds the observer that cancels linked timers tagged with
+/// `component` when it is removed from an entity.
+pub(crate) fn watch_link(world: &mut World, component: ComponentId) {
+    if LazyObservers::first(world, LazyObserver::Link, component) {
+        let observer = Observer::new(move |t: Trigger<OnRemove>, mut commands: Commands| {
+            let entity = t.entity();
+            // Deferred, to tell a removed tag apart from a despawn.
+            commands.queue(move |world: &mut World| {
+                // Despawns are reported by the `Timers` component instead.
+                let Ok(mut emut) = world.get_entity_mut(entity) else {
+                    return;
+                };
+                let Some(mut timers) = emut.get_mut::<Timers>() else {
+                    return;
+                };
+                if timers.is_linked(component) != Some(true) {
+                    return;
+                }
+                timers.remove(component);
+                world.trigger_targets(
+                    OnTimerCancelled {
+                        reason: CancelReason::TagRemoved,
+                    },
+                    TargetBoth::new(entity, component),
+                );
+            });
+        });
+        world.spawn(observer.with_component(component));
     }
 }
 
-/// An [`EntityCommand`] that starts a [`Timer`] on the target entity. The
+/// An [`EntityCommand`] that resets a [`Timer`] on the target entity. The
 /// [`Component`] `T` is used as a tag to identify the timer.
 ///
-/// Use [`EntityCommands::start_timer`] to queue this command.
-pub struct StartTimer<T: Component>(Timer, PhantomData<T>);
+/// Use [`EntityCommands::reset_timer`] to queue this command.
+pub struct ResetTimer<T: Component>(PhantomData<T>);
 
-impl<T: Component> StartTimer<T> {
-    /// Creates a new entity command.
-    pub fn new(timer: Timer) -> Self {
-        Self(timer, PhantomData)
+impl<T: Component> Default for ResetTimer<T> {
+    fn default() -> Self {
+        Self(PhantomData)
     }
 }
 
-impl<T: Component> EntityCommand for StartTimer<T> {
+impl<T: Component> EntityCommand for ResetTimer<T> {
     fn apply(self, entity: Entity, world: &mut World) {
         let component = world.register_component::<T>();
 
         let Ok(mut emut) = world.get_entity_mut(entity) else {
             return;
         };
-        let mut timers = emut.entry::<Timers>().or_default();
-        timers.insert(component, self.0);
+        let Some(mut timers) = emut.get_mut::<Timers>() else {
+            return;
+        };
+        let Some(timer) = timers.get_mut(component) else {
+            return;
+        };
+        timer.reset();
     }
 }
 
-/// An [`EntityCommand`] that resets a [`Timer`] on the target entity. The
-/// [`Component`] `T` is used as a tag to identify the timer.
+/// An [`EntityCommand`] that resets a [`Timer`] on the target entity, and
+/// unpauses it if it was paused. The [`Component`] `T` is used as a tag to
+/// identify the timer.
 ///
-/// Use [`EntityCommands::reset_timer`] to queue this command.
-pub struct ResetTimer<T: Component>(PhantomData<T>);
+/// Use [`EntityCommands::refresh_timer`] to queue this command.
+pub struct RefreshTimer<T: Component>(PhantomData<T>);
 
-impl<T: Component> Default for ResetTimer<T> {
+impl<T: Component> Default for RefreshTimer<T> {
     fn default() -> Self {
         Self(PhantomData)
     }
 }
 
-impl<T: Component> EntityCommand for ResetTimer<T> {
+impl<T: Component> EntityCommand for RefreshTimer<T> {
+    fn apply(self, entity: Entity, world: &mut World) {
+        let component = world.register_component::<T>();
+
+        let Ok(mut emut) = world.get_entity_mut(entity) else {
+            return;
+        };
+        let Some(mut timers) = emut.get_mut::<Timers>() else {
+            return;
+        };
+        let Some(timer) = timers.get_mut(component) else {
+            return;
+        };
+        timer.reset();
+        if timer.paused() {
+            timer.unpause();
+            lifecycle::mirror_pause(world, entity, component, false);
+        }
+    }
+}
+
+/// An [`EntityCommand`] that changes the duration of a [`Timer`] on the
+/// target entity. The [`Component`] `T` is used as a tag to identify the
+/// timer.
+///
+/// Use [`EntityCommands::set_timer_duration`] to queue this command.
+pub struct SetTimerDuration<T: Component> {
+    duration: Duration,
+    marker: PhantomData<T>,
+}
+
+impl<T: Component> SetTimerDuration<T> {
+    /// Creates a new entity command.
+    pub fn new(duration: Duration) -> Self {
+        Self {
+            duration,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T: Component> EntityCommand for SetTimerDuration<T> {
+    fn apply(self, entity: Entity, world: &mut World) {
+        let component = world.register_component::<T>();
+
+        let Ok(mut emut) = world.get_entity_mut(entity) else {
+            return;
+        };
+        let Some(mut timers) = emut.get_mut::<Timers>() else {
+            return;
+        };
+        let Some(timer) = timers.get_mut(component) else {
+            return;
+        };
+        timer.set_duration(self.duration);
+    }
+}
+
+/// An [`EntityCommand`] that sets the elapsed time of a [`Timer`] on the
+/// target entity to a fraction of its duration. The [`Component`] `T` is used
+/// as a tag to identify the timer.
+///
+/// Use [`EntityCommands::set_fraction_timer`] to queue this command.
+pub struct SetTimerFraction<T: Component> {
+    fraction: f32,
+    marker: PhantomData<T>,
+}
+
+impl<T: Component> SetTimerFraction<T> {
+    /// Creates a new entity command.
+    pub fn new(fraction: f32) -> Self {
+        Self {
+            fraction,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T: Component> EntityCommand for SetTimerFraction<T> {
     fn apply(self, entity: Entity, world: &mut World) {
+        if self.fraction.is_nan() {
+            return;
+        }
         let component = world.register_component::<T>();
 
         let Ok(mut emut) = world.get_entity_mut(entity) else {
@@ -193,7 +1837,61 @@ impl<T: Component> EntityCommand for ResetTimer<T> {
         let Some(timer) = timers.get_mut(component) else {
             return;
         };
+        // Clears the finished state, which would keep a one-shot timer from
+        // ticking again.
         timer.reset();
+        let elapsed = timer.duration().mul_f32(self.fraction.clamp(0., 1.));
+        timer.set_elapsed(elapsed);
+    }
+}
+
+/// An [`EntityCommand`] that temporarily scales the speed of a [`Timer`] on
+/// the target entity. The [`Component`] `T` is used as a tag to identify the
+/// timer.
+///
+/// Use [`EntityCommands::boost_timer`] to queue this command.
+pub struct BoostTimer<T: Component> {
+    scale: f32,
+    for_duration: Duration,
+    marker: PhantomData<T>,
+}
+
+impl<T: Component> BoostTimer<T> {
+    /// Creates a new entity command.
+    pub fn new(scale: f32, for_duration: Duration) -> Self {
+        Self {
+            scale,
+            for_duration,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T: Component> EntityCommand for BoostTimer<T> {
+    fn apply(self, entity: Entity, world: &mut World) {
+        if self.scale.is_nan() || self.scale < 0. {
+            warn!(
+                "not boosting timer {} on {entity}: its scale is {}",
+                core::any::type_name::<T>(),
+                self.scale
+            );
+            return;
+        }
+        let component = world.register_component::<T>();
+
+        let Ok(mut emut) = world.get_entity_mut(entity) else {
+            return;
+        };
+        let Some(mut timers) = emut.get_mut::<Timers>() else {
+            return;
+        };
+        timers.set_boost(
+            component,
+            Boost {
+                scale: self.scale,
+                remaining: self.for_duration,
+            },
+        );
     }
 }
 
@@ -222,7 +1920,10 @@ impl<T: Component> EntityCommand for PauseTimer<T> {
         let Some(timer) = timers.get_mut(component) else {
             return;
         };
-        timer.pause();
+        if !timer.paused() {
+            timer.pause();
+            lifecycle::mirror_pause(world, entity, component, true);
+        }
     }
 }
 
@@ -251,7 +1952,10 @@ impl<T: Component> EntityCommand for UnpauseTimer<T> {
         let Some(timer) = timers.get_mut(component) else {
             return;
         };
-        timer.unpause();
+        if timer.paused() {
+            timer.unpause();
+            lifecycle::mirror_pause(world, entity, component, false);
+        }
     }
 }
 
@@ -270,15 +1974,330 @@ impl<T: Component> Default for CancelTimer<T> {
 impl<T: Component> EntityCommand for CancelTimer<T> {
     fn apply(self, entity: Entity, world: &mut World) {
         let component = world.register_component::<T>();
+        cancel_timer(world, entity, component);
+    }
+}
+
+/// Removes the timer identified by `component` from the entity, triggering
+/// [`OnTimerCancelled`], and returns `true` if there was one.
+pub(crate) fn cancel_timer(world: &mut World, entity: Entity, component: ComponentId) -> bool {
+    let Ok(mut emut) = world.get_entity_mut(entity) else {
+        return false;
+    };
+    let Some(mut timers) = emut.get_mut::<Timers>() else {
+        return false;
+    };
+    if timers.remove(component).is_none() {
+        return false;
+    }
+    world.trigger_targets(
+        OnTimerCancelled {
+            reason: CancelReason::Manual,
+        },
+        TargetBoth::new(entity, component),
+    );
+    true
+}
+
+/// An [`EntityCommand`] that cancels every [`Timer`] on the target entity.
+///
+#[cfg_attr(
+    feature = "bevy_hierarchy",
+    doc = "Set [`recursive`](Self::recursive) to also cancel the timers of its",
+    doc = "descendants.",
+    doc = ""
+)]
+#[cfg_attr(
+    not(feature = "bevy_hierarchy"),
+    doc = "Use [`EntityCommands::cancel_all_timers`] to queue this command."
+)]
+#[cfg_attr(
+    feature = "bevy_hierarchy",
+    doc = "Use [`EntityCommands::cancel_all_timers`] or",
+    doc = "[`EntityCommands::cancel_timers_recursive`] to queue this command."
+)]
+#[derive(Clone, Copy, Default)]
+pub struct CancelAllTimers {
+    /// Whether to also cancel the timers of the entity's descendants.
+    #[cfg(feature = "bevy_hierarchy")]
+    pub recursive: bool,
+}
+
+impl EntityCommand for CancelAllTimers {
+    fn apply(self, entity: Entity, world: &mut World) {
+        let Ok(mut emut) = world.get_entity_mut(entity) else {
+            return;
+        };
+        if let Some(mut timers) = emut.get_mut::<Timers>() {
+            let cancelled: Vec<_> = timers.drain().map(|(component, _)| component).collect();
+            for component in cancelled {
+                world.trigger_targets(
+                    OnTimerCancelled {
+                        reason: CancelReason::Manual,
+                    },
+                    TargetBoth::new(entity, component),
+                );
+            }
+        }
+
+        #[cfg(feature = "bevy_hierarchy")]
+        if self.recursive {
+            let Some(children) = world
+                .get::<bevy_hierarchy::Children>(entity)
+                .map(|children| children.to_vec())
+            else {
+                return;
+            };
+            for child in children {
+                self.apply(child, world);
+            }
+        }
+    }
+}
+
+/// An [`EntityCommand`] that cancels every [`Timer`] on the target entity
+/// whose tag belongs to a category.
+///
+/// Use [`EntityCommands::cancel_category`] to queue this command.
+#[derive(Clone, Copy)]
+pub struct CancelCategory(pub TimerCategory);
+
+impl EntityCommand for CancelCategory {
+    fn apply(self, entity: Entity, world: &mut World) {
+        let Some(categories) = world.get_resource::<TimerCategories>() else {
+            return;
+        };
+        let Some(timers) = world.get::<Timers>(entity) else {
+            return;
+        };
+        let cancelled: Vec<_> = timers
+            .iter()
+            .map(|(&component, _)| component)
+            .filter(|&component| categories.contains(self.0, component))
+            .collect();
+        for component in cancelled {
+            cancel_timer(world, entity, component);
+        }
+    }
+}
+
+/// An [`EntityCommand`] that resets every [`Timer`] on the target entity.
+///
+/// Use [`EntityCommands::reset_all_timers`] to queue this command.
+pub struct ResetAllTimers;
+
+impl EntityCommand for ResetAllTimers {
+    fn apply(self, entity: Entity, world: &mut World) {
+        let Ok(mut emut) = world.get_entity_mut(entity) else {
+            return;
+        };
+        let Some(mut timers) = emut.get_mut::<Timers>() else {
+            return;
+        };
+        timers.reset_all();
+    }
+}
+
+/// An [`EntityCommand`] that removes a [`Timer`] from the target entity
+/// without triggering any event. The [`Component`] `T` is used as a tag to
+/// identify the timer.
+///
+/// Use [`EntityCommands::remove_timer`] to queue this command.
+pub struct RemoveTimer<T: Component>(PhantomData<T>);
+
+impl<T: Component> Default for RemoveTimer<T> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T: Component> EntityCommand for RemoveTimer<T> {
+    fn apply(self, entity: Entity, world: &mut World) {
+        let component = world.register_component::<T>();
+
+        let Ok(mut emut) = world.get_entity_mut(entity) else {
+            return;
+        };
+        let Some(mut timers) = emut.get_mut::<Timers>() else {
+            return;
+        };
+        if timers.remove(component).is_none() {
+            return;
+        }
+        tracking::forget(world, entity, &[component]);
+    }
+}
+
+/// An [`EntityCommand`] that copies a [`Timer`] from another entity to the
+/// target entity. The [`Component`] `T` is used as a tag to identify the
+/// timer.
+///
+/// Use [`EntityCommands::copy_timer_from`] to queue this command.
+pub struct CopyTimer<T: Component> {
+    source: Entity,
+    marker: PhantomData<T>,
+}
+
+impl<T: Component> CopyTimer<T> {
+    /// Creates a new entity command that copies the timer from the entity
+    /// `source`.
+    pub fn new(source: Entity) -> Self {
+        Self {
+            source,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T: Component> EntityCommand for CopyTimer<T> {
+    fn apply(self, entity: Entity, world: &mut World) {
+        let component = world.register_component::<T>();
+        let capacity = world
+            .get_resource::<TimerSettings>()
+            .map_or(0, |settings| settings.default_capacity);
+
+        if entity == self.source || world.get_entity(entity).is_err() {
+            return;
+        }
+        let Some(mut entry) = world
+            .get::<Timers>(self.source)
+            .and_then(|timers| timers.get_entry(component))
+            .cloned()
+        else {
+            return;
+        };
+
+        let mut emut = world.entity_mut(entity);
+        if let Some(gate) = entry.gate {
+            entry.gate_open = emut.contains_id(gate);
+        }
+        let mut timers = emut
+            .entry::<Timers>()
+            .or_insert_with(|| Timers::with_capacity(capacity));
+        let replaced = timers.insert_entry(component, entry).is_some();
+        callback::remove(world, entity, component);
+
+        world.trigger_targets(
+            OnTimerStarted { replaced },
+            TargetBoth::new(entity, component),
+        );
+    }
+}
+
+/// An [`EntityCommand`] that exchanges two [`Timer`]s on the target entity.
+/// The [`Component`]s `A` and `B` are used as tags to identify the timers.
+///
+/// Use [`EntityCommands::swap_timers`] to queue this command.
+pub struct SwapTimers<A: Component, B: Component>(PhantomData<(A, B)>);
+
+impl<A: Component, B: Component> Default for SwapTimers<A, B> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<A: Component, B: Component> EntityCommand for SwapTimers<A, B> {
+    fn apply(self, entity: Entity, world: &mut World) {
+        let a = world.register_component::<A>();
+        let b = world.register_component::<B>();
+
+        let Ok(mut emut) = world.get_entity_mut(entity) else {
+            return;
+        };
+        let Some(mut timers) = emut.get_mut::<Timers>() else {
+            return;
+        };
+        let had = (timers.contains(a), timers.contains(b));
+        timers.swap(a, b);
+        let linked = (timers.is_linked(a), timers.is_linked(b));
+        // Linked timers are only watched under the tag they were started with.
+        if linked.0 == Some(true) {
+            watch_link(world, a);
+        }
+        if linked.1 == Some(true) {
+            watch_link(world, b);
+        }
+        callback::swap(world, entity, a, b);
+        if had.0 != had.1 {
+            tracking::track(world, entity, &[a, b]);
+        }
+    }
+}
+
+/// An [`EntityCommand`] that moves a [`Timer`] from the target entity to
+/// another entity. The [`Component`] `T` is used as a tag to identify the
+/// timer.
+///
+/// Use [`EntityCommands::transfer_timer`] to queue this command.
+pub struct TransferTimer<T: Component> {
+    to: Entity,
+    events: bool,
+    marker: PhantomData<T>,
+}
+
+impl<T: Component> TransferTimer<T> {
+    /// Creates a new entity command that moves the timer to the entity `to`.
+    pub fn new(to: Entity) -> Self {
+        Self {
+            to,
+            events: false,
+            marker: PhantomData,
+        }
+    }
+
+    /// Triggers [`OnTimerCancelled`] on the source entity and
+    /// [`OnTimerStarted`] on the destination entity once the timer is moved.
+    pub fn with_events(mut self) -> Self {
+        self.events = true;
+        self
+    }
+}
+
+impl<T: Component> EntityCommand for TransferTimer<T> {
+    fn apply(self, entity: Entity, world: &mut World) {
+        let component = world.register_component::<T>();
+        let capacity = world
+            .get_resource::<TimerSettings>()
+            .map_or(0, |settings| settings.default_capacity);
 
+        if entity == self.to || world.get_entity(self.to).is_err() {
+            return;
+        }
         let Ok(mut emut) = world.get_entity_mut(entity) else {
             return;
         };
         let Some(mut timers) = emut.get_mut::<Timers>() else {
             return;
         };
-        if timers.remove(component).is_some() {
-            world.trigger_targets(OnTimerCancelled, TargetBoth(entity, component));
+        let Some(mut entry) = timers.remove_entry(component) else {
+            return;
+        };
+
+        let mut emut = world.entity_mut(self.to);
+        if let Some(gate) = entry.gate {
+            entry.gate_open = emut.contains_id(gate);
+        }
+        let mut timers = emut
+            .entry::<Timers>()
+            .or_insert_with(|| Timers::with_capacity(capacity));
+        let replaced = timers.insert_entry(component, entry).is_some();
+        callback::transfer(world, (entity, component), (self.to, component));
+
+        if self.events {
+            world.trigger_targets(
+                OnTimerCancelled {
+                    reason: CancelReason::Manual,
+                },
+                TargetBoth::new(entity, component),
+            );
+            world.trigger_targets(
+                OnTimerStarted { replaced },
+                TargetBoth::new(self.to, component),
+            );
+        } else {
+            // The callback already moved, so forgetting drops nothing.
+            tracking::forget(world, entity, &[component]);
+            tracking::track(world, self.to, &[component]);
         }
     }
 }
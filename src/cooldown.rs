@@ -0,0 +1,132 @@
+//! Provides cooldown groups: several timer tags sharing a single cooldown,
+//! such as abilities on a global cooldown.
+
+use core::time::Duration;
+
+use bevy_ecs::{
+    component::{Component, ComponentId},
+    entity::Entity,
+    system::{EntityCommand, Resource},
+    world::World,
+};
+use bevy_time::{Timer, TimerMode};
+use bevy_utils::HashMap;
+
+use crate::{command::StartTimer, core::Timers};
+
+/// Starts the shared cooldown timer of a group on an entity, for the given
+/// duration.
+type StartGroup = fn(Entity, &mut World, Duration);
+
+/// [`Resource`] defining cooldown groups: sets of timer tags, the members,
+/// that share one cooldown timer tagged by the group's own [`Component`].
+///
+/// Starting a member with
+/// [`start_cooldown`](crate::command::EntityCommandTimersExt::start_cooldown)
+/// also (re)starts the shared timer of its group for the group's duration,
+/// and every member is on cooldown until that timer finishes, see
+/// [`Timers::is_on_cooldown`]. The shared timer is a regular one-shot timer,
+/// so `Trigger<OnTimerFinished, Group>` observers run when it finishes. It can
+/// also be started directly with
+/// [`start_group_cooldown`](crate::command::EntityCommandTimersExt::start_group_cooldown).
+///
+/// Groups are global, while the shared timers are per entity.
+///
+/// # Example
+///
+/// ```
+/// # use std::time::Duration;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_observed_timers::{cooldown::CooldownGroups, prelude::*};
+/// # use bevy_time::prelude::*;
+/// #[derive(Component)]
+/// struct GlobalCooldown;
+/// #[derive(Component)]
+/// struct Fireball;
+/// #[derive(Component)]
+/// struct Frostbolt;
+///
+/// # let mut world = World::new();
+/// CooldownGroups::register::<GlobalCooldown>(&mut world, Duration::from_millis(1500));
+/// CooldownGroups::add_member::<Fireball, GlobalCooldown>(&mut world);
+/// CooldownGroups::add_member::<Frostbolt, GlobalCooldown>(&mut world);
+///
+/// # let e1 = world.spawn_empty().id();
+/// // Puts Frostbolt on cooldown for 1.5s as well.
+/// world.commands().entity(e1)
+///     .start_cooldown::<Fireball>(Timer::from_seconds(8., TimerMode::Once));
+/// ```
+#[derive(Resource, Default)]
+pub struct CooldownGroups {
+    durations: HashMap<ComponentId, Duration>,
+    members: HashMap<ComponentId, ComponentId>,
+    starts: HashMap<ComponentId, StartGroup>,
+}
+
+impl CooldownGroups {
+    /// Defines the cooldown group identified by the [`Component`] `G`, whose
+    /// shared cooldown lasts `duration`, and returns its [`ComponentId`].
+    ///
+    /// Redefining a group replaces its duration and keeps its members.
+    pub fn register<G: Component>(world: &mut World, duration: Duration) -> ComponentId {
+        let group = world.register_component::<G>();
+        let mut groups = world.get_resource_or_init::<CooldownGroups>();
+        groups.durations.insert(group, duration);
+        groups.starts.insert(group, start_group::<G>);
+        group
+    }
+
+    /// Adds the timer tag `T` to the cooldown group `G`.
+    ///
+    /// A tag belongs to at most one group, so this moves `T` out of any group
+    /// it was in.
+    pub fn add_member<T: Component, G: Component>(world: &mut World) {
+        let member = world.register_component::<T>();
+        let group = world.register_component::<G>();
+        world
+            .get_resource_or_init::<CooldownGroups>()
+            .members
+            .insert(member, group);
+    }
+
+    /// Returns the group of the given timer tag, if it belongs to one.
+    pub fn group_of(&self, member: ComponentId) -> Option<ComponentId> {
+        self.members.get(&member).copied()
+    }
+
+    /// Returns the duration of the shared cooldown of the given group, if it
+    /// is defined.
+    pub fn duration(&self, group: ComponentId) -> Option<Duration> {
+        self.durations.get(&group).copied()
+    }
+
+    /// Returns the function starting the shared timer of the given group,
+    /// and its duration, if the group is defined.
+    pub(crate) fn start(&self, group: ComponentId) -> Option<(StartGroup, Duration)> {
+        Some((*self.starts.get(&group)?, self.duration(group)?))
+    }
+}
+
+/// Starts the shared cooldown timer of the group `G`, as
+/// [`start_group_cooldown`](crate::command::EntityCommandTimersExt::start_group_cooldown)
+/// does.
+fn start_group<G: Component>(entity: Entity, world: &mut World, duration: Duration) {
+    StartTimer::<G>::new(Timer::new(duration, TimerMode::Once)).apply(entity, world);
+}
+
+impl Timers {
+    /// Returns `true` if the shared cooldown timer of the given group is not
+    /// running, either because it was never started or because it finished.
+    pub fn is_group_ready(&self, group: ComponentId) -> bool {
+        self.get(group).is_none_or(|timer| timer.finished())
+    }
+
+    /// Returns `true` if the timer tagged with `member`, or the shared
+    /// cooldown timer of its group, is running.
+    pub fn is_on_cooldown(&self, member: ComponentId, groups: &CooldownGroups) -> bool {
+        let own = self.get(member).is_some_and(|timer| !timer.finished());
+        own || groups
+            .group_of(member)
+            .is_some_and(|group| !self.is_group_ready(group))
+    }
+}
@@ -1,15 +1,40 @@
 //! The core functionality of the crate, providing the [`Timers`] component and
 //! the [`tick_entity_timers`] system.
 
+use core::time::Duration;
+
+#[cfg(feature = "bevy_reflect")]
+use bevy_ecs::reflect::ReflectComponent;
 use bevy_ecs::{
-    component::{Component, ComponentId},
+    batching::BatchingStrategy,
+    bundle::Bundle,
+    component::{Component, ComponentId, Components},
     entity::Entity,
-    system::{ParallelCommands, Query, Res},
+    event::Event,
+    observer::{Trigger, TriggerTargets},
+    query::{QueryFilter, QueryItem, With},
+    system::{Commands, In, Local, ParallelCommands, Query, Res, ResMut, Resource, RunSystemOnce},
+    world::{DeferredWorld, World},
 };
-use bevy_time::{Time, Timer, TimerMode};
-use indexmap::IndexMap;
+#[cfg(feature = "bevy_reflect")]
+use bevy_reflect::std_traits::ReflectDefault;
+use bevy_time::{Fixed, Real, Time, Timer, TimerMode, Virtual};
+use bevy_utils::{tracing::warn, HashMap, Parallel};
+use disqualified::ShortName;
 
-use crate::{event::OnTimerFinished, TargetBoth};
+#[cfg(feature = "diagnostics")]
+use crate::diagnostics::TimerDrift;
+use crate::{
+    callback,
+    event::{
+        CancelReason, OnTimerCancelled, OnTimerFinished, OnTimerSecondTick, OnTimerStarted,
+        OnTimerThreshold, OnTimersFinishedBatch,
+    },
+    map::TimerMap,
+    settings::{FinishedEvents, TimerSettings},
+    targets::TargetBoth,
+    tracking,
+};
 
 /// [`Component`] that stores [`Timer`]s for an entity, tagged by [`Component`]s.
 ///
@@ -17,10 +42,351 @@ use crate::{event::OnTimerFinished, TargetBoth};
 /// the [`EntityCommandTimersExt`] trait on [`EntityCommands`] to interact with
 /// it more easily.
 ///
+/// Timers are iterated in insertion order, except that [`Timers::remove`]
+/// moves the last timer into the removed timer's position. The first few
+/// timers are stored inline in the component, without allocating.
+///
 /// [`EntityCommandTimersExt`]: crate::command::EntityCommandTimersExt
 /// [`EntityCommands`]: bevy_ecs::system::EntityCommands
 #[derive(Component, Default)]
-pub struct Timers(IndexMap<ComponentId, Timer>);
+#[component(on_add = warn_if_not_ticked, on_remove = cancel_on_despawn)]
+#[require(TimerConfig)]
+pub struct Timers(TimerMap<TimerEntry>);
+
+/// [`Component`] holding the per-entity configuration of its [`Timers`].
+///
+/// It is a required component of [`Timers`], so a default configuration is
+/// inserted along with the first timer of an entity, unless the entity
+/// already has one. Insert it beforehand, or modify it afterwards, to
+/// configure an entity. The tick systems read it on every tick, so changes
+/// apply from the next tick on. With the `bevy_reflect` feature, it is
+/// reflected, and can be edited in inspectors and saved in scenes.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_observed_timers::{core::TimerConfig, prelude::*};
+/// # use bevy_time::prelude::*;
+/// # #[derive(Component)]
+/// # struct Slowed;
+/// # let mut world = World::new();
+/// // Every timer of this entity runs at half speed.
+/// world
+///     .commands()
+///     .spawn(TimerConfig { scale: 0.5 })
+///     .start_timer::<Slowed>(Timer::from_seconds(2., TimerMode::Once));
+/// ```
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(bevy_reflect::Reflect),
+    reflect(Component, Default)
+)]
+pub struct TimerConfig {
+    /// The factor every delta is multiplied by before ticking the entity's
+    /// timers. Defaults to `1.0`.
+    ///
+    /// `0.0` freezes the timers, and negative or `NaN` scales are treated as
+    /// `0.0`. The scaled delta is what substeps split, see
+    /// [`TimerSettings::max_substep`], and what per-timer boosts then scale
+    /// further (see [`EntityCommandTimersExt::boost_timer`]).
+    ///
+    /// [`EntityCommandTimersExt::boost_timer`]: crate::command::EntityCommandTimersExt::boost_timer
+    pub scale: f32,
+}
+
+impl Default for TimerConfig {
+    fn default() -> Self {
+        Self { scale: 1. }
+    }
+}
+
+/// Optional [`Component`] scaling the delta of an entity's [`Timers`], such
+/// as for a time dilation field slowing down a single unit.
+///
+/// Entities without it tick normally. Negative and `NaN` scales are treated as
+/// `0.0`, freezing the timers.
+///
+/// Scales compose by multiplication: the tick delta, which already reflects
+/// the relative speed of [`Time<Virtual>`] in the usual schedules, is
+/// multiplied by [`TimerConfig::scale`], and then by this scale. Unlike
+/// [`TimerConfig`], which every entity with timers has, this component is
+/// meant to be inserted and removed by gameplay systems as effects come and
+/// go, without touching the entity's own configuration.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_observed_timers::core::LocalTimeScale;
+/// # let mut world = World::new();
+/// # let unit = world.spawn_empty().id();
+/// // The unit enters a stasis field.
+/// world.entity_mut(unit).insert(LocalTimeScale(0.25));
+/// ```
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct LocalTimeScale(pub f32);
+
+impl Default for LocalTimeScale {
+    fn default() -> Self {
+        Self(1.)
+    }
+}
+
+/// Returns `delta` scaled by the entity's [`TimerConfig`] and
+/// [`LocalTimeScale`], saturating on overflow.
+fn scale_delta(
+    delta: Duration,
+    config: Option<&TimerConfig>,
+    local: Option<&LocalTimeScale>,
+) -> Duration {
+    // `max` also maps `NaN` to zero.
+    let scale = config.map_or(1., |config| config.scale.max(0.))
+        * local.map_or(1., |local| local.0.max(0.));
+    if scale == 1. {
+        return delta;
+    }
+    Duration::try_from_secs_f64(delta.as_secs_f64() * f64::from(scale)).unwrap_or(Duration::MAX)
+}
+
+/// [`Resource`] marking that [`tick_entity_timers`], or one of its variants,
+/// is scheduled.
+///
+/// The first time [`Timers`] is added to an entity, a warning is logged if
+/// this resource is absent, since timers would then never advance. When
+/// scheduling the tick system manually, or ticking timers some other way,
+/// insert it to silence the warning:
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_observed_timers::core::TimerTickScheduled;
+/// # let mut world = World::new();
+/// world.init_resource::<TimerTickScheduled>();
+/// ```
+///
+/// The warning is logged at most once per world.
+#[cfg_attr(
+    feature = "bevy_app",
+    doc = "",
+    doc = "The [`ScheduleTimerTickPlugin`] inserts it.",
+    doc = "",
+    doc = "[`ScheduleTimerTickPlugin`]: crate::plugin::ScheduleTimerTickPlugin"
+)]
+#[derive(Resource, Default)]
+pub struct TimerTickScheduled;
+
+/// [`Resource`] marking that the missing tick system warning was logged.
+#[derive(Resource, Default)]
+pub(crate) struct TickWarningLogged;
+
+/// Warns about [`Timers`] being added to a world that does not tick them,
+/// see [`TimerTickScheduled`].
+fn warn_if_not_ticked(mut world: DeferredWorld, entity: Entity, _: ComponentId) {
+    if world.contains_resource::<TimerTickScheduled>()
+        || world.contains_resource::<TickWarningLogged>()
+    {
+        return;
+    }
+    warn!(
+        "timers were added to {entity}, but no system ticks them: add the \
+        `ScheduleTimerTickPlugin`, or insert the `TimerTickScheduled` resource \
+        when scheduling `tick_entity_timers` manually"
+    );
+    world.commands().init_resource::<TickWarningLogged>();
+}
+
+/// Triggers [`OnTimerCancelled`] with [`CancelReason::Despawn`] for the timers
+/// left on a despawned entity.
+fn cancel_on_despawn(mut world: DeferredWorld, entity: Entity, _: ComponentId) {
+    let Some(timers) = world.get::<Timers>(entity) else {
+        return;
+    };
+    if timers.is_empty() {
+        return;
+    }
+    let components: Vec<_> = timers.0.keys().copied().collect();
+    world.commands().queue(move |world: &mut World| {
+        // Only the component was removed, not the entity.
+        if world.get_entity(entity).is_ok() {
+            return;
+        }
+        for component in components {
+            world.trigger_targets(
+                OnTimerCancelled {
+                    reason: CancelReason::Despawn,
+                },
+                TargetBoth::new(entity, component),
+            );
+        }
+    });
+}
+
+/// A [`Timer`] stored in [`Timers`], along with its per-timer options.
+#[derive(Clone)]
+pub(crate) struct TimerEntry {
+    pub(crate) timer: Timer,
+    /// Whether the timer is kept after finishing in [`TimerMode::Once`].
+    pub(crate) persistent: bool,
+    /// The [`Component`] that must be present on the entity for the timer to
+    /// tick.
+    pub(crate) gate: Option<ComponentId>,
+    /// Whether the gate component is currently present on the entity.
+    pub(crate) gate_open: bool,
+    /// The alternating active and gap phases, if any.
+    pub(crate) phases: Option<GapPhases>,
+    /// Whether the timer is cancelled when its tag component is removed.
+    pub(crate) linked: bool,
+    /// The order in which finishes are dispatched within an entity, highest
+    /// first.
+    pub(crate) priority: i32,
+    /// The delay left before the timer starts ticking, if any.
+    pub(crate) delay: Option<StartDelay>,
+    /// The step at which [`TimerProgress`] is updated, for tracked timers.
+    pub(crate) progress_step: Option<f32>,
+    /// Whether the tag component is removed from the entity when the timer
+    /// finishes.
+    pub(crate) removes_tag: bool,
+    /// The sorted fractions at which [`OnTimerThreshold`] is triggered.
+    pub(crate) thresholds: Vec<f32>,
+    /// Whether [`OnTimerSecondTick`] is triggered as the remaining whole
+    /// seconds decrease.
+    pub(crate) second_ticks: bool,
+    /// The number of ticks left before a finished one-shot timer is removed,
+    /// see [`TimerSettings::one_shot_removal_delay`].
+    pub(crate) sweep_in: Option<u32>,
+    /// The number of times a limited repeating timer will still finish.
+    pub(crate) repetitions: Option<u32>,
+    /// The temporary speed boost of the timer, if any.
+    pub(crate) boost: Option<Boost>,
+    /// Whether the timer finished at least once since it was inserted.
+    pub(crate) has_finished: bool,
+    /// The clock ticking the timer, see [`tick_entity_timers_on_clock`].
+    pub(crate) clock: TimerClock,
+}
+
+/// A temporary speed multiplier, see
+/// [`EntityCommandTimersExt::boost_timer`].
+///
+/// [`EntityCommandTimersExt::boost_timer`]: crate::command::EntityCommandTimersExt::boost_timer
+#[derive(Clone, Copy)]
+pub(crate) struct Boost {
+    pub(crate) scale: f32,
+    /// The unscaled ticked time left before the boost ends.
+    pub(crate) remaining: Duration,
+}
+
+impl Boost {
+    /// Counts the boost down by the given unscaled delta, and returns the delta
+    /// to tick the timer by, along with whether the boost ended.
+    fn apply(&mut self, delta: Duration) -> (Duration, bool) {
+        let boosted = delta.min(self.remaining);
+        self.remaining -= boosted;
+        let scaled = Duration::try_from_secs_f64(boosted.as_secs_f64() * f64::from(self.scale))
+            .unwrap_or(Duration::MAX)
+            .saturating_add(delta - boosted);
+        (scaled, self.remaining.is_zero())
+    }
+}
+
+/// The delay before a timer started with
+/// [`EntityCommandTimersExt::start_timer_delayed`] begins ticking.
+///
+/// [`EntityCommandTimersExt::start_timer_delayed`]: crate::command::EntityCommandTimersExt::start_timer_delayed
+#[derive(Clone, Copy)]
+pub(crate) struct StartDelay {
+    pub(crate) remaining: Duration,
+    /// Whether starting the timer replaced another one, reported by the
+    /// deferred [`OnTimerStarted`].
+    pub(crate) replaced: bool,
+}
+
+/// Alternating phases of a timer started with a cooldown gap. Only the end
+/// of an active phase counts as a finish.
+#[derive(Clone, Copy)]
+pub(crate) struct GapPhases {
+    pub(crate) active: Duration,
+    pub(crate) gap: Duration,
+    pub(crate) in_gap: bool,
+}
+
+impl GapPhases {
+    /// Ticks the phase timer by `delta`, switching phases as many times as
+    /// needed, and returns how many active phases finished.
+    ///
+    /// Whole cycles are skipped at once, so a large delta does not loop once
+    /// per phase.
+    fn tick(&mut self, timer: &mut Timer, mut delta: Duration) -> u32 {
+        if timer.paused() {
+            return 0;
+        }
+        if self.active.is_zero() && self.gap.is_zero() {
+            // Both phases are empty, finish once per tick instead of looping.
+            timer.tick(delta);
+            return 1;
+        }
+        let mut finished: u32 = 0;
+        let mut skipped = false;
+        loop {
+            let remaining = timer.remaining();
+            if delta < remaining {
+                timer.tick(delta);
+                return finished;
+            }
+            delta -= remaining;
+            if !self.in_gap {
+                finished = finished.saturating_add(1);
+            }
+            self.in_gap = !self.in_gap;
+            timer.set_duration(if self.in_gap { self.gap } else { self.active });
+            timer.reset();
+            if !skipped {
+                // At the start of a phase, each whole cycle finishes once.
+                skipped = true;
+                let cycle = self.active.saturating_add(self.gap).as_nanos();
+                let cycles = delta.as_nanos() / cycle;
+                finished = finished.saturating_add(cycles.try_into().unwrap_or(u32::MAX));
+                let rest = delta.as_nanos() % cycle;
+                // `rest` is below `delta`, so it fits in a `Duration`.
+                delta = Duration::new((rest / 1_000_000_000) as u64, (rest % 1_000_000_000) as u32);
+            }
+        }
+    }
+}
+
+impl TimerEntry {
+    pub(crate) fn new(timer: Timer) -> Self {
+        Self {
+            timer,
+            persistent: false,
+            gate: None,
+            gate_open: true,
+            phases: None,
+            linked: false,
+            priority: 0,
+            delay: None,
+            progress_step: None,
+            removes_tag: false,
+            thresholds: Vec::new(),
+            second_ticks: false,
+            sweep_in: None,
+            repetitions: None,
+            boost: None,
+            has_finished: false,
+            clock: TimerClock::Virtual,
+        }
+    }
+
+    pub(crate) fn with_gap(active: Duration, gap: Duration) -> Self {
+        let mut entry = Self::new(Timer::new(active, TimerMode::Once));
+        entry.phases = Some(GapPhases {
+            active,
+            gap,
+            in_gap: false,
+        });
+        entry
+    }
+}
 
 impl Timers {
     /// Create a new Timers component.
@@ -28,65 +394,2004 @@ impl Timers {
         Self::default()
     }
 
+    /// Create a new Timers component with room for at least `capacity` timers
+    /// without reallocating.
+    ///
+    /// Timers started with [`EntityCommandTimersExt`] on entities without a
+    /// [`Timers`] component use [`TimerSettings::default_capacity`].
+    ///
+    /// [`EntityCommandTimersExt`]: crate::command::EntityCommandTimersExt
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self(TimerMap::with_capacity(capacity))
+    }
+
+    /// Returns the number of timers that can be held without reallocating.
+    pub fn capacity(&self) -> usize {
+        self.0.capacity()
+    }
+
+    /// Reserves room for at least `additional` more timers.
+    pub fn reserve(&mut self, additional: usize) {
+        self.0.reserve(additional);
+    }
+
+    /// Returns the number of timers.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if there are no timers.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
     /// Returns a reference to the [`Timer`] with the given [`ComponentId`].
     pub fn get(&self, component: ComponentId) -> Option<&Timer> {
-        self.0.get(&component)
+        self.0.get(&component).map(|entry| &entry.timer)
+    }
+
+    /// Returns a reference to the [`Timer`] tagged with the [`Component`] `T`.
+    ///
+    /// The [`ComponentId`] of `T` is looked up in the given [`Components`],
+    /// available in systems as `&Components` or from [`World::components`].
+    /// Returns `None` if `T` was never registered, in which case no timer can
+    /// be tagged with it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::{component::Components, prelude::*};
+    /// # use bevy_observed_timers::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Reload;
+    /// fn show_reload(timers: Query<&Timers>, components: &Components) {
+    ///     for timers in &timers {
+    ///         if let Some(reload) = timers.timer::<Reload>(components) {
+    ///             println!("reloading: {:.0}%", reload.fraction() * 100.);
+    ///         }
+    ///     }
+    /// }
+    /// # bevy_ecs::system::assert_is_system(show_reload);
+    /// ```
+    ///
+    /// [`World::components`]: bevy_ecs::world::World::components
+    pub fn timer<T: Component>(&self, components: &Components) -> Option<&Timer> {
+        self.get(components.component_id::<T>()?)
+    }
+
+    /// Returns `true` if there is a [`Timer`] with the given [`ComponentId`].
+    pub fn contains(&self, component: ComponentId) -> bool {
+        self.0.contains_key(&component)
     }
 
     /// Returns a mutable reference to the [`Timer`] with the given
     /// [`ComponentId`].
     pub fn get_mut(&mut self, component: ComponentId) -> Option<&mut Timer> {
-        self.0.get_mut(&component)
+        self.0.get_mut(&component).map(|entry| &mut entry.timer)
     }
 
     /// Insert a new [`Timer`] identified by the given [`ComponentId`].
-    pub fn insert(&mut self, component: ComponentId, timer: Timer) {
-        self.0.insert(component, timer);
+    ///
+    /// Returns the previous [`Timer`] if one was replaced.
+    pub fn insert(&mut self, component: ComponentId, timer: Timer) -> Option<Timer> {
+        self.insert_entry(component, TimerEntry::new(timer))
+    }
+
+    /// Insert a new persistent [`Timer`] identified by the given
+    /// [`ComponentId`].
+    ///
+    /// Unlike timers added with [`Timers::insert`], a persistent timer in
+    /// [`TimerMode::Once`] is not removed when it finishes, so
+    /// [`Timer::finished`] can still be read afterwards. It must be removed
+    /// manually, for example by cancelling it.
+    ///
+    /// Returns the previous [`Timer`] if one was replaced.
+    pub fn insert_persistent(&mut self, component: ComponentId, timer: Timer) -> Option<Timer> {
+        let mut entry = TimerEntry::new(timer);
+        entry.persistent = true;
+        self.insert_entry(component, entry)
+    }
+
+    /// Insert a new [`Timer`] with a cooldown gap, identified by the given
+    /// [`ComponentId`].
+    ///
+    /// The timer alternates between an active phase lasting `active` and a gap
+    /// phase lasting `gap`, starting with the active phase. Only the end of an
+    /// active phase counts as a finish, so the timer finishes at `active`,
+    /// `2 * active + gap`, `3 * active + 2 * gap`, and so on. The [`Timer`]
+    /// returned by [`Timers::get`] reflects the current phase.
+    ///
+    /// Returns the previous [`Timer`] if one was replaced.
+    pub fn insert_with_gap(
+        &mut self,
+        component: ComponentId,
+        active: Duration,
+        gap: Duration,
+    ) -> Option<Timer> {
+        self.insert_entry(component, TimerEntry::with_gap(active, gap))
+    }
+
+    /// Opens or closes the gate of all timers gated by the given
+    /// [`ComponentId`].
+    pub(crate) fn set_gate_open(&mut self, gate: ComponentId, open: bool) {
+        for entry in self.0.values_mut() {
+            if entry.gate == Some(gate) {
+                entry.gate_open = open;
+            }
+        }
+    }
+
+    /// Returns `true` if any timer is gated by the given [`ComponentId`].
+    pub(crate) fn has_gate(&self, gate: ComponentId) -> bool {
+        self.0.values().any(|entry| entry.gate == Some(gate))
+    }
+
+    pub(crate) fn insert_entry(
+        &mut self,
+        component: ComponentId,
+        entry: TimerEntry,
+    ) -> Option<Timer> {
+        self.0.insert(component, entry).map(|entry| entry.timer)
+    }
+
+    /// Sets the boost of the timer with the given [`ComponentId`], replacing
+    /// any current boost, and returns `true` if there is such a timer.
+    pub(crate) fn set_boost(&mut self, component: ComponentId, boost: Boost) -> bool {
+        let Some(entry) = self.0.get_mut(&component) else {
+            return false;
+        };
+        entry.boost = Some(boost);
+        true
+    }
+
+    pub(crate) fn drain(&mut self) -> impl Iterator<Item = (ComponentId, TimerEntry)> + '_ {
+        self.0.drain()
+    }
+
+    pub(crate) fn get_entry(&self, component: ComponentId) -> Option<&TimerEntry> {
+        self.0.get(&component)
+    }
+
+    pub(crate) fn remove_entry(&mut self, component: ComponentId) -> Option<TimerEntry> {
+        self.0.swap_remove(&component)
+    }
+
+    /// Removes every [`Timer`], keeping the allocated capacity.
+    ///
+    /// No events are triggered. This is meant for entity pooling: clearing
+    /// the [`Timers`] of an entity being recycled, rather than removing the
+    /// component, keeps its storage for the timers started after reuse. Use
+    /// [`EntityCommandTimersExt::cancel_all_timers`] instead when observers
+    /// must know about the removed timers.
+    ///
+    /// [`EntityCommandTimersExt::cancel_all_timers`]: crate::command::EntityCommandTimersExt::cancel_all_timers
+    pub fn clear(&mut self) {
+        self.0.clear();
     }
 
     /// Remove the [`Timer`] identified by the given [`ComponentId`].
     pub fn remove(&mut self, component: ComponentId) -> Option<Timer> {
-        self.0.swap_remove(&component)
+        self.0.swap_remove(&component).map(|entry| entry.timer)
+    }
+
+    /// Moves the [`Timer`] identified by `old` to the [`ComponentId`] `new`,
+    /// keeping its state, options and position, and returns `true` if there
+    /// was such a timer. A timer already identified by `new` is replaced.
+    /// Timers gated on `old` are gated on `new` afterwards.
+    ///
+    /// Gated and linked timers rely on observers of their gate and tag, added
+    /// by the commands that started them, so this does not make them react to
+    /// `new`. [`WorldTimersExt::remap_timer_ids`] adds the missing observers.
+    ///
+    /// No events are triggered. See [`WorldTimersExt::remap_timer_ids`] for
+    /// when ids change, and for migrating every entity at once.
+    ///
+    /// [`WorldTimersExt::remap_timer_ids`]: crate::world::WorldTimersExt::remap_timer_ids
+    pub fn remap_id(&mut self, old: ComponentId, new: ComponentId) -> bool {
+        for entry in self.0.values_mut() {
+            if entry.gate == Some(old) {
+                entry.gate = Some(new);
+            }
+        }
+        self.0.rename(&old, new)
+    }
+
+    /// Exchanges the [`Timer`]s identified by `a` and `b`, along with their
+    /// state and options.
+    ///
+    /// If only one of them exists, it moves to the other [`ComponentId`]. If
+    /// neither exists, this does nothing. Timers keep their position either
+    /// way. No events are triggered.
+    ///
+    /// A linked timer is cancelled by an observer of its tag, added by the
+    /// command that started it, so a linked timer moved to a tag without such
+    /// an observer is not cancelled when that tag is removed. Use
+    /// [`EntityCommandTimersExt::swap_timers`] to keep linked timers linked.
+    ///
+    /// [`EntityCommandTimersExt::swap_timers`]: crate::command::EntityCommandTimersExt::swap_timers
+    pub fn swap(&mut self, a: ComponentId, b: ComponentId) {
+        match (self.0.contains_key(&a), self.0.contains_key(&b)) {
+            (true, true) => self.0.swap_values(&a, &b),
+            (true, false) => {
+                self.0.rename(&a, b);
+            }
+            (false, true) => {
+                self.0.rename(&b, a);
+            }
+            (false, false) => {}
+        }
+    }
+
+    /// Moves every [`Timer`] of `other` into these timers, along with its
+    /// state and options, resolving timers with the same [`ComponentId`] with
+    /// the given [`ConflictPolicy`].
+    ///
+    /// Timers keep their position, and timers only in `other` are appended in
+    /// their order in `other`. A conflicting timer taken from `other` takes
+    /// the position of the timer it replaces. No events are triggered, and
+    /// neither [`TimerIndex`] nor [`HasTimers`] are updated.
+    ///
+    /// Callbacks set with [`start_timer_with_callback`] are kept by entity, so
+    /// they do not move with the timers: use
+    /// [`WorldTimersExt::merge_timers`] to merge the timers of two entities.
+    ///
+    /// [`TimerIndex`]: crate::index::TimerIndex
+    /// [`HasTimers`]: crate::marker::HasTimers
+    /// [`start_timer_with_callback`]: crate::command::EntityCommandTimersExt::start_timer_with_callback
+    /// [`WorldTimersExt::merge_timers`]: crate::world::WorldTimersExt::merge_timers
+    pub fn merge(&mut self, other: Timers, on_conflict: ConflictPolicy) {
+        self.merge_taken(other, on_conflict);
+    }
+
+    /// Like [`merge`](Self::merge), returning the [`ComponentId`]s of the
+    /// timers taken from `other`.
+    pub(crate) fn merge_taken(
+        &mut self,
+        mut other: Timers,
+        on_conflict: ConflictPolicy,
+    ) -> Vec<ComponentId> {
+        let mut taken = Vec::new();
+        self.0.reserve(other.len());
+        for (component, entry) in other.0.drain() {
+            let keep_other = match self.0.get(&component) {
+                None => true,
+                Some(existing) => match on_conflict {
+                    ConflictPolicy::KeepSelf => false,
+                    ConflictPolicy::KeepOther => true,
+                    ConflictPolicy::KeepLongerRemaining => {
+                        total_remaining(&entry) > total_remaining(existing)
+                    }
+                },
+            };
+            if keep_other {
+                self.0.insert(component, entry);
+                taken.push(component);
+            }
+        }
+        taken
+    }
+
+    /// Returns the [`TimerClock`] of the [`Timer`] with the given
+    /// [`ComponentId`], or `None` if there is no such timer.
+    pub fn clock(&self, component: ComponentId) -> Option<TimerClock> {
+        self.0.get(&component).map(|entry| entry.clock)
+    }
+
+    /// Sets the [`TimerClock`] of the [`Timer`] with the given
+    /// [`ComponentId`], returning `false` if there is no such timer.
+    pub fn set_clock(&mut self, component: ComponentId, clock: TimerClock) -> bool {
+        let Some(entry) = self.0.get_mut(&component) else {
+            return false;
+        };
+        entry.clock = clock;
+        true
+    }
+
+    /// Returns `true` if the [`Timer`] with the given [`ComponentId`] is
+    /// paused, or `None` if there is no such timer.
+    ///
+    /// See [`Timer::paused`].
+    pub fn is_paused(&self, component: ComponentId) -> Option<bool> {
+        self.0.get(&component).map(|entry| entry.timer.paused())
+    }
+
+    /// Returns the [`TimerMode`] of the [`Timer`] with the given
+    /// [`ComponentId`], or `None` if there is no such timer.
+    ///
+    /// See [`Timer::mode`].
+    pub fn mode(&self, component: ComponentId) -> Option<TimerMode> {
+        self.0.get(&component).map(|entry| entry.timer.mode())
+    }
+
+    /// Returns the remaining time of the [`Timer`] with the given
+    /// [`ComponentId`], or `None` if there is no such timer.
+    ///
+    /// See [`Timer::remaining`].
+    pub fn remaining(&self, component: ComponentId) -> Option<Duration> {
+        self.0.get(&component).map(|entry| entry.timer.remaining())
+    }
+
+    /// Returns the remaining time in seconds of the [`Timer`] with the given
+    /// [`ComponentId`] as an `f64`, or `None` if there is no such timer.
+    ///
+    /// An `f32` only has 24 bits of precision: at three hours, it rounds to
+    /// about a millisecond, and past a day to several. An `f64` stays precise
+    /// to a few nanoseconds for months, which suits very long cooldowns. Prefer
+    /// [`Timers::remaining`] when the value is not needed as a float, such as
+    /// for comparisons and accumulation, which [`Duration`] does exactly.
+    pub fn remaining_secs_f64(&self, component: ComponentId) -> Option<f64> {
+        self.remaining(component)
+            .map(|remaining| remaining.as_secs_f64())
+    }
+
+    /// Returns the longest remaining time among the [`Timer`]s, or `None` if
+    /// there are no timers running.
+    ///
+    /// The remaining time includes the delay left before delayed timers
+    /// start, see [`EntityCommandTimersExt::start_timer_delayed`]. Paused
+    /// timers are excluded, since they are not counting down. This is meant
+    /// for displays such as the total duration of an entity's effects.
+    ///
+    /// See [`Timer::remaining`].
+    ///
+    /// [`EntityCommandTimersExt::start_timer_delayed`]: crate::command::EntityCommandTimersExt::start_timer_delayed
+    pub fn max_remaining(&self) -> Option<Duration> {
+        self.running_remaining().max()
+    }
+
+    /// Returns the shortest remaining time among the [`Timer`]s, or `None` if
+    /// there are no timers running.
+    ///
+    /// Delays are included and paused timers are excluded, like in
+    /// [`Timers::max_remaining`].
+    pub fn min_remaining(&self) -> Option<Duration> {
+        self.running_remaining().min()
+    }
+
+    /// Returns the [`ComponentId`] and remaining time of the running [`Timer`]
+    /// that finishes soonest, or `None` if there are no timers running.
+    ///
+    /// The remaining time includes the delay left before delayed timers
+    /// start, see [`EntityCommandTimersExt::start_timer_delayed`]. Paused
+    /// timers are excluded, like in [`Timers::min_remaining`]. Ties are
+    /// broken by iteration order, which is insertion order unless timers were
+    /// removed, see [`Timers`].
+    ///
+    /// [`EntityCommandTimersExt::start_timer_delayed`]: crate::command::EntityCommandTimersExt::start_timer_delayed
+    pub fn next_to_finish(&self) -> Option<(ComponentId, Duration)> {
+        self.0
+            .iter()
+            .filter(|(_, entry)| !entry.timer.paused())
+            .map(|(&component, entry)| (component, total_remaining(entry)))
+            .min_by_key(|&(_, remaining)| remaining)
+    }
+
+    /// Returns how long until every running [`Timer`] is done, or `None` if
+    /// there are no timers running.
+    ///
+    /// Unlike [`Timers::max_remaining`], which only looks at the current
+    /// cycle of each timer, this accounts for every remaining cycle of
+    /// limited repeating timers (see
+    /// [`EntityCommandTimersExt::start_timer_repeating_n`]). Both include the
+    /// delay left before delayed timers start.
+    /// Repeating timers without a limit never become idle, so they only
+    /// count until their next finish. Paused timers are excluded, since they
+    /// are not counting down; the result assumes that none of them resume.
+    ///
+    /// [`EntityCommandTimersExt::start_timer_repeating_n`]: crate::command::EntityCommandTimersExt::start_timer_repeating_n
+    pub fn time_until_all_idle(&self) -> Option<Duration> {
+        self.0
+            .values()
+            .filter(|entry| !entry.timer.paused())
+            .map(|entry| {
+                let cycles = entry.repetitions.map_or(0, |left| left.saturating_sub(1));
+                let later = entry
+                    .timer
+                    .duration()
+                    .checked_mul(cycles)
+                    .unwrap_or(Duration::MAX);
+                total_remaining(entry).saturating_add(later)
+            })
+            .max()
+    }
+
+    fn running_remaining(&self) -> impl Iterator<Item = Duration> + '_ {
+        self.0
+            .values()
+            .filter(|entry| !entry.timer.paused())
+            .map(total_remaining)
+    }
+
+    /// Returns `true` if the [`Timer`] with the given [`ComponentId`] is
+    /// persistent, or `None` if there is no such timer.
+    ///
+    /// See [`Timers::insert_persistent`].
+    pub fn is_persistent(&self, component: ComponentId) -> Option<bool> {
+        self.0.get(&component).map(|entry| entry.persistent)
+    }
+
+    /// Returns the [`ComponentId`] gating the [`Timer`] with the given
+    /// [`ComponentId`], if it exists and is gated.
+    ///
+    /// See [`EntityCommandTimersExt::start_timer_gated`].
+    ///
+    /// [`EntityCommandTimersExt::start_timer_gated`]: crate::command::EntityCommandTimersExt::start_timer_gated
+    pub fn gate(&self, component: ComponentId) -> Option<ComponentId> {
+        self.0.get(&component).and_then(|entry| entry.gate)
+    }
+
+    /// Returns `true` if the [`Timer`] with the given [`ComponentId`] is
+    /// cancelled when its tag component is removed, or `None` if there is no
+    /// such timer.
+    ///
+    /// See [`EntityCommandTimersExt::start_timer_linked`].
+    ///
+    /// [`EntityCommandTimersExt::start_timer_linked`]: crate::command::EntityCommandTimersExt::start_timer_linked
+    pub fn is_linked(&self, component: ComponentId) -> Option<bool> {
+        self.0.get(&component).map(|entry| entry.linked)
+    }
+
+    /// Returns the dispatch priority of the [`Timer`] with the given
+    /// [`ComponentId`], or `None` if there is no such timer.
+    ///
+    /// See [`EntityCommandTimersExt::start_timer_with_priority`].
+    ///
+    /// [`EntityCommandTimersExt::start_timer_with_priority`]: crate::command::EntityCommandTimersExt::start_timer_with_priority
+    pub fn priority(&self, component: ComponentId) -> Option<i32> {
+        self.0.get(&component).map(|entry| entry.priority)
+    }
+
+    /// Returns the delay left before the [`Timer`] with the given
+    /// [`ComponentId`] starts ticking, or `None` if there is no such timer or
+    /// it already started.
+    ///
+    /// See [`EntityCommandTimersExt::start_timer_delayed`].
+    ///
+    /// [`EntityCommandTimersExt::start_timer_delayed`]: crate::command::EntityCommandTimersExt::start_timer_delayed
+    pub fn delay_remaining(&self, component: ComponentId) -> Option<Duration> {
+        self.0
+            .get(&component)
+            .and_then(|entry| entry.delay)
+            .map(|delay| delay.remaining)
+    }
+
+    /// Returns the number of times the [`Timer`] with the given
+    /// [`ComponentId`] will still finish, or `None` if there is no such timer
+    /// or it repeats without a limit.
+    ///
+    /// See [`EntityCommandTimersExt::start_timer_repeating_n`].
+    ///
+    /// [`EntityCommandTimersExt::start_timer_repeating_n`]: crate::command::EntityCommandTimersExt::start_timer_repeating_n
+    pub fn repetitions_remaining(&self, component: ComponentId) -> Option<u32> {
+        self.0.get(&component).and_then(|entry| entry.repetitions)
+    }
+
+    /// Returns how many ticks of the given delta the [`Timer`] with the given
+    /// [`ComponentId`] needs to finish, or `None` if there is no such timer,
+    /// it is paused, or the delta is zero.
+    ///
+    /// The count is rounded up, since a tick that only partially covers the
+    /// remaining time still finishes the timer, and includes the delay left
+    /// before delayed timers start. Under a fixed timestep, this is the
+    /// number of fixed steps until [`OnTimerFinished`] is triggered, as long
+    /// as the timer is not boosted or otherwise modified. A finished timer
+    /// that is still kept returns `Some(0)`.
+    pub fn ticks_remaining(&self, component: ComponentId, delta: Duration) -> Option<u64> {
+        let entry = self.0.get(&component)?;
+        if entry.timer.paused() || delta.is_zero() {
+            return None;
+        }
+        let ticks = total_remaining(entry).as_nanos().div_ceil(delta.as_nanos());
+        Some(u64::try_from(ticks).unwrap_or(u64::MAX))
+    }
+
+    /// Returns `true` if the [`Timer`] with the given [`ComponentId`] has
+    /// finished at least once since it was inserted, or `false` if there is no
+    /// such timer.
+    ///
+    /// Unlike [`Timer::finished`], which only reports the last tick, this stays
+    /// `true` across the repeats of repeating timers and the phases of timers
+    /// with a cooldown gap. It is only useful for timers that are kept after
+    /// finishing, since finished one-shot timers are removed unless persistent
+    /// (see [`EntityCommandTimersExt::start_timer_persistent`]). Resetting the
+    /// timer keeps the flag, while starting it again clears it.
+    ///
+    /// [`EntityCommandTimersExt::start_timer_persistent`]: crate::command::EntityCommandTimersExt::start_timer_persistent
+    pub fn has_ever_finished(&self, component: ComponentId) -> bool {
+        self.0
+            .get(&component)
+            .is_some_and(|entry| entry.has_finished)
+    }
+
+    /// Returns `true` if the [`Timer`] with the given [`ComponentId`] is in
+    /// its gap phase, or `None` if there is no such timer or it has no gap.
+    ///
+    /// See [`Timers::insert_with_gap`].
+    pub fn in_gap(&self, component: ComponentId) -> Option<bool> {
+        self.0
+            .get(&component)
+            .and_then(|entry| entry.phases)
+            .map(|phases| phases.in_gap)
+    }
+
+    /// Resets every [`Timer`], in insertion order.
+    ///
+    /// See [`Timer::reset`]. No events are triggered.
+    pub fn reset_all(&mut self) {
+        for entry in self.0.values_mut() {
+            entry.timer.reset();
+        }
+    }
+
+    /// Rewinds every [`Timer`] by the given [`Duration`], reducing its elapsed
+    /// time and clamping it at zero.
+    ///
+    /// Rewinding clears the finished state of the timers, so a timer that just
+    /// finished, or a finished persistent one-shot timer, is no longer
+    /// finished afterwards. Finish events are never triggered by rewinding.
+    pub fn rewind(&mut self, by: Duration) {
+        for entry in self.0.values_mut() {
+            let elapsed = entry.timer.elapsed().saturating_sub(by);
+            entry.timer.reset();
+            entry.timer.set_elapsed(elapsed);
+        }
+    }
+
+    /// Returns a borrow-free snapshot of the current state of every [`Timer`],
+    /// in insertion order.
+    ///
+    /// This allocates a new [`Vec`] on every call, and is intended for
+    /// inspection, debugging, diffing and saving rather than per-frame use.
+    /// Snapshots can be restored with [`Timers::apply_snapshot`].
+    pub fn snapshot(&self) -> Vec<TimerSnapshot> {
+        self.0
+            .iter()
+            .map(|(&component, entry)| TimerSnapshot {
+                component,
+                elapsed: entry.timer.elapsed(),
+                duration: entry.timer.duration(),
+                mode: entry.timer.mode(),
+                paused: entry.timer.paused(),
+            })
+            .collect()
+    }
+
+    /// Restores the state of [`Timer`]s from snapshots taken with
+    /// [`Timers::snapshot`], for example when loading a saved game.
+    ///
+    /// Each timer resumes exactly where it was saved: a timer saved at 80% of
+    /// its duration resumes at 80%, and finishes once the remaining 20% has
+    /// elapsed, rather than restarting. Its duration, mode and paused state
+    /// are restored as well. One-shot timers saved after finishing, which
+    /// only happens for persistent timers, are restored as finished and do
+    /// not finish again.
+    ///
+    /// Timers that already exist keep their per-timer options, such as being
+    /// persistent or gated, and only have their state replaced. Missing timers
+    /// are inserted as with [`Timers::insert`]. No events are triggered.
+    ///
+    /// [`ComponentId`]s are only valid within the world that assigned them, so
+    /// snapshots loaded into another world must be mapped to that world's ids
+    /// first, for example by saving tag names alongside them.
+    pub fn apply_snapshot(&mut self, snapshots: &[TimerSnapshot]) {
+        for snapshot in snapshots {
+            let mut timer = Timer::new(snapshot.duration, snapshot.mode);
+            timer.set_elapsed(snapshot.elapsed);
+            if snapshot.mode == TimerMode::Once && snapshot.elapsed >= snapshot.duration {
+                // Only marks the timer as finished, as `set_elapsed` does not.
+                timer.tick(Duration::ZERO);
+            }
+            if snapshot.paused {
+                timer.pause();
+            }
+            match self.0.get_mut(&snapshot.component) {
+                Some(entry) => entry.timer = timer,
+                None => {
+                    self.insert(snapshot.component, timer);
+                }
+            }
+        }
+    }
+
+    /// Returns an iterator over the short type names of the tags of every
+    /// [`Timer`], in insertion order, such as `"Poison"` for a tag declared
+    /// as `my_game::effects::Poison`.
+    ///
+    /// Names are read from the [`ComponentInfo`] registered in `components`,
+    /// which must come from the world owning these timers. Tags without
+    /// registered info are skipped.
+    ///
+    /// [`ComponentInfo`]: bevy_ecs::component::ComponentInfo
+    pub fn tag_names<'a>(
+        &'a self,
+        components: &'a Components,
+    ) -> impl Iterator<Item = String> + 'a {
+        self.0.keys().filter_map(|&component| {
+            let info = components.get_info(component)?;
+            Some(ShortName(info.name()).to_string())
+        })
+    }
+
+    /// Returns a read-only view of the timers, the recommended way to inspect
+    /// them from tooling.
+    pub fn view(&self) -> TimersView<'_> {
+        TimersView { timers: self }
     }
 
     /// Returns an iterator over the [`Timer`]s and their [`ComponentId`]s.
     pub fn iter(&self) -> impl Iterator<Item = (&ComponentId, &Timer)> {
-        self.0.iter()
+        self.0
+            .iter()
+            .map(|(component, entry)| (component, &entry.timer))
+    }
+
+    /// Returns an iterator over the paused [`Timer`]s and their
+    /// [`ComponentId`]s, in the order of [`Self::iter`].
+    ///
+    /// This filters the same timers as [`Self::iter`] by [`Timer::paused`],
+    /// without copying anything, and [`Self::iter_running`] yields the rest.
+    /// Timers that are not ticked for other reasons, such as a closed gate or
+    /// a pending start delay, are not paused.
+    pub fn iter_paused(&self) -> impl Iterator<Item = (&ComponentId, &Timer)> {
+        self.iter().filter(|(_, timer)| timer.paused())
+    }
+
+    /// Returns an iterator over the [`Timer`]s that are not paused, and their
+    /// [`ComponentId`]s, in the order of [`Self::iter`]. See
+    /// [`Self::iter_paused`].
+    pub fn iter_running(&self) -> impl Iterator<Item = (&ComponentId, &Timer)> {
+        self.iter().filter(|(_, timer)| !timer.paused())
+    }
+
+    /// Returns an iterator over clones of the [`Timer`]s and their
+    /// [`ComponentId`]s.
+    ///
+    /// The timers are cloned up front, so the iterator does not borrow the
+    /// [`Timers`], which can be modified or dropped while the clones are
+    /// processed, for example to diff or send them elsewhere. This allocates,
+    /// and clones every timer once, whether or not the iterator is consumed.
+    /// Prefer [`Self::iter`] when a borrow is not in the way.
+    pub fn iter_owned(&self) -> impl Iterator<Item = (ComponentId, Timer)> {
+        self.iter()
+            .map(|(&component, timer)| (component, timer.clone()))
+            .collect::<Vec<_>>()
+            .into_iter()
     }
 
     /// Returns a mutable iterator over the [`Timer`]s and their [`ComponentId`]s.
     pub fn iter_mut(&mut self) -> impl Iterator<Item = (&ComponentId, &mut Timer)> {
-        self.0.iter_mut()
+        self.0
+            .iter_mut()
+            .map(|(component, entry)| (component, &mut entry.timer))
+    }
+}
+
+/// How [`Timers::merge`] resolves two timers with the same [`ComponentId`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ConflictPolicy {
+    /// Keep the timer being merged into.
+    #[default]
+    KeepSelf,
+    /// Keep the timer being merged in.
+    KeepOther,
+    /// Keep the timer with the longest remaining time, counting the delay
+    /// left before delayed timers start. On a tie, the timer being merged
+    /// into is kept.
+    KeepLongerRemaining,
+}
+
+/// Returns the time left before the timer of the entry finishes, including
+/// its start delay.
+fn total_remaining(entry: &TimerEntry) -> Duration {
+    entry
+        .delay
+        .map_or(Duration::ZERO, |delay| delay.remaining)
+        // Tolerates durations shortened below the elapsed time.
+        .saturating_add(entry.timer.duration().saturating_sub(entry.timer.elapsed()))
+}
+
+/// A read-only view of [`Timers`], as returned by [`Timers::view`].
+///
+/// This is the recommended way for tooling, such as inspectors and debug
+/// overlays, to read timers: it exposes no mutation, and does not depend on
+/// how [`Timers`] stores its timers internally.
+#[derive(Clone, Copy)]
+pub struct TimersView<'a> {
+    timers: &'a Timers,
+}
+
+impl<'a> TimersView<'a> {
+    /// Returns the number of timers.
+    pub fn len(&self) -> usize {
+        self.timers.len()
+    }
+
+    /// Returns `true` if there are no timers.
+    pub fn is_empty(&self) -> bool {
+        self.timers.is_empty()
+    }
+
+    /// Returns the [`Timer`] identified by the given [`ComponentId`], if any.
+    pub fn get(&self, component: ComponentId) -> Option<&'a Timer> {
+        self.timers.get(component)
+    }
+
+    /// Returns an iterator over the [`Timer`]s and their [`ComponentId`]s, in
+    /// the order of [`Timers`].
+    pub fn iter(&self) -> impl Iterator<Item = (ComponentId, &'a Timer)> {
+        self.timers
+            .0
+            .iter()
+            .map(|(&component, entry)| (component, &entry.timer))
+    }
+
+    /// Returns the short type name of the tag identified by the given
+    /// [`ComponentId`], such as `"Poison"` for `my_game::effects::Poison`, or
+    /// `None` if it has no registered info in `components`.
+    ///
+    /// `components` must come from the world owning these timers. See
+    /// [`Timers::tag_names`].
+    pub fn name(&self, component: ComponentId, components: &Components) -> Option<String> {
+        let info = components.get_info(component)?;
+        Some(ShortName(info.name()).to_string())
+    }
+}
+
+/// The state of a single [`Timer`] in [`Timers`], as returned by
+/// [`Timers::snapshot`].
+#[cfg_attr(
+    feature = "bevy_reflect",
+    doc = "",
+    doc = "Snapshots implement [`Reflect`](bevy_reflect::Reflect), and can be saved with",
+    doc = "Bevy's reflection serializers, such as",
+    doc = "[`TypedReflectSerializer`](bevy_reflect::serde::TypedReflectSerializer). The",
+    doc = "type is registered by the [`ScheduleTimerTickPlugin`](crate::plugin::ScheduleTimerTickPlugin).",
+    doc = "Save the tag type paths from",
+    doc = "[`TimerTagPaths::snapshot`](crate::reflect::TimerTagPaths::snapshot) alongside",
+    doc = "them, as the [`ComponentId`]s are only valid in the world that took the",
+    doc = "snapshot.",
+    derive(bevy_reflect::Reflect),
+    reflect(Debug, PartialEq)
+)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimerSnapshot {
+    /// The [`ComponentId`] identifying the timer.
+    pub component: ComponentId,
+    /// The time elapsed on the timer.
+    pub elapsed: Duration,
+    /// The total duration of the timer.
+    pub duration: Duration,
+    /// The mode of the timer.
+    pub mode: TimerMode,
+    /// Whether the timer is paused.
+    pub paused: bool,
+}
+
+/// [`Resource`] listing the timers that finished during the last run of
+/// [`tick_entity_timers`], as `(entity, component)` pairs, along with how many
+/// times each of them finished during that tick.
+///
+/// This is an alternative to observing [`OnTimerFinished`] for systems that
+/// prefer ordered system execution over observers. It is opt-in: the list is
+/// only maintained when this resource exists, for example after calling
+/// `app.init_resource::<TimersFinishedThisFrame>()`.
+///
+/// The list is cleared and refilled every time [`tick_entity_timers`] runs, so
+/// systems reading it must be ordered after [`tick_entity_timers`] in the same
+/// frame. It includes one-shot timers that were removed after finishing. The
+/// order of entries is unspecified.
+///
+/// # Finish counts
+///
+/// With [`TimerSettings::max_fires_per_tick`] set to `1`,
+/// [`OnTimerFinished`] is triggered once per tick, even when a repeating timer
+/// finished several times because of a large delta. Observers can read the
+/// actual count with [`TimersFinishedThisFrame::times_finished_for`], which is
+/// valid while the [`OnTimerFinished`] triggers of the tick are running:
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_observed_timers::prelude::*;
+/// # #[derive(Component)]
+/// # struct Poison;
+/// # let mut world = World::new();
+/// # world.init_resource::<TimersFinishedThisFrame>();
+/// world.insert_resource(TimerSettings {
+///     max_fires_per_tick: 1,
+///     ..Default::default()
+/// });
+/// world.add_observer(|t: Trigger<OnTimerFinished, Poison>, finished: Res<TimersFinishedThisFrame>| {
+///     for _ in 0..finished.times_finished_for(&t) {
+///         // Apply one tick of damage.
+///     }
+/// });
+/// ```
+#[derive(Resource, Default)]
+pub struct TimersFinishedThisFrame(HashMap<(Entity, ComponentId), u32>);
+
+impl TimersFinishedThisFrame {
+    /// Returns an iterator over the `(entity, component)` pairs of timers that
+    /// finished during the last tick.
+    pub fn iter(&self) -> impl Iterator<Item = (Entity, ComponentId)> + '_ {
+        self.0.keys().copied()
+    }
+
+    /// Returns `true` if the timer identified by the given [`ComponentId`]
+    /// finished on the given entity during the last tick.
+    pub fn contains(&self, entity: Entity, component: ComponentId) -> bool {
+        self.0.contains_key(&(entity, component))
+    }
+
+    /// Returns how many times the timer identified by the given
+    /// [`ComponentId`] finished on the given entity during the last tick, or
+    /// `0` if it did not finish.
+    pub fn times_finished(&self, entity: Entity, component: ComponentId) -> u32 {
+        self.0.get(&(entity, component)).copied().unwrap_or(0)
+    }
+
+    /// Returns how many times the timer that caused the given
+    /// [`OnTimerFinished`] trigger finished during the last tick.
+    ///
+    /// Only valid while handling a trigger of the last tick; returns `0` for
+    /// triggers that were not caused by [`tick_entity_timers`].
+    pub fn times_finished_for<B: Bundle>(&self, trigger: &Trigger<OnTimerFinished, B>) -> u32 {
+        trigger.components().first().map_or(0, |&component| {
+            self.times_finished(trigger.entity(), component)
+        })
+    }
+
+    /// Returns the number of timers that finished during the last tick.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if no timers finished during the last tick.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// [`Component`] counting how many times each [`Timer`] on an entity finished
+/// over the entity's lifetime, keyed by the [`ComponentId`] of its tag.
+///
+/// It is opt-in: [`tick_entity_timers`] only inserts it when
+/// [`TimerSettings::count_finishes`] is enabled. It is also kept up to date
+/// on entities it was inserted on manually.
+///
+/// Counts are never reset by the crate, so they persist across the timer
+/// finishing, being restarted with the same tag, or being cancelled.
+#[cfg_attr(
+    feature = "bevy_app",
+    doc = "",
+    doc = "With the `bevy_app` feature, counting can be enabled through",
+    doc = "[`ScheduleTimerTickPlugin::with_finished_count`].",
+    doc = "",
+    doc = "[`ScheduleTimerTickPlugin::with_finished_count`]: crate::plugin::ScheduleTimerTickPlugin::with_finished_count"
+)]
+#[derive(Component, Default, Debug, Clone)]
+pub struct TimerFinishedCount(HashMap<ComponentId, u32>);
+
+impl TimerFinishedCount {
+    /// Returns how many times the [`Timer`] tagged with the given
+    /// [`ComponentId`] finished, or `0` if it never did.
+    pub fn get(&self, component: ComponentId) -> u32 {
+        self.0.get(&component).copied().unwrap_or(0)
+    }
+
+    /// Returns an iterator over the tags of timers that finished at least
+    /// once, along with their counts.
+    pub fn iter(&self) -> impl Iterator<Item = (ComponentId, u32)> + '_ {
+        self.0.iter().map(|(&component, &count)| (component, count))
+    }
+
+    fn add(&mut self, component: ComponentId, times: u32) {
+        *self.0.entry(component).or_default() += times;
+    }
+}
+
+/// [`Resource`] that freezes every [`Timer`] while set to `true`.
+///
+/// While paused, [`tick_entity_timers`] does not tick anything. Pausing is
+/// layered: a timer ticks only when neither the resource nor the timer itself
+/// is paused. Since the global pause skips ticking rather than touching the
+/// timers, the two layers are independent: a timer paused with
+/// [`EntityCommandTimersExt::pause_timer`] stays paused once the resource is
+/// set back to `false`, and unpausing a timer while the resource is `true`
+/// does not make it tick.
+///
+/// [`EntityCommandTimersExt::pause_timer`]: crate::command::EntityCommandTimersExt::pause_timer
+#[cfg_attr(
+    feature = "bevy_state",
+    doc = "",
+    doc = "With the `bevy_state` feature, [`PauseTimersInState`] toggles this resource",
+    doc = "on state transitions.",
+    doc = "",
+    doc = "[`PauseTimersInState`]: crate::plugin::PauseTimersInState"
+)]
+#[derive(Resource, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimersPaused(pub bool);
+
+/// [`Component`] holding the progress of tracked [`Timer`]s on an entity,
+/// keyed by the [`ComponentId`] of their tag, and rounded down to a step.
+///
+/// [`Timers`] changes every time it is ticked, so `Changed<Timers>` is true on
+/// every frame a timer runs. This component only changes when the progress of
+/// a tracked timer crosses a step boundary, which makes `Changed<TimerProgress>`
+/// suitable for rebuilding cooldown bars only when they would look different.
+///
+/// Timers are tracked when started with
+/// [`EntityCommandTimersExt::start_timer_tracked`], which inserts this
+/// component if needed. The progress of a tracked timer is kept after it
+/// finishes or is cancelled, until a tracked timer with the same tag is
+/// started again.
+///
+/// [`EntityCommandTimersExt::start_timer_tracked`]: crate::command::EntityCommandTimersExt::start_timer_tracked
+#[derive(Component, Default, Debug, Clone)]
+pub struct TimerProgress(HashMap<ComponentId, f32>);
+
+impl TimerProgress {
+    /// Returns the progress of the tracked [`Timer`] with the given
+    /// [`ComponentId`], between `0.0` and `1.0` and rounded down to its step,
+    /// or `None` if it is not tracked.
+    pub fn get(&self, component: ComponentId) -> Option<f32> {
+        self.0.get(&component).copied()
+    }
+
+    /// Returns an iterator over the tags of tracked timers and their progress.
+    pub fn iter(&self) -> impl Iterator<Item = (ComponentId, f32)> + '_ {
+        self.0
+            .iter()
+            .map(|(&component, &fraction)| (component, fraction))
+    }
+
+    /// Rounds `fraction` down to a multiple of `step`.
+    pub(crate) fn quantize(fraction: f32, step: f32) -> f32 {
+        if fraction >= 1. {
+            return 1.;
+        }
+        ((fraction / step).floor() * step).min(1.)
+    }
+
+    pub(crate) fn set(&mut self, component: ComponentId, fraction: f32) {
+        self.0.insert(component, fraction);
     }
 }
 
+/// Run condition that skips [`tick_entity_timers`] while the [`Time`] delta
+/// is zero, for example while [`Time<Virtual>`] is paused.
+///
+/// Ticking with a zero delta does not advance any timer, so this avoids
+/// iterating every entity with [`Timers`] for nothing. The system still runs
+/// once more after pausing if [`TimersFinishedThisFrame`] is not empty, so
+/// that it gets cleared.
+///
+/// Skipped ticks are observable, which is why this condition is opt-in:
+/// timers with a zero duration wait for the next non-zero delta to finish,
+/// and paused frames do not count towards
+/// [`TimerSettings::one_shot_removal_delay`].
+///
+/// The condition passes when the [`Time`] resource is missing, so that the
+/// tick system can warn about it.
+///
+/// [`Time<Virtual>`]: bevy_time::Virtual
+#[cfg_attr(
+    feature = "bevy_app",
+    doc = "",
+    doc = "The [`ScheduleTimerTickPlugin`] adds this condition to the tick system",
+    doc = "when enabled with [`ScheduleTimerTickPlugin::with_skip_zero_delta`].",
+    doc = "",
+    doc = "[`ScheduleTimerTickPlugin`]: crate::plugin::ScheduleTimerTickPlugin",
+    doc = "[`ScheduleTimerTickPlugin::with_skip_zero_delta`]: crate::plugin::ScheduleTimerTickPlugin::with_skip_zero_delta"
+)]
+pub fn time_is_advancing(
+    time: Option<Res<Time>>,
+    finished_this_frame: Option<Res<TimersFinishedThisFrame>>,
+) -> bool {
+    // Lets the tick system run and warn about the missing clock.
+    let Some(time) = time else {
+        return true;
+    };
+    !time.delta().is_zero() || finished_this_frame.is_some_and(|finished| !finished.is_empty())
+}
+
 /// [`System`] that ticks [`Timers`] on entities, and triggers
 /// [`OnTimerFinished`] when a timer just finished.
 ///
-/// This system can be scheduled with the [`ScheduleTimerTickPlugin`] plugin,
-/// or added to a schedule manually.
+/// When a large delta makes a repeating timer finish several times, or spans
+/// several phases of a timer with a cooldown gap (see
+/// [`Timers::insert_with_gap`]), [`OnTimerFinished`] is triggered once per
+/// finish, up to [`TimerSettings::max_fires_per_tick`]. The full count,
+/// including finishes past that cap, can be read from
+/// [`TimersFinishedThisFrame`].
+///
+/// With [`TimerSettings::max_substep`] set, the delta is split into substeps
+/// no longer than it, and each substep is a separate tick in the above sense:
+/// a timer can finish once per substep, and finishes are dispatched substep
+/// by substep. The substeps are capped at [`TimerSettings::MAX_SUBSTEPS`].
+///
+/// With [`FinishedEvents::Batched`] or [`FinishedEvents::Both`] set in
+/// [`TimerSettings`], a single [`OnTimersFinishedBatch`] is also triggered per
+/// entity, after its individual [`OnTimerFinished`] triggers.
+///
+/// Gated timers (see [`EntityCommandTimersExt::start_timer_gated`]) are only
+/// ticked while their entity has the gate component.
+///
+/// Within an entity, finishes are dispatched from the highest to the lowest
+/// priority (see [`EntityCommandTimersExt::start_timer_with_priority`]), and
+/// timers with equal priorities keep their order in [`Timers`].
+///
+/// Delayed timers (see [`EntityCommandTimersExt::start_timer_delayed`]) count
+/// down their delay first, and [`OnTimerStarted`] is triggered once it
+/// elapses.
+///
+/// Lifetime finish counts are accumulated in [`TimerFinishedCount`].
+///
+/// Events are triggered one after the other once the system's commands are
+/// applied, and the commands queued by the observers of an event are applied
+/// before the next event is triggered. If an observer despawns the entity,
+/// its remaining events from the same tick are skipped, so observers never
+/// run for an entity that no longer exists.
+///
+/// Nothing is ticked while the [`TimersPaused`] resource is set to `true`.
+/// Nothing is ticked either when the [`Time`] resource is missing, as in
+/// minimal worlds that never initialized it, and a warning is logged the
+/// first time instead of panicking.
+///
+/// For development only, the `debug-invariants` feature makes the tick panic
+/// in builds with debug assertions when it finds timers in a state it never
+/// leaves them in, such as a finished one-shot timer that was not removed.
+/// This helps catching bugs in code manipulating [`Timers`] directly.
+///
+/// Entities are ticked in parallel, so events of different entities are
+/// triggered in an unspecified order. Use [`tick_entity_timers_ordered`]
+/// instead when that order must be deterministic.
+///
+/// This system can be added to a schedule manually. Timers started through
+/// commands are only ticked once the commands are applied, so whether a timer
+/// is ticked in the frame it is started in depends on system ordering.
 ///
 /// [`System`]: bevy_ecs::system::System
-/// [`ScheduleTimerTickPlugin`]: crate::plugin::ScheduleTimerTickPlugin
+/// [`FinishedEvents::Batched`]: crate::settings::FinishedEvents::Batched
+/// [`FinishedEvents::Both`]: crate::settings::FinishedEvents::Both
+/// [`EntityCommandTimersExt::start_timer_gated`]: crate::command::EntityCommandTimersExt::start_timer_gated
+/// [`EntityCommandTimersExt::start_timer_with_priority`]: crate::command::EntityCommandTimersExt::start_timer_with_priority
+/// [`EntityCommandTimersExt::start_timer_delayed`]: crate::command::EntityCommandTimersExt::start_timer_delayed
+#[cfg_attr(
+    feature = "bevy_app",
+    doc = "",
+    doc = "With the `bevy_app` feature, it can also be scheduled with the",
+    doc = "[`ScheduleTimerTickPlugin`]. See [`TimerTickSet`] for ordering systems",
+    doc = "relative to the tick.",
+    doc = "",
+    doc = "[`ScheduleTimerTickPlugin`]: crate::plugin::ScheduleTimerTickPlugin",
+    doc = "[`TimerTickSet`]: crate::plugin::TimerTickSet"
+)]
+#[allow(clippy::too_many_arguments)]
 pub fn tick_entity_timers(
-    mut timers: Query<(Entity, &mut Timers)>,
-    time: Res<Time>,
+    timers: Query<TickData>,
+    time: Option<Res<Time>>,
+    mut time_warned: Local<bool>,
+    par_commands: ParallelCommands,
+    settings: Option<Res<TimerSettings>>,
+    finished_this_frame: Option<ResMut<TimersFinishedThisFrame>>,
+    finished_local: Local<Parallel<Vec<FinishRecord>>>,
+    paused: Option<Res<TimersPaused>>,
+    #[cfg(feature = "diagnostics")] drift: Option<ResMut<TimerDrift>>,
+) {
+    let Some(delta) = time_delta(time, &mut time_warned) else {
+        return;
+    };
+    tick_timers(
+        timers,
+        delta,
+        None,
+        par_commands,
+        settings,
+        finished_this_frame,
+        finished_local,
+        paused,
+        #[cfg(feature = "diagnostics")]
+        drift,
+    );
+}
+
+/// [`System`] that behaves like [`tick_entity_timers`], but only ticks the
+/// [`Timers`] of entities with the [`Component`] `M`.
+///
+/// This allows ticking groups of entities separately, for example on
+/// different clocks or in different schedules, by adding one system per
+/// marker. Entities without any of the markers are not ticked by these
+/// systems, and can be ticked manually. Avoid also running the unfiltered
+/// [`tick_entity_timers`], which would tick the marked entities twice.
+///
+/// Each tick system refills [`TimersFinishedThisFrame`] when it runs, so with
+/// several of them the resource only lists the finishes of the last one.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_observed_timers::core::tick_entity_timers_filtered;
+/// #[derive(Component)]
+/// struct Gameplay;
+///
+/// #[derive(Component)]
+/// struct Ui;
+///
+/// # let mut schedule = Schedule::default();
+/// schedule.add_systems((
+///     tick_entity_timers_filtered::<Gameplay>,
+///     tick_entity_timers_filtered::<Ui>,
+/// ));
+/// ```
+///
+/// [`System`]: bevy_ecs::system::System
+#[allow(clippy::too_many_arguments)]
+pub fn tick_entity_timers_filtered<M: Component>(
+    timers: Query<TickData, With<M>>,
+    time: Option<Res<Time>>,
+    mut time_warned: Local<bool>,
+    par_commands: ParallelCommands,
+    settings: Option<Res<TimerSettings>>,
+    finished_this_frame: Option<ResMut<TimersFinishedThisFrame>>,
+    finished_local: Local<Parallel<Vec<FinishRecord>>>,
+    paused: Option<Res<TimersPaused>>,
+    #[cfg(feature = "diagnostics")] drift: Option<ResMut<TimerDrift>>,
+) {
+    let Some(delta) = time_delta(time, &mut time_warned) else {
+        return;
+    };
+    tick_timers(
+        timers,
+        delta,
+        None,
+        par_commands,
+        settings,
+        finished_this_frame,
+        finished_local,
+        paused,
+        #[cfg(feature = "diagnostics")]
+        drift,
+    );
+}
+
+/// The clock a [`Timer`] is ticked by, when ticking timers per clock with
+/// [`tick_entity_timers_on_clock`].
+///
+/// Timers use [`TimerClock::Virtual`] unless started with another clock, see
+/// [`EntityCommandTimersExt::start_timer_on_clock`]. The clock is ignored by
+/// the other tick systems, such as [`tick_entity_timers`], which tick every
+/// timer by the delta of the generic [`Time`].
+///
+/// [`EntityCommandTimersExt::start_timer_on_clock`]: crate::command::EntityCommandTimersExt::start_timer_on_clock
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum TimerClock {
+    /// Game time, which can be paused and scaled, see [`Time<Virtual>`].
+    #[default]
+    Virtual,
+    /// The fixed timestep, see [`Time<Fixed>`].
+    Fixed,
+    /// Wall-clock time, which is unaffected by pausing and scaling, see
+    /// [`Time<Real>`].
+    Real,
+}
+
+/// A [`Time`] context matching a [`TimerClock`], used to select the timers
+/// ticked by [`tick_entity_timers_on_clock`].
+pub trait TimerClockContext: Default + Send + Sync + 'static {
+    /// The clock of the timers ticked with this context.
+    const CLOCK: TimerClock;
+}
+
+impl TimerClockContext for Virtual {
+    const CLOCK: TimerClock = TimerClock::Virtual;
+}
+
+impl TimerClockContext for Fixed {
+    const CLOCK: TimerClock = TimerClock::Fixed;
+}
+
+impl TimerClockContext for Real {
+    const CLOCK: TimerClock = TimerClock::Real;
+}
+
+/// [`System`] that behaves like [`tick_entity_timers`], but only ticks the
+/// timers on the [`TimerClock`] of `C`, by the delta of [`Time<C>`].
+///
+/// Each timer carries a clock, so timers on different clocks can live on the
+/// same entity. Adding one system per clock, each in a schedule running at
+/// the pace of its clock, ticks every timer exactly once per step of its
+/// clock: [`Fixed`] in a fixed schedule such as `FixedPreUpdate`, and
+/// [`Virtual`] and [`Real`] in a schedule running once per frame. Avoid also
+/// running the tick systems that ignore clocks, which would tick timers twice.
+///
+/// Each tick system refills [`TimersFinishedThisFrame`] when it runs, so with
+/// several of them the resource only lists the finishes of the last one.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_time::{Fixed, Virtual};
+/// # use bevy_observed_timers::core::tick_entity_timers_on_clock;
+/// # let mut frame = Schedule::default();
+/// # let mut fixed = Schedule::default();
+/// frame.add_systems(tick_entity_timers_on_clock::<Virtual>);
+/// fixed.add_systems(tick_entity_timers_on_clock::<Fixed>);
+/// ```
+///
+/// [`System`]: bevy_ecs::system::System
+#[cfg_attr(
+    feature = "bevy_app",
+    doc = "",
+    doc = "The [`ScheduleTimerTickPlugin`] sets this up with",
+    doc = "[`ScheduleTimerTickPlugin::with_timer_clocks`].",
+    doc = "",
+    doc = "[`ScheduleTimerTickPlugin`]: crate::plugin::ScheduleTimerTickPlugin",
+    doc = "[`ScheduleTimerTickPlugin::with_timer_clocks`]: crate::plugin::ScheduleTimerTickPlugin::with_timer_clocks"
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn tick_entity_timers_on_clock<C: TimerClockContext>(
+    timers: Query<TickData>,
+    time: Option<Res<Time<C>>>,
+    mut time_warned: Local<bool>,
     par_commands: ParallelCommands,
+    settings: Option<Res<TimerSettings>>,
+    finished_this_frame: Option<ResMut<TimersFinishedThisFrame>>,
+    finished_local: Local<Parallel<Vec<FinishRecord>>>,
+    paused: Option<Res<TimersPaused>>,
+    #[cfg(feature = "diagnostics")] drift: Option<ResMut<TimerDrift>>,
+) {
+    let Some(delta) = time_delta(time, &mut time_warned) else {
+        return;
+    };
+    tick_timers(
+        timers,
+        delta,
+        Some(C::CLOCK),
+        par_commands,
+        settings,
+        finished_this_frame,
+        finished_local,
+        paused,
+        #[cfg(feature = "diagnostics")]
+        drift,
+    );
+}
+
+/// [`System`] that behaves like [`tick_entity_timers`], but ticks entities
+/// one at a time in a stable order, sorted by [`Entity`].
+///
+/// [`tick_entity_timers`] ticks entities in parallel, so the order in which
+/// the events of different entities are triggered varies between runs. This
+/// system triggers them in the same order every time, which replays and
+/// lockstep networking rely on. Within an entity, the order is the same as in
+/// [`tick_entity_timers`].
+///
+/// This comes at a cost: the entities are collected and sorted on every run,
+/// and ticked on a single thread. Prefer [`tick_entity_timers`] unless
+/// determinism across entities is needed.
+///
+/// [`System`]: bevy_ecs::system::System
+#[allow(clippy::too_many_arguments)]
+pub fn tick_entity_timers_ordered(
+    timers: Query<TickData>,
+    time: Option<Res<Time>>,
+    mut time_warned: Local<bool>,
+    commands: Commands,
+    settings: Option<Res<TimerSettings>>,
+    finished_this_frame: Option<ResMut<TimersFinishedThisFrame>>,
+    paused: Option<Res<TimersPaused>>,
+    #[cfg(feature = "diagnostics")] drift: Option<ResMut<TimerDrift>>,
+) {
+    let Some(delta) = time_delta(time, &mut time_warned) else {
+        return;
+    };
+    tick_timers_ordered(
+        In(delta),
+        timers,
+        commands,
+        settings,
+        finished_this_frame,
+        paused,
+        #[cfg(feature = "diagnostics")]
+        drift,
+    );
+}
+
+/// Ticks every [`Timers`] in the world by exactly `delta`, independently of
+/// [`Time`], and applies the resulting events before returning.
+///
+/// This suits games advancing in discrete units, such as turns or in-game
+/// days: represent a unit as a fixed [`Duration`], and call this from the
+/// system resolving a turn, instead of scheduling [`tick_entity_timers`].
+/// Timers then finish after a whole number of turns, regardless of the
+/// frame rate. Entities are ticked as by [`tick_entity_timers_ordered`], so
+/// events are triggered in the same order every time. [`TimerSettings`] and
+/// [`TimersPaused`] apply as for the tick systems, and
+/// [`TimersFinishedThisFrame`] lists the finishes of the call.
+///
+/// # Example
+///
+/// ```
+/// # use core::time::Duration;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_observed_timers::core::{tick_entity_timers_by, TimerTickScheduled};
+/// /// Timers count turns, each lasting one second.
+/// const TURN: Duration = Duration::from_secs(1);
+///
+/// fn end_turn(world: &mut World) {
+///     // Resolve the turn...
+///     tick_entity_timers_by(world, TURN);
+/// }
+///
+/// # let mut world = World::new();
+/// // Timers are ticked, just not by a tick system.
+/// world.init_resource::<TimerTickScheduled>();
+/// end_turn(&mut world);
+/// ```
+pub fn tick_entity_timers_by(world: &mut World, delta: Duration) {
+    // The system's parameters are always valid, so it always runs.
+    let _ = world.run_system_once_with(delta, tick_timers_ordered);
+    world.flush();
+}
+
+#[allow(clippy::too_many_arguments)]
+fn tick_timers_ordered(
+    In(delta): In<Duration>,
+    mut timers: Query<TickData>,
+    mut commands: Commands,
+    settings: Option<Res<TimerSettings>>,
+    finished_this_frame: Option<ResMut<TimersFinishedThisFrame>>,
+    paused: Option<Res<TimersPaused>>,
+    #[cfg(feature = "diagnostics")] drift: Option<ResMut<TimerDrift>>,
 ) {
-    timers.par_iter_mut().for_each(|(entity, mut timers)| {
+    #[cfg(not(feature = "diagnostics"))]
+    let record_drift = false;
+    #[cfg(feature = "diagnostics")]
+    let record_drift = drift.is_some();
+    let Some(config) = TickConfig::new(delta, settings, &finished_this_frame, paused, record_drift)
+    else {
+        clear_finished(finished_this_frame);
+        return;
+    };
+    let mut entities: Vec<_> = timers.iter_mut().collect();
+    entities.sort_unstable_by_key(|(entity, ..)| *entity);
+    let mut finished_local = Vec::new();
+    for item in entities {
+        let entity = item.0;
+        if let Some(ticked) = tick_entity(item, &config, &mut finished_local) {
+            ticked.dispatch(entity, &mut commands, config.finished_events);
+        }
+    }
+    #[cfg(feature = "diagnostics")]
+    record_drift_stats(drift, &finished_local);
+    record_finished(finished_this_frame, finished_local);
+}
+
+/// The data queried by the tick systems.
+type TickData = (
+    Entity,
+    &'static mut Timers,
+    Option<&'static mut TimerFinishedCount>,
+    Option<&'static mut TimerProgress>,
+    Option<&'static TimerConfig>,
+    Option<&'static LocalTimeScale>,
+);
+
+/// A timer finish recorded during a tick: the entity, the tag, the number of
+/// times the timer finished, and its overshoot when measuring drift.
+type FinishRecord = (Entity, ComponentId, u32, Option<Duration>);
+
+/// The global state shared by every entity during a tick.
+struct TickConfig {
+    delta: Duration,
+    max_substep: Option<Duration>,
+    count_finishes: bool,
+    finished_events: FinishedEvents,
+    record_finished: bool,
+    record_drift: bool,
+    batching: BatchingStrategy,
+    /// The only timer to tick, if not all of them.
+    only: Option<ComponentId>,
+    removal_delay: u32,
+    max_fires: u32,
+    /// The only clock whose timers are ticked, if not all of them.
+    clock: Option<TimerClock>,
+}
+
+impl TickConfig {
+    /// Returns `None` if timers must not be ticked.
+    fn new(
+        delta: Duration,
+        settings: Option<Res<TimerSettings>>,
+        finished_this_frame: &Option<ResMut<TimersFinishedThisFrame>>,
+        paused: Option<Res<TimersPaused>>,
+        record_drift: bool,
+    ) -> Option<Self> {
+        if paused.is_some_and(|paused| paused.0) {
+            return None;
+        }
+        Some(Self {
+            delta,
+            max_substep: settings.as_ref().and_then(|s| s.max_substep),
+            batching: match settings.as_ref().and_then(|s| s.batch_size) {
+                Some(batch_size) => BatchingStrategy::fixed(batch_size),
+                None => BatchingStrategy::new(),
+            },
+            count_finishes: settings.as_ref().is_some_and(|s| s.count_finishes),
+            removal_delay: settings.as_ref().map_or(0, |s| s.one_shot_removal_delay),
+            max_fires: settings
+                .as_ref()
+                .map_or(TimerSettings::DEFAULT_MAX_FIRES_PER_TICK, |s| {
+                    s.max_fires_per_tick
+                }),
+            finished_events: settings.map(|s| s.finished_events).unwrap_or_default(),
+            record_finished: finished_this_frame.is_some(),
+            record_drift,
+            only: None,
+            clock: None,
+        })
+    }
+
+    /// Returns `true` if the timer must not be ticked by this tick.
+    fn skips(&self, component: ComponentId, entry: &TimerEntry) -> bool {
+        self.only.is_some_and(|only| only != component)
+            || self.clock.is_some_and(|clock| clock != entry.clock)
+    }
+}
+
+/// What happened to an entity's timers during a tick, to be dispatched as
+/// events.
+struct EntityTick {
+    started: Vec<(ComponentId, bool)>,
+    thresholds: Vec<(ComponentId, f32)>,
+    second_ticks: Vec<(ComponentId, u32)>,
+    /// The tag, duration, repetitions remaining, priority, and number of
+    /// [`OnTimerFinished`] triggers of each finished timer.
+    finished: Vec<(ComponentId, Duration, Option<u32>, i32, u32)>,
+    /// The tags to remove from the entity, for finished timers started with
+    /// [`start_timer_removing_tag`](crate::command::EntityCommandTimersExt::start_timer_removing_tag).
+    removed_tags: Vec<ComponentId>,
+    /// Finished one-shot timers removed after their removal delay.
+    swept: Vec<ComponentId>,
+    new_count: Option<TimerFinishedCount>,
+    new_progress: Option<TimerProgress>,
+}
+
+impl EntityTick {
+    /// Queues the events, which are each skipped if the entity was despawned
+    /// by the time they would be triggered, for example by an observer of an
+    /// earlier event.
+    fn dispatch(self, entity: Entity, commands: &mut Commands, finished_events: FinishedEvents) {
+        if let Some(new_count) = self.new_count {
+            commands.entity(entity).try_insert(new_count);
+        }
+        if let Some(new_progress) = self.new_progress {
+            commands.entity(entity).try_insert(new_progress);
+        }
+        if !self.swept.is_empty() {
+            // No event reports the removal, so resync the trackers directly.
+            let swept = self.swept;
+            commands.queue(move |world: &mut World| {
+                tracking::forget(world, entity, &swept);
+            });
+        }
+        for (component, replaced) in self.started {
+            trigger_if_alive(
+                commands,
+                entity,
+                OnTimerStarted { replaced },
+                TargetBoth::new(entity, component),
+            );
+        }
+        for (component, fraction) in self.thresholds {
+            trigger_if_alive(
+                commands,
+                entity,
+                OnTimerThreshold { fraction },
+                TargetBoth::new(entity, component),
+            );
+        }
+        for (component, remaining_secs) in self.second_ticks {
+            trigger_if_alive(
+                commands,
+                entity,
+                OnTimerSecondTick { remaining_secs },
+                TargetBoth::new(entity, component),
+            );
+        }
+        let finished_components: Vec<ComponentId> = self
+            .finished
+            .iter()
+            .map(|&(component, ..)| component)
+            .collect();
+        if finished_events.individual() {
+            for &(component, duration, repetitions_remaining, _, fires) in &self.finished {
+                for fire in 1..=fires {
+                    trigger_if_alive(
+                        commands,
+                        entity,
+                        OnTimerFinished {
+                            duration,
+                            // Counts down across the fires of the tick.
+                            repetitions_remaining: repetitions_remaining
+                                .map(|left| left.saturating_add(fires - fire)),
+                        },
+                        TargetBoth::new(entity, component),
+                    );
+                }
+            }
+        }
+        if finished_events.batched() && !self.finished.is_empty() {
+            trigger_if_alive(
+                commands,
+                entity,
+                OnTimersFinishedBatch {
+                    components: finished_components.clone(),
+                },
+                entity,
+            );
+        }
+        if !finished_components.is_empty() {
+            // Once per timer and tick, whatever the fires and finish events.
+            commands.queue(move |world: &mut World| {
+                callback::run_finished(world, entity, &finished_components);
+            });
+        }
+        if !self.removed_tags.is_empty() {
+            // Queued last, so that the finish observers still see the tags.
+            let removed_tags = self.removed_tags;
+            commands.queue(move |world: &mut World| {
+                let Ok(mut emut) = world.get_entity_mut(entity) else {
+                    return;
+                };
+                for component in removed_tags {
+                    emut.remove_by_id(component);
+                }
+            });
+        }
+    }
+}
+
+/// Returns the delta of the [`Time`] resource, or `None` if it is missing, in
+/// which case a warning is logged the first time.
+fn time_delta<C: Default + Send + Sync + 'static>(
+    time: Option<Res<Time<C>>>,
+    warned: &mut bool,
+) -> Option<Duration> {
+    let Some(time) = time else {
+        if !*warned {
+            *warned = true;
+            warn!(
+                "not ticking timers: the {} resource is missing",
+                ShortName::of::<Time<C>>()
+            );
+        }
+        return None;
+    };
+    Some(time.delta())
+}
+
+/// Queues a trigger of `event` that is skipped if the targeted entity no
+/// longer exists when it is applied.
+fn trigger_if_alive(
+    commands: &mut Commands,
+    entity: Entity,
+    event: impl Event,
+    targets: impl TriggerTargets + Send + Sync + 'static,
+) {
+    commands.queue(move |world: &mut World| {
+        if world.get_entity(entity).is_ok() {
+            world.trigger_targets(event, targets);
+        }
+    });
+}
+
+/// Ticks the timers of a single entity, returning the events to dispatch, if
+/// any.
+fn tick_entity(
+    (entity, mut timers, mut count, mut progress, entity_config, local_scale): QueryItem<TickData>,
+    config: &TickConfig,
+    finished_local: &mut Vec<FinishRecord>,
+) -> Option<EntityTick> {
+    let mut new_count = None;
+    let mut new_progress = None;
+    let mut started = Vec::new();
+    let mut thresholds = Vec::new();
+    let mut second_ticks = Vec::new();
+    let mut finished = Vec::new();
+    let mut removed_tags = Vec::new();
+    let swept = sweep_finished(&mut timers, config);
+    let mut remaining = scale_delta(config.delta, entity_config, local_scale);
+    let mut substeps = 0;
+    loop {
+        substeps += 1;
+        let step = match config.max_substep {
+            Some(max_substep)
+                if !max_substep.is_zero() && substeps < TimerSettings::MAX_SUBSTEPS =>
+            {
+                remaining.min(max_substep)
+            }
+            _ => remaining,
+        };
+        remaining -= step;
+
+        let step_start = finished.len();
         let mut finished_timers = Vec::new();
-        par_commands.command_scope(|mut commands| {
-            for (&component, timer) in timers.0.iter_mut() {
-                if timer.tick(time.delta()).just_finished() {
-                    commands.trigger_targets(OnTimerFinished, TargetBoth(entity, component));
-                    if timer.mode() == TimerMode::Once {
-                        finished_timers.push(component);
+        for (&component, entry) in timers.0.iter_mut() {
+            if !entry.gate_open || config.skips(component, entry) {
+                continue;
+            }
+            let mut delta = step;
+            if let Some(delay) = &mut entry.delay {
+                if entry.timer.paused() {
+                    continue;
+                }
+                if delta < delay.remaining {
+                    delay.remaining -= delta;
+                    continue;
+                }
+                // Carry the rest of the delta into the timer.
+                delta -= delay.remaining;
+                started.push((component, delay.replaced));
+                entry.delay = None;
+            }
+            if let Some(boost) = entry.boost.as_mut().filter(|_| !entry.timer.paused()) {
+                let ended;
+                (delta, ended) = boost.apply(delta);
+                if ended {
+                    entry.boost = None;
+                }
+            }
+            let elapsed = entry.timer.elapsed();
+            let before = threshold_fraction(&entry.timer);
+            let secs_before = whole_secs_remaining(&entry.timer);
+            let mut times_finished = match &mut entry.phases {
+                Some(phases) => phases.tick(&mut entry.timer, delta),
+                None => entry.timer.tick(delta).times_finished_this_tick(),
+            };
+            if !entry.thresholds.is_empty() && entry.phases.is_none() {
+                let after = entry.timer.fraction();
+                let crossed = |from: f32, to: f32| {
+                    entry
+                        .thresholds
+                        .iter()
+                        .filter(move |&&t| from < t && t <= to)
+                        .map(move |&t| (component, t))
+                };
+                if times_finished == 0 {
+                    thresholds.extend(crossed(before, after));
+                } else {
+                    // Only the cycles the tick started and ended in, not the
+                    // whole cycles in between.
+                    thresholds.extend(crossed(before, 1.));
+                    let restarts = entry.timer.mode() == TimerMode::Repeating
+                        && entry.repetitions.is_none_or(|left| times_finished < left);
+                    if restarts {
+                        thresholds.extend(crossed(0., after));
                     }
                 }
             }
-        });
+            if entry.second_ticks && entry.phases.is_none() {
+                let after = whole_secs_remaining(&entry.timer);
+                let crossed = |from: u32, to: u32| (to..from).rev().map(move |s| (component, s));
+                if times_finished == 0 {
+                    second_ticks.extend(crossed(secs_before, after));
+                } else {
+                    // The finish itself is reported by `OnTimerFinished`.
+                    second_ticks.extend(crossed(secs_before, 1));
+                    let restarts = entry.timer.mode() == TimerMode::Repeating
+                        && entry.repetitions.is_none_or(|left| times_finished < left);
+                    if restarts {
+                        let cycle = whole_secs(entry.timer.duration());
+                        second_ticks.extend(crossed(cycle, after));
+                    }
+                }
+            }
+            if let Some(step) = entry.progress_step {
+                let fraction = TimerProgress::quantize(entry.timer.fraction(), step);
+                match &mut progress {
+                    // Only mark the component as changed when crossing a step.
+                    Some(progress) if progress.get(component) == Some(fraction) => {}
+                    Some(progress) => progress.set(component, fraction),
+                    None => new_progress
+                        .get_or_insert_with(TimerProgress::default)
+                        .set(component, fraction),
+                }
+            }
+            if times_finished == 0 {
+                continue;
+            }
+            entry.has_finished = true;
+            let cycles = times_finished;
+            if let Some(left) = &mut entry.repetitions {
+                times_finished = times_finished.min(*left);
+                *left -= times_finished;
+                if *left == 0 {
+                    // Stops as a finished one-shot timer would.
+                    let duration = entry.timer.duration();
+                    entry.timer.set_mode(TimerMode::Once);
+                    entry.timer.set_elapsed(duration);
+                    entry.timer.tick(Duration::ZERO);
+                }
+            }
+            let duration = match entry.phases {
+                Some(phases) => phases.active,
+                None => entry.timer.duration(),
+            };
+            let repetitions_remaining = match entry.repetitions {
+                Some(left) => Some(left),
+                None if entry.phases.is_none() && entry.timer.mode() == TimerMode::Once => Some(0),
+                None => None,
+            };
+            let fires = times_finished.clamp(1, config.max_fires.max(1));
+            finished.push((
+                component,
+                duration,
+                repetitions_remaining,
+                entry.priority,
+                fires,
+            ));
+            match &mut count {
+                Some(count) => count.add(component, times_finished),
+                None if config.count_finishes => new_count
+                    .get_or_insert_with(TimerFinishedCount::default)
+                    .add(component, times_finished),
+                None => {}
+            }
+            if config.record_finished || config.record_drift {
+                let overshoot = (config.record_drift && entry.phases.is_none()).then(|| {
+                    // From the unclamped count, as the cycles past the last
+                    // repetition were still ticked through.
+                    let due = duration.checked_mul(cycles).unwrap_or(Duration::MAX);
+                    elapsed.saturating_add(delta).saturating_sub(due)
+                });
+                finished_local.push((entity, component, times_finished, overshoot));
+            }
+            if entry.removes_tag {
+                removed_tags.push(component);
+            }
+            if entry.phases.is_none() && entry.timer.mode() == TimerMode::Once && !entry.persistent
+            {
+                match config.removal_delay {
+                    0 => finished_timers.push(component),
+                    delay => entry.sweep_in = Some(delay),
+                }
+            }
+        }
         for component in finished_timers {
             timers.0.swap_remove(&component);
         }
-    });
+        #[cfg(all(feature = "debug-invariants", debug_assertions))]
+        validate(entity, &timers);
+        // Stable, so equal priorities keep their order.
+        finished[step_start..].sort_by_key(|&(_, _, _, priority, _)| core::cmp::Reverse(priority));
+
+        if remaining.is_zero() {
+            break;
+        }
+    }
+    if started.is_empty()
+        && thresholds.is_empty()
+        && second_ticks.is_empty()
+        && finished.is_empty()
+        && removed_tags.is_empty()
+        && swept.is_empty()
+        && new_progress.is_none()
+    {
+        return None;
+    }
+    Some(EntityTick {
+        started,
+        thresholds,
+        second_ticks,
+        finished,
+        removed_tags,
+        swept,
+        new_count,
+        new_progress,
+    })
+}
+
+/// Removes the finished one-shot timers whose removal delay ran out, before
+/// ticking the entity's timers, and returns their [`ComponentId`]s.
+fn sweep_finished(timers: &mut Timers, config: &TickConfig) -> Vec<ComponentId> {
+    let mut swept = Vec::new();
+    for (&component, entry) in timers.0.iter_mut() {
+        if entry.sweep_in.is_none() || config.skips(component, entry) {
+            continue;
+        }
+        let Some(ticks) = &mut entry.sweep_in else {
+            continue;
+        };
+        // Restarted in place, for example by rewinding.
+        if !entry.timer.finished() {
+            entry.sweep_in = None;
+            continue;
+        }
+        *ticks -= 1;
+        if *ticks == 0 {
+            swept.push(component);
+        }
+    }
+    for component in &swept {
+        timers.0.swap_remove(component);
+    }
+    swept
+}
+
+/// Panics if the timers of the entity are in a state that ticking alone never
+/// leaves them in, which points at a bug in code manipulating [`Timers`]
+/// directly.
+///
+/// Runs after each tick step when the `debug-invariants` feature is enabled,
+/// in builds with debug assertions. Timer ids are unique by construction, and
+/// [`Duration`]s cannot be NaN, so only the following is checked:
+/// - Finished one-shot timers are removed, unless they are persistent or wait
+///   for their removal delay.
+/// - Speed boosts, thresholds and progress steps are not NaN.
+#[cfg(all(feature = "debug-invariants", debug_assertions))]
+fn validate(entity: Entity, timers: &Timers) {
+    for (component, entry) in timers.0.iter() {
+        let left_behind = entry.timer.finished()
+            && entry.timer.mode() == TimerMode::Once
+            && entry.phases.is_none()
+            && !entry.persistent
+            && entry.sweep_in.is_none();
+        assert!(
+            !left_behind,
+            "timer {component:?} of {entity} is a finished one-shot timer that was not removed"
+        );
+        assert!(
+            !entry.boost.is_some_and(|boost| boost.scale.is_nan()),
+            "timer {component:?} of {entity} has a NaN speed boost"
+        );
+        assert!(
+            !entry.thresholds.iter().any(|t| t.is_nan()),
+            "timer {component:?} of {entity} has a NaN threshold"
+        );
+        assert!(
+            !entry.progress_step.is_some_and(f32::is_nan),
+            "timer {component:?} of {entity} has a NaN progress step"
+        );
+    }
+}
+
+/// Ticks a single timer of an entity by `delta`, as the tick systems would,
+/// and applies the resulting events.
+///
+/// [`TimersFinishedThisFrame`] and [`TimersPaused`] are not involved.
+pub(crate) fn tick_single(
+    world: &mut World,
+    entity: Entity,
+    component: ComponentId,
+    delta: Duration,
+) {
+    let settings = world.get_resource::<TimerSettings>();
+    let config = TickConfig {
+        delta,
+        max_substep: settings.and_then(|s| s.max_substep),
+        count_finishes: settings.is_some_and(|s| s.count_finishes),
+        finished_events: settings.map(|s| s.finished_events).unwrap_or_default(),
+        record_finished: false,
+        record_drift: false,
+        batching: BatchingStrategy::new(),
+        only: Some(component),
+        removal_delay: settings.map_or(0, |s| s.one_shot_removal_delay),
+        max_fires: settings.map_or(TimerSettings::DEFAULT_MAX_FIRES_PER_TICK, |s| {
+            s.max_fires_per_tick
+        }),
+        clock: None,
+    };
+    let Ok(item) = world.query::<TickData>().get_mut(world, entity) else {
+        return;
+    };
+    let Some(ticked) = tick_entity(item, &config, &mut Vec::new()) else {
+        return;
+    };
+    ticked.dispatch(entity, &mut world.commands(), config.finished_events);
+    world.flush();
+}
+
+/// Returns the number of seconds in `duration`, rounded up, as reported by
+/// [`OnTimerSecondTick`].
+fn whole_secs(duration: Duration) -> u32 {
+    let secs = duration.as_secs() + u64::from(duration.subsec_nanos() > 0);
+    secs.try_into().unwrap_or(u32::MAX)
+}
+
+/// Returns the whole seconds left before the timer finishes, rounded up.
+fn whole_secs_remaining(timer: &Timer) -> u32 {
+    // Unlike `Timer::remaining`, tolerates durations shortened below the
+    // elapsed time.
+    whole_secs(timer.duration().saturating_sub(timer.elapsed()))
+}
+
+/// Returns the fraction of the timer's duration that has elapsed, from which
+/// thresholds are crossed. Unlike [`Timer::fraction`], this is `0.0` for
+/// zero-duration timers that have not finished yet.
+fn threshold_fraction(timer: &Timer) -> f32 {
+    if timer.duration().is_zero() && !timer.finished() {
+        0.
+    } else {
+        timer.fraction()
+    }
+}
+
+fn clear_finished(finished_this_frame: Option<ResMut<TimersFinishedThisFrame>>) {
+    if let Some(mut finished_this_frame) = finished_this_frame {
+        finished_this_frame.0.clear();
+    }
+}
+
+fn record_finished(
+    finished_this_frame: Option<ResMut<TimersFinishedThisFrame>>,
+    finished: impl IntoIterator<Item = FinishRecord>,
+) {
+    if let Some(mut finished_this_frame) = finished_this_frame {
+        finished_this_frame.0.clear();
+        for (entity, component, times, _) in finished {
+            *finished_this_frame
+                .0
+                .entry((entity, component))
+                .or_default() += times;
+        }
+    }
+}
+
+#[cfg(feature = "diagnostics")]
+fn record_drift_stats(drift: Option<ResMut<TimerDrift>>, finished: &[FinishRecord]) {
+    let Some(mut drift) = drift else {
+        return;
+    };
+    for &(_, component, _, overshoot) in finished {
+        if let Some(overshoot) = overshoot {
+            drift.record(component, overshoot);
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn tick_timers<F: QueryFilter>(
+    mut timers: Query<TickData, F>,
+    delta: Duration,
+    clock: Option<TimerClock>,
+    par_commands: ParallelCommands,
+    settings: Option<Res<TimerSettings>>,
+    finished_this_frame: Option<ResMut<TimersFinishedThisFrame>>,
+    mut finished_local: Local<Parallel<Vec<FinishRecord>>>,
+    paused: Option<Res<TimersPaused>>,
+    #[cfg(feature = "diagnostics")] drift: Option<ResMut<TimerDrift>>,
+) {
+    #[cfg(not(feature = "diagnostics"))]
+    let record_drift = false;
+    #[cfg(feature = "diagnostics")]
+    let record_drift = drift.is_some();
+    let Some(mut config) =
+        TickConfig::new(delta, settings, &finished_this_frame, paused, record_drift)
+    else {
+        clear_finished(finished_this_frame);
+        return;
+    };
+    config.clock = clock;
+    timers
+        .par_iter_mut()
+        .batching_strategy(config.batching.clone())
+        .for_each(|item| {
+            let entity = item.0;
+            let ticked = tick_entity(item, &config, &mut finished_local.borrow_local_mut());
+            if let Some(ticked) = ticked {
+                par_commands.command_scope(|mut commands| {
+                    ticked.dispatch(entity, &mut commands, config.finished_events);
+                });
+            }
+        });
+    #[cfg(feature = "diagnostics")]
+    let finished_local: Vec<_> = finished_local.drain().collect();
+    #[cfg(feature = "diagnostics")]
+    record_drift_stats(drift, &finished_local);
+    #[cfg(not(feature = "diagnostics"))]
+    let finished_local = finished_local.drain();
+    record_finished(finished_this_frame, finished_local);
 }
@@ -0,0 +1,86 @@
+//! Provides the [`TimerDrift`] resource, measuring how late timers finish
+//! because of frame timing.
+//!
+//! Requires the `diagnostics` feature.
+
+use core::time::Duration;
+
+use bevy_ecs::{component::ComponentId, system::Resource};
+use bevy_utils::HashMap;
+
+/// [`Resource`] recording, per timer tag, how far past their duration timers
+/// were when they finished.
+///
+/// Timers only finish on frames, so a timer usually finishes after its
+/// duration has elapsed: this overshoot (`elapsed - duration`) is carried
+/// into the next cycle by repeating timers, but still shifts when observers
+/// run. A large average drift suggests enabling
+/// [`TimerSettings::max_substep`], or ticking in a fixed schedule.
+///
+/// The drift is opt-in: it is only recorded while this resource exists.
+/// Insert it with [`World::init_resource`]. Timers with a cooldown gap are not
+/// measured.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_observed_timers::diagnostics::TimerDrift;
+/// # #[derive(Component)]
+/// # struct Spawner;
+/// # let mut world = World::new();
+/// world.init_resource::<TimerDrift>();
+/// # let spawner = world.register_component::<Spawner>();
+/// // After some ticks:
+/// if let Some(stats) = world.resource::<TimerDrift>().get(spawner) {
+///     println!("average drift: {:?}", stats.average());
+/// }
+/// ```
+///
+/// [`TimerSettings::max_substep`]: crate::settings::TimerSettings::max_substep
+/// [`World::init_resource`]: bevy_ecs::world::World::init_resource
+#[derive(Resource, Default, Debug, Clone)]
+pub struct TimerDrift(HashMap<ComponentId, DriftStats>);
+
+impl TimerDrift {
+    /// Returns the drift statistics of the timers tagged with the given
+    /// [`ComponentId`], or `None` if none of them finished yet.
+    pub fn get(&self, component: ComponentId) -> Option<&DriftStats> {
+        self.0.get(&component)
+    }
+
+    /// Returns an iterator over the drift statistics of every timer tag.
+    pub fn iter(&self) -> impl Iterator<Item = (ComponentId, &DriftStats)> {
+        self.0.iter().map(|(&component, stats)| (component, stats))
+    }
+
+    /// Forgets every recorded finish.
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    pub(crate) fn record(&mut self, component: ComponentId, overshoot: Duration) {
+        let stats = self.0.entry(component).or_default();
+        stats.finishes += 1;
+        stats.total = stats.total.saturating_add(overshoot);
+        stats.last = overshoot;
+    }
+}
+
+/// Drift statistics of the timers sharing a tag, see [`TimerDrift`].
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DriftStats {
+    /// The number of measured finishes.
+    pub finishes: u32,
+    /// The sum of the overshoots of every measured finish.
+    pub total: Duration,
+    /// The overshoot of the last measured finish.
+    pub last: Duration,
+}
+
+impl DriftStats {
+    /// Returns the average overshoot of the measured finishes.
+    pub fn average(&self) -> Duration {
+        self.total.checked_div(self.finishes).unwrap_or_default()
+    }
+}
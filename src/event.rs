@@ -1,10 +1,23 @@
 //! [`Event`]s that are triggered by this crate.
 
-use bevy_ecs::event::Event;
+use core::time::Duration;
+
+use bevy_ecs::{
+    component::ComponentId,
+    event::Event,
+    observer::Trigger,
+    system::{Commands, Resource},
+    world::{OnRemove, World},
+};
+
+use crate::{core::Timers, marker::HasTimers};
 
 /// [`Event`] that is triggered when a [`Timer`] on an entity just finished.
 /// The [`Trigger`] will contain the [`Component`] that identifies the timer.
 ///
+/// The event carries the configured duration of the timer at the time it
+/// finished, and how many more times it will finish.
+///
 /// # Example
 ///
 /// ```
@@ -13,8 +26,135 @@ use bevy_ecs::event::Event;
 /// # #[derive(Component)]
 /// # struct MyComponent;
 /// # let mut world = World::new();
-/// world.add_observer(|_: Trigger<OnTimerFinished, MyComponent>| {
-///     // ...
+/// world.add_observer(|t: Trigger<OnTimerFinished, MyComponent>| {
+///     println!("{:?} cooldown completed", t.event().duration);
+/// });
+/// ```
+///
+/// Since the event also targets the entity, observers can be scoped to a
+/// single entity, and only run for timers finishing on it:
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_observed_timers::prelude::*;
+/// # #[derive(Component)]
+/// # struct MyComponent;
+/// # let mut world = World::new();
+/// world.spawn_empty().observe(|t: Trigger<OnTimerFinished, MyComponent>| {
+///     println!("{} finished its cooldown", t.entity());
+/// });
+/// ```
+///
+/// # Tags with data
+///
+/// Starting a timer tagged with `T` does not insert `T` on the entity: the tag
+/// is only used for its [`ComponentId`]. To carry data with a timer, insert
+/// the tag as a component too, and read it from the observer with a
+/// [`Query`]. The query fails for entities without the component, as for any
+/// other component. Tags removed by
+/// [`start_timer_removing_tag`](crate::command::EntityCommandTimersExt::start_timer_removing_tag)
+/// are removed through commands, so every observer of the finish can still
+/// read them.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_observed_timers::prelude::*;
+/// # use bevy_time::prelude::*;
+/// #[derive(Component)]
+/// struct Poison {
+///     damage: u32,
+/// }
+///
+/// # let mut world = World::new();
+/// world.add_observer(|t: Trigger<OnTimerFinished, Poison>, poison: Query<&Poison>| {
+///     if let Ok(poison) = poison.get(t.entity()) {
+///         println!("{} takes {} damage", t.entity(), poison.damage);
+///     }
+/// });
+/// world
+///     .commands()
+///     .spawn(Poison { damage: 5 })
+///     .start_timer::<Poison>(Timer::from_seconds(1., TimerMode::Repeating));
+/// ```
+///
+/// [`Timer`]: bevy_time::Timer
+/// [`Trigger`]: bevy_ecs::observer::Trigger
+/// [`Component`]: bevy_ecs::component::Component
+/// [`Query`]: bevy_ecs::system::Query
+#[derive(Event)]
+pub struct OnTimerFinished {
+    /// The configured duration of the timer. For timers with a cooldown gap,
+    /// this is the duration of the active phase.
+    pub duration: Duration,
+    /// The number of times the timer will still finish, after this one.
+    ///
+    /// This is `Some(0)` for one-shot timers, and for the final finish of
+    /// timers started with
+    /// [`start_timer_repeating_n`](crate::command::EntityCommandTimersExt::start_timer_repeating_n),
+    /// whose earlier finishes count down to it. Repeating timers without a
+    /// limit, including timers with a cooldown gap, report `None`. When a
+    /// large delta makes a limited timer finish several times in one tick,
+    /// this is the count after the last of them.
+    pub repetitions_remaining: Option<u32>,
+}
+
+/// [`Event`] that is triggered once per entity and tick, listing all of the
+/// entity's [`Timer`]s that finished during the tick. The [`Trigger`] only
+/// targets the entity.
+///
+/// Only triggered when [`TimerSettings::finished_events`] is set to
+/// [`FinishedEvents::Batched`] or [`FinishedEvents::Both`]. With the latter, it
+/// is triggered after the entity's individual [`OnTimerFinished`] triggers.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_observed_timers::prelude::*;
+/// # let mut world = World::new();
+/// world.add_observer(|t: Trigger<OnTimersFinishedBatch>| {
+///     println!("{} timers finished on {}", t.event().components.len(), t.entity());
+/// });
+/// ```
+///
+/// [`Timer`]: bevy_time::Timer
+/// [`Trigger`]: bevy_ecs::observer::Trigger
+/// [`TimerSettings::finished_events`]: crate::settings::TimerSettings::finished_events
+/// [`FinishedEvents::Batched`]: crate::settings::FinishedEvents::Batched
+/// [`FinishedEvents::Both`]: crate::settings::FinishedEvents::Both
+#[derive(Event)]
+pub struct OnTimersFinishedBatch {
+    /// The [`ComponentId`]s identifying the finished timers, in dispatch
+    /// order: by descending priority, then in the entity's timer order.
+    pub components: Vec<ComponentId>,
+}
+
+/// [`Event`] that is triggered when a [`Timer`] is started via
+/// [`start_timer`](crate::command::EntityCommandTimersExt::start_timer).
+/// The [`Trigger`] will contain the [`Component`] that identifies the timer.
+///
+/// `replaced` is `true` when the timer replaced an existing timer with the
+/// same tag that was still present on the entity, and `false` when it was
+/// newly created. One-shot timers are removed when they finish, so restarting
+/// a finished one-shot timer reports `replaced: false`.
+///
+/// For timers started with
+/// [`start_timer_delayed`](crate::command::EntityCommandTimersExt::start_timer_delayed),
+/// it is triggered by [`tick_entity_timers`](crate::core::tick_entity_timers)
+/// once the delay elapses.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_observed_timers::prelude::*;
+/// # #[derive(Component)]
+/// # struct Cast;
+/// # let mut world = World::new();
+/// world.add_observer(|t: Trigger<OnTimerStarted, Cast>| {
+///     if t.event().replaced {
+///         // The ability was recast while still active.
+///     }
 /// });
 /// ```
 ///
@@ -22,10 +162,81 @@ use bevy_ecs::event::Event;
 /// [`Trigger`]: bevy_ecs::observer::Trigger
 /// [`Component`]: bevy_ecs::component::Component
 #[derive(Event)]
-pub struct OnTimerFinished;
+pub struct OnTimerStarted {
+    /// Whether the started timer replaced an existing timer with the same tag.
+    pub replaced: bool,
+}
 
-/// [`Event`] that is triggered when a [`Timer`] is manually cancelled via
+/// [`Event`] that is triggered when a [`Timer`] started with
+/// [`start_timer_with_thresholds`](crate::command::EntityCommandTimersExt::start_timer_with_thresholds)
+/// crosses one of its thresholds. The [`Trigger`] will contain the
+/// [`Component`] that identifies the timer.
+///
+/// A threshold of `1.0` is crossed when the timer finishes, and its event is
+/// triggered just before the timer's [`OnTimerFinished`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_observed_timers::prelude::*;
+/// # #[derive(Component)]
+/// # struct ChargeUp;
+/// # let mut world = World::new();
+/// world.add_observer(|t: Trigger<OnTimerThreshold, ChargeUp>| {
+///     println!("charged to {}%", t.event().fraction * 100.);
+/// });
+/// ```
+///
+/// [`Timer`]: bevy_time::Timer
+/// [`Trigger`]: bevy_ecs::observer::Trigger
+/// [`Component`]: bevy_ecs::component::Component
+#[derive(Event)]
+pub struct OnTimerThreshold {
+    /// The crossed threshold, as a fraction of the timer's duration.
+    pub fraction: f32,
+}
+
+/// [`Event`] that is triggered when the remaining whole seconds of a [`Timer`]
+/// started with
+/// [`start_timer_with_second_ticks`](crate::command::EntityCommandTimersExt::start_timer_with_second_ticks)
+/// decrease. The [`Trigger`] will contain the [`Component`] that identifies
+/// the timer.
+///
+/// The remaining time is rounded up, so a 3 second timer triggers the event
+/// with `2` and `1` once 1 and 2 seconds elapsed. Reaching zero is the
+/// finish, reported by [`OnTimerFinished`] instead, so the last event of a
+/// cycle is triggered with `1`, a second before the timer finishes.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_observed_timers::prelude::*;
+/// # #[derive(Component)]
+/// # struct Countdown;
+/// # let mut world = World::new();
+/// world.add_observer(|t: Trigger<OnTimerSecondTick, Countdown>| {
+///     println!("{}...", t.event().remaining_secs);
+/// });
+/// ```
+///
+/// [`Timer`]: bevy_time::Timer
+/// [`Trigger`]: bevy_ecs::observer::Trigger
+/// [`Component`]: bevy_ecs::component::Component
+#[derive(Event)]
+pub struct OnTimerSecondTick {
+    /// The whole seconds left before the timer finishes, rounded up.
+    pub remaining_secs: u32,
+}
+
+/// [`Event`] that is triggered when a [`Timer`] is cancelled before it
+/// finished, for example via
 /// [`cancel_timer`](crate::command::EntityCommandTimersExt::cancel_timer).
+/// The [`Trigger`] will contain the [`Component`] that identifies the timer.
+///
+/// The `reason` tells manual cancellations apart from automatic ones, see
+/// [`CancelReason`].
 ///
 /// # Example
 ///
@@ -33,13 +244,108 @@ pub struct OnTimerFinished;
 /// # use bevy_ecs::prelude::*;
 /// # use bevy_observed_timers::prelude::*;
 /// # #[derive(Component)]
-/// # struct MyComponent;
+/// # struct Channel;
 /// # let mut world = World::new();
-/// world.add_observer(|_: Trigger<OnTimerCancelled, MyComponent>| {
-///    // ...
+/// world.add_observer(|t: Trigger<OnTimerCancelled, Channel>| {
+///     if t.event().reason == CancelReason::Manual {
+///         // Play the "interrupted" sound.
+///     }
 /// });
 /// ```
 ///
 /// [`Timer`]: bevy_time::Timer
+/// [`Trigger`]: bevy_ecs::observer::Trigger
+/// [`Component`]: bevy_ecs::component::Component
 #[derive(Event)]
-pub struct OnTimerCancelled;
+pub struct OnTimerCancelled {
+    /// Why the timer was cancelled.
+    pub reason: CancelReason,
+}
+
+/// Why a [`Timer`] was cancelled, carried by [`OnTimerCancelled`].
+///
+/// [`Timer`]: bevy_time::Timer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CancelReason {
+    /// The timer was cancelled by a command or system, such as
+    /// [`cancel_timer`](crate::command::EntityCommandTimersExt::cancel_timer),
+    /// [`cancel_all_timers`](crate::command::EntityCommandTimersExt::cancel_all_timers),
+    /// or [`TimerQueryMut::cancel`](crate::query::TimerQueryMut::cancel).
+    Manual,
+    /// The entity was despawned while the timer was running. The event is
+    /// triggered after the despawn, and [`Trigger::entity`] returns the
+    /// despawned entity. Global observers and observers scoped to the tag,
+    /// such as `Trigger<OnTimerCancelled, Tag>`, run for it, while observers
+    /// watching the entity were despawned with it. Unlike the events of
+    /// [`tick_entity_timers`], this event is meant for a dead entity, so
+    /// observers must not assume that it still exists.
+    ///
+    /// Removing the [`Timers`] component from a live entity drops its timers
+    /// without any event.
+    ///
+    /// [`Trigger::entity`]: bevy_ecs::observer::Trigger::entity
+    /// [`tick_entity_timers`]: crate::core::tick_entity_timers
+    Despawn,
+    /// The timer was dropped to make room for others. This crate never evicts
+    /// timers itself; the variant is meant for extensions that do, such as
+    /// capacity limits, triggering the event with
+    /// [`TargetBoth`](crate::targets::TargetBoth).
+    Evicted,
+    /// The tag component of a linked timer was removed from the entity, see
+    /// [`start_timer_linked`](crate::command::EntityCommandTimersExt::start_timer_linked).
+    TagRemoved,
+}
+
+/// [`Event`] that is triggered when the last [`Timer`] of an entity is gone,
+/// because it finished, or was cancelled or removed. The event only targets
+/// the entity.
+///
+/// The event is opt-in: enable it with [`OnAllTimersCleared::register`]. It
+/// is triggered once per transition from having timers to having none, even
+/// when several timers end during the same tick, and again after new timers
+/// are started and end in turn. It is not triggered when the entity is
+/// despawned, nor when [`Timers`] is removed from it.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_observed_timers::{event::OnAllTimersCleared, prelude::*};
+/// # let mut world = World::new();
+/// OnAllTimersCleared::register(&mut world);
+/// world.add_observer(|t: Trigger<OnAllTimersCleared>| {
+///     println!("{} is free of all effects", t.entity());
+/// });
+/// ```
+///
+/// [`Timer`]: bevy_time::Timer
+#[derive(Event, Debug, Clone, Copy)]
+pub struct OnAllTimersCleared;
+
+/// [`Resource`] recording that [`OnAllTimersCleared`] is triggered.
+#[derive(Resource, Default)]
+struct TriggerAllTimersCleared;
+
+impl OnAllTimersCleared {
+    /// Adds the observers that trigger [`OnAllTimersCleared`].
+    ///
+    /// Detecting the last timer relies on the [`HasTimers`] marker, which is
+    /// registered as well, see [`HasTimers::register`]. Does nothing if the
+    /// observers were already added.
+    pub fn register(world: &mut World) {
+        if world.contains_resource::<TriggerAllTimersCleared>() {
+            return;
+        }
+        world.init_resource::<TriggerAllTimersCleared>();
+        HasTimers::register(world);
+        world.add_observer(|t: Trigger<OnRemove, HasTimers>, mut commands: Commands| {
+            let entity = t.entity();
+            commands.queue(move |world: &mut World| {
+                // Neither despawned, nor stripped of its timers, nor restarted.
+                if world.get::<Timers>(entity).is_some_and(Timers::is_empty) {
+                    world.trigger_targets(OnAllTimersCleared, entity);
+                }
+            });
+        });
+    }
+}
@@ -0,0 +1,161 @@
+//! Provides the [`TimerIndex`] resource, a reverse index from timer tags to
+//! the entities timing them.
+
+use bevy_ecs::{
+    component::ComponentId,
+    entity::Entity,
+    observer::Trigger,
+    system::{Query, ResMut, Resource},
+    world::{Mut, OnRemove, World},
+};
+use bevy_utils::{HashMap, HashSet};
+
+use crate::{
+    core::Timers,
+    event::{OnTimerCancelled, OnTimerFinished, OnTimerStarted, OnTimersFinishedBatch},
+};
+
+/// [`Resource`] mapping each timer tag's [`ComponentId`] to the set of
+/// entities that currently have a timer with that tag.
+///
+/// This makes "which entities have a `Reload` timer" an `O(1)` lookup, unlike
+/// [`TimerQuery::entities_with`] which scans every entity with [`Timers`].
+///
+/// The index is opt-in, since keeping it up to date costs an observer run per
+/// timer event. Enable it with [`TimerIndex::register`]. It is kept in sync
+/// through the timer events: starting (delayed timers are indexed from the
+/// start, before their delay elapses), finishing (including the removal of
+/// one-shot timers, immediately or after
+/// [`TimerSettings::one_shot_removal_delay`]), cancelling, and the removal of
+/// the [`Timers`] component, which also covers despawning. Timers inserted
+/// into or removed from [`Timers`] directly, without triggering events, are
+/// not tracked.
+///
+/// [`TimerQuery::entities_with`]: crate::query::TimerQuery::entities_with
+/// [`TimerSettings::one_shot_removal_delay`]: crate::settings::TimerSettings::one_shot_removal_delay
+#[cfg_attr(
+    feature = "bevy_app",
+    doc = "",
+    doc = "With the `bevy_app` feature, it can also be enabled with",
+    doc = "[`ScheduleTimerTickPlugin::with_index`].",
+    doc = "",
+    doc = "[`ScheduleTimerTickPlugin::with_index`]: crate::plugin::ScheduleTimerTickPlugin::with_index"
+)]
+#[derive(Resource, Default)]
+pub struct TimerIndex(HashMap<ComponentId, HashSet<Entity>>);
+
+impl TimerIndex {
+    /// Inserts the [`TimerIndex`] resource into the world, along with the
+    /// observers that keep it up to date.
+    ///
+    /// Does nothing if the resource already exists.
+    pub fn register(world: &mut World) {
+        if world.contains_resource::<TimerIndex>() {
+            return;
+        }
+        world.init_resource::<TimerIndex>();
+        world.add_observer(
+            |t: Trigger<OnTimerStarted>, timers: Query<&Timers>, index: ResMut<TimerIndex>| {
+                sync_observed(t.entity(), t.components(), &timers, index);
+            },
+        );
+        world.add_observer(
+            |t: Trigger<OnTimerFinished>, timers: Query<&Timers>, index: ResMut<TimerIndex>| {
+                sync_observed(t.entity(), t.components(), &timers, index);
+            },
+        );
+        world.add_observer(
+            |t: Trigger<OnTimersFinishedBatch>,
+             timers: Query<&Timers>,
+             index: ResMut<TimerIndex>| {
+                sync_observed(t.entity(), &t.event().components, &timers, index);
+            },
+        );
+        world.add_observer(
+            |t: Trigger<OnTimerCancelled>, timers: Query<&Timers>, index: ResMut<TimerIndex>| {
+                sync_observed(t.entity(), t.components(), &timers, index);
+            },
+        );
+        world.add_observer(
+            |t: Trigger<OnRemove, Timers>,
+             timers: Query<&Timers>,
+             mut index: ResMut<TimerIndex>| {
+                let Ok(timers) = timers.get(t.entity()) else {
+                    return;
+                };
+                for (component, _) in timers.iter() {
+                    index.remove(*component, t.entity());
+                }
+            },
+        );
+    }
+
+    /// Returns the entities that have a timer identified by the given
+    /// [`ComponentId`].
+    pub fn entities(&self, component: ComponentId) -> impl Iterator<Item = Entity> + '_ {
+        self.0.get(&component).into_iter().flatten().copied()
+    }
+
+    /// Returns `true` if the given entity has a timer identified by the given
+    /// [`ComponentId`].
+    pub fn contains(&self, component: ComponentId, entity: Entity) -> bool {
+        self.0
+            .get(&component)
+            .is_some_and(|entities| entities.contains(&entity))
+    }
+
+    /// Returns the number of entities that have a timer identified by the
+    /// given [`ComponentId`].
+    pub fn len(&self, component: ComponentId) -> usize {
+        self.0.get(&component).map_or(0, HashSet::len)
+    }
+
+    pub(crate) fn insert(&mut self, component: ComponentId, entity: Entity) {
+        self.0.entry(component).or_default().insert(entity);
+    }
+
+    pub(crate) fn remove(&mut self, component: ComponentId, entity: Entity) {
+        if let Some(entities) = self.0.get_mut(&component) {
+            entities.remove(&entity);
+            if entities.is_empty() {
+                self.0.remove(&component);
+            }
+        }
+    }
+}
+
+/// Updates the index entries of the given entity and components to match its
+/// [`Timers`], if the index is enabled.
+pub(crate) fn sync(world: &mut World, entity: Entity, components: &[ComponentId]) {
+    if !world.contains_resource::<TimerIndex>() {
+        return;
+    }
+    world.resource_scope(|world, mut index: Mut<TimerIndex>| {
+        let timers = world.get::<Timers>(entity);
+        for &component in components {
+            if timers.is_some_and(|timers| timers.contains(component)) {
+                index.insert(component, entity);
+            } else {
+                index.remove(component, entity);
+            }
+        }
+    });
+}
+
+/// Updates the index entries of the given entity and components to match its
+/// [`Timers`].
+fn sync_observed(
+    entity: Entity,
+    components: &[ComponentId],
+    timers: &Query<&Timers>,
+    mut index: ResMut<TimerIndex>,
+) {
+    let timers = timers.get(entity).ok();
+    for &component in components {
+        if timers.is_some_and(|timers| timers.contains(component)) {
+            index.insert(component, entity);
+        } else {
+            index.remove(component, entity);
+        }
+    }
+}
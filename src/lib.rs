@@ -63,34 +63,65 @@
 
 #![warn(missing_docs)]
 
-use bevy_ecs::{component::ComponentId, entity::Entity, observer::TriggerTargets};
-
+mod callback;
+pub mod category;
 pub mod command;
+pub mod cooldown;
 pub mod core;
+#[cfg(feature = "diagnostics")]
+pub mod diagnostics;
 pub mod event;
+pub mod index;
+pub mod lifecycle;
+mod macros;
+mod map;
+pub mod marker;
 #[cfg(feature = "bevy_app")]
 pub mod plugin;
+pub mod query;
+#[cfg(feature = "bevy_reflect")]
+pub mod reflect;
+pub mod script;
+pub mod settings;
+pub mod targets;
+#[cfg(feature = "test-util")]
+pub mod testing;
+mod tracking;
+pub mod util;
+#[cfg(feature = "async")]
+pub mod wait;
+pub mod world;
 
 pub mod prelude {
     //! Re-exports the most commonly used types and traits.
 
     pub use crate::command::EntityCommandTimersExt as _;
-    pub use crate::core::{tick_entity_timers, Timers};
-    pub use crate::event::{OnTimerCancelled, OnTimerFinished};
+    pub use crate::core::{
+        tick_entity_timers, tick_entity_timers_by, tick_entity_timers_filtered,
+        tick_entity_timers_on_clock, tick_entity_timers_ordered, time_is_advancing, ConflictPolicy,
+        LocalTimeScale, TimerClock, TimerConfig, TimerFinishedCount, TimerProgress, Timers,
+        TimersFinishedThisFrame, TimersPaused,
+    };
+    pub use crate::event::{
+        CancelReason, OnAllTimersCleared, OnTimerCancelled, OnTimerFinished, OnTimerSecondTick,
+        OnTimerStarted, OnTimerThreshold, OnTimersFinishedBatch,
+    };
+    pub use crate::index::TimerIndex;
+    pub use crate::marker::HasTimers;
+    #[cfg(feature = "bevy_app")]
+    pub use crate::plugin::AppTimersExt as _;
     #[cfg(feature = "bevy_app")]
     pub use crate::plugin::ScheduleTimerTickPlugin;
+    pub use crate::query::{TimerQuery, TimerQueryMut};
+    pub use crate::settings::{FinishedEvents, MissingEntityPolicy, TimerSettings};
+    #[cfg(feature = "async")]
+    pub use crate::wait::TimerWaitResult;
+    pub use crate::world::WorldTimersExt as _;
 }
 
-struct TargetBoth(Entity, ComponentId);
-
-impl TriggerTargets for TargetBoth {
-    fn components(&self) -> &[ComponentId] {
-        std::array::from_ref(&self.1)
-    }
-
-    fn entities(&self) -> &[Entity] {
-        std::array::from_ref(&self.0)
-    }
+#[doc(hidden)]
+pub mod __macro_exports {
+    pub use bevy_ecs::{component::Component, observer::Observer};
 }
 
 #[cfg(test)]
@@ -99,19 +130,37 @@ mod tests {
 
     use bevy_ecs::{
         component::Component,
+        entity::Entity,
         observer::Trigger,
-        system::{ResMut, Resource},
+        system::{In, Res, ResMut, Resource},
         world::World,
     };
     use bevy_time::{Time, Timer};
 
     use crate::{
-        command::EntityCommandTimersExt, core::tick_entity_timers, event::OnTimerFinished,
+        category::{TimerCategories, TimerCategory},
+        command::EntityCommandTimersExt,
+        core::{
+            tick_entity_timers, tick_entity_timers_filtered, tick_entity_timers_ordered,
+            time_is_advancing, TimerFinishedCount, TimerSnapshot, Timers, TimersFinishedThisFrame,
+            TimersPaused,
+        },
+        event::{
+            OnTimerCancelled, OnTimerFinished, OnTimerSecondTick, OnTimerStarted, OnTimerThreshold,
+            OnTimersFinishedBatch,
+        },
+        index::TimerIndex,
+        query::TimerQuery,
+        settings::{FinishedEvents, MissingEntityPolicy, TimerSettings},
+        world::WorldTimersExt,
     };
 
     #[derive(Component)]
     struct Foo;
 
+    #[derive(Component)]
+    struct Baz;
+
     #[derive(Resource, Default)]
     struct Finished(bool);
 
@@ -150,4 +199,4270 @@ mod tests {
         world.run_system_cached(tick_entity_timers).unwrap();
         assert!(world.get_resource::<Finished>().unwrap().0);
     }
+
+    #[derive(Resource, Default)]
+    struct Started(Vec<bool>);
+
+    #[test]
+    fn restart_after_finish() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<Started>();
+        world.add_observer(
+            |t: Trigger<OnTimerStarted, Foo>, mut started: ResMut<Started>| {
+                started.0.push(t.event().replaced);
+            },
+        );
+
+        let e1 = world.spawn_empty().id();
+
+        world
+            .commands()
+            .entity(e1)
+            .start_timer::<Foo>(Timer::from_seconds(1., bevy_time::TimerMode::Once))
+            .start_timer::<Foo>(Timer::from_seconds(1., bevy_time::TimerMode::Once));
+        world.flush();
+        assert_eq!(world.resource::<Started>().0, [false, true]);
+
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(1));
+        world.run_system_cached(tick_entity_timers).unwrap();
+
+        world
+            .commands()
+            .entity(e1)
+            .start_timer::<Foo>(Timer::from_seconds(1., bevy_time::TimerMode::Once));
+        world.flush();
+        assert_eq!(world.resource::<Started>().0, [false, true, false]);
+    }
+
+    #[test]
+    fn persistent_once() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<Finished>();
+        world.add_observer(
+            |_: Trigger<OnTimerFinished, Foo>, mut finished: ResMut<Finished>| {
+                assert!(!finished.0, "persistent timer fired twice");
+                finished.0 = true;
+            },
+        );
+
+        let e1 = world.spawn_empty().id();
+        let foo = world.register_component::<Foo>();
+
+        world
+            .commands()
+            .entity(e1)
+            .start_timer_persistent::<Foo>(Timer::from_seconds(1., bevy_time::TimerMode::Once));
+        world.flush();
+
+        for _ in 0..3 {
+            world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs(1));
+            world.run_system_cached(tick_entity_timers).unwrap();
+        }
+        assert!(world.resource::<Finished>().0);
+        let timers = world.get::<Timers>(e1).unwrap();
+        assert!(timers.get(foo).unwrap().finished());
+        assert_eq!(timers.is_persistent(foo), Some(true));
+    }
+
+    crate::timer_tag!(Bar => on_finished |_: Trigger<OnTimerFinished, Bar>, mut finished: ResMut<Finished>| {
+        finished.0 = true;
+    });
+
+    #[test]
+    fn timer_tag_macro() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<Finished>();
+        world.spawn(Bar::on_finished());
+
+        let e1 = world.spawn(Bar).id();
+
+        world
+            .commands()
+            .entity(e1)
+            .start_timer::<Bar>(Timer::from_seconds(1., bevy_time::TimerMode::Once));
+        world.flush();
+
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(1));
+        world.run_system_cached(tick_entity_timers).unwrap();
+        assert!(world.resource::<Finished>().0);
+    }
+
+    #[derive(Component)]
+    struct Gate;
+
+    #[test]
+    fn gated() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+
+        let e1 = world.spawn_empty().id();
+        let foo = world.register_component::<Foo>();
+
+        world
+            .commands()
+            .entity(e1)
+            .start_timer_gated::<Foo, Gate>(Timer::from_seconds(5., bevy_time::TimerMode::Once));
+        world.flush();
+
+        let advance = |world: &mut World| {
+            world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs(1));
+            world.run_system_cached(tick_entity_timers).unwrap();
+            world.get::<Timers>(e1).unwrap().get(foo).unwrap().elapsed()
+        };
+
+        assert_eq!(advance(&mut world), Duration::ZERO);
+        world.entity_mut(e1).insert(Gate);
+        assert_eq!(advance(&mut world), Duration::from_secs(1));
+        assert_eq!(advance(&mut world), Duration::from_secs(2));
+        world.entity_mut(e1).remove::<Gate>();
+        assert_eq!(advance(&mut world), Duration::from_secs(2));
+    }
+
+    #[test]
+    fn finished_this_frame() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<TimersFinishedThisFrame>();
+
+        let e1 = world.spawn_empty().id();
+        let e2 = world.spawn_empty().id();
+        let foo = world.register_component::<Foo>();
+
+        world
+            .commands()
+            .entity(e1)
+            .start_timer::<Foo>(Timer::from_seconds(1., bevy_time::TimerMode::Once));
+        world
+            .commands()
+            .entity(e2)
+            .start_timer::<Foo>(Timer::from_seconds(2., bevy_time::TimerMode::Once));
+        world.flush();
+
+        let read_finished =
+            |finished: Res<TimersFinishedThisFrame>| finished.iter().collect::<Vec<_>>();
+
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(1));
+        world.run_system_cached(tick_entity_timers).unwrap();
+        let finished = world.run_system_cached(read_finished).unwrap();
+        assert_eq!(finished, [(e1, foo)]);
+
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(1));
+        world.run_system_cached(tick_entity_timers).unwrap();
+        let finished = world.run_system_cached(read_finished).unwrap();
+        assert_eq!(finished, [(e2, foo)]);
+    }
+
+    #[derive(Resource, Default)]
+    struct FinishedAt(Vec<Duration>);
+
+    #[test]
+    fn with_gap() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<FinishedAt>();
+        world.add_observer(
+            |_: Trigger<OnTimerFinished, Foo>, time: Res<Time>, mut at: ResMut<FinishedAt>| {
+                at.0.push(time.elapsed());
+            },
+        );
+
+        let e1 = world.spawn_empty().id();
+
+        world
+            .commands()
+            .entity(e1)
+            .start_timer_with_gap::<Foo>(Duration::from_secs(1), Duration::from_secs(2));
+        world.flush();
+
+        for _ in 0..8 {
+            world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs(1));
+            world.run_system_cached(tick_entity_timers).unwrap();
+        }
+        assert_eq!(
+            world.resource::<FinishedAt>().0,
+            [1, 4, 7].map(Duration::from_secs)
+        );
+
+        // A single large delta spanning several phases.
+        world.init_resource::<TimersFinishedThisFrame>();
+        let foo = world.register_component::<Foo>();
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(6));
+        world.run_system_cached(tick_entity_timers).unwrap();
+        assert_eq!(
+            world
+                .resource::<TimersFinishedThisFrame>()
+                .times_finished(e1, foo),
+            2
+        );
+
+        // A huge delta skips whole cycles instead of looping once per phase:
+        // the last 1s of the gap, 1_000_000 cycles, then 500ms of activity.
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_millis(3_000_001_500));
+        world.run_system_cached(tick_entity_timers).unwrap();
+        assert_eq!(
+            world
+                .resource::<TimersFinishedThisFrame>()
+                .times_finished(e1, foo),
+            1_000_000
+        );
+        assert_eq!(
+            world.get::<Timers>(e1).unwrap().get(foo).unwrap().elapsed(),
+            Duration::from_millis(500)
+        );
+    }
+
+    #[derive(Resource, Default)]
+    struct TimesFinished(Vec<u32>);
+
+    #[test]
+    fn times_finished_in_observer() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<TimersFinishedThisFrame>();
+        world.init_resource::<TimesFinished>();
+        world.insert_resource(TimerSettings {
+            max_fires_per_tick: 1,
+            ..Default::default()
+        });
+        world.add_observer(
+            |t: Trigger<OnTimerFinished, Foo>,
+             finished: Res<TimersFinishedThisFrame>,
+             mut times: ResMut<TimesFinished>| {
+                times.0.push(finished.times_finished_for(&t));
+            },
+        );
+
+        let e1 = world.spawn_empty().id();
+
+        world
+            .commands()
+            .entity(e1)
+            .start_timer::<Foo>(Timer::from_seconds(1., bevy_time::TimerMode::Repeating));
+        world.flush();
+
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_millis(3500));
+        world.run_system_cached(tick_entity_timers).unwrap();
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_millis(500));
+        world.run_system_cached(tick_entity_timers).unwrap();
+        assert_eq!(world.resource::<TimesFinished>().0, [3, 1]);
+    }
+
+    #[test]
+    fn snapshot() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+
+        let e1 = world.spawn_empty().id();
+        let foo = world.register_component::<Foo>();
+        let bar = world.register_component::<Bar>();
+
+        world
+            .commands()
+            .entity(e1)
+            .start_timer::<Foo>(Timer::from_seconds(5., bevy_time::TimerMode::Once))
+            .start_timer::<Bar>(Timer::from_seconds(2., bevy_time::TimerMode::Repeating))
+            .pause_timer::<Bar>();
+        world.flush();
+
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(1));
+        world.run_system_cached(tick_entity_timers).unwrap();
+
+        let snapshot = world.get::<Timers>(e1).unwrap().snapshot();
+        assert_eq!(
+            snapshot,
+            [
+                TimerSnapshot {
+                    component: foo,
+                    elapsed: Duration::from_secs(1),
+                    duration: Duration::from_secs(5),
+                    mode: bevy_time::TimerMode::Once,
+                    paused: false,
+                },
+                TimerSnapshot {
+                    component: bar,
+                    elapsed: Duration::ZERO,
+                    duration: Duration::from_secs(2),
+                    mode: bevy_time::TimerMode::Repeating,
+                    paused: true,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn rewind() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+
+        let e1 = world.spawn_empty().id();
+        let foo = world.register_component::<Foo>();
+        let bar = world.register_component::<Bar>();
+
+        world
+            .commands()
+            .entity(e1)
+            .start_timer::<Foo>(Timer::from_seconds(5., bevy_time::TimerMode::Once))
+            .start_timer::<Bar>(Timer::from_seconds(2., bevy_time::TimerMode::Repeating));
+        world.flush();
+
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(2));
+        world.run_system_cached(tick_entity_timers).unwrap();
+        assert!(world
+            .get::<Timers>(e1)
+            .unwrap()
+            .get(bar)
+            .unwrap()
+            .finished());
+
+        world.rewind_timers(e1, Duration::from_secs(1));
+        let timers = world.get::<Timers>(e1).unwrap();
+        assert_eq!(timers.get(foo).unwrap().remaining(), Duration::from_secs(4));
+        assert!(!timers.get(bar).unwrap().finished());
+        assert_eq!(timers.get(bar).unwrap().elapsed(), Duration::ZERO);
+    }
+
+    #[derive(Resource, Default)]
+    struct Batches(Vec<Vec<bevy_ecs::component::ComponentId>>);
+
+    #[test]
+    fn batched_finishes() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<Finished>();
+        world.init_resource::<Batches>();
+        world.insert_resource(TimerSettings {
+            finished_events: FinishedEvents::Batched,
+            ..Default::default()
+        });
+        world.add_observer(
+            |_: Trigger<OnTimerFinished, Foo>, mut finished: ResMut<Finished>| {
+                finished.0 = true;
+            },
+        );
+        world.add_observer(
+            |t: Trigger<OnTimersFinishedBatch>, mut batches: ResMut<Batches>| {
+                batches.0.push(t.event().components.clone());
+            },
+        );
+
+        let e1 = world.spawn_empty().id();
+        let foo = world.register_component::<Foo>();
+        let bar = world.register_component::<Bar>();
+
+        world
+            .commands()
+            .entity(e1)
+            .start_timer::<Foo>(Timer::from_seconds(1., bevy_time::TimerMode::Once))
+            .start_timer::<Bar>(Timer::from_seconds(1., bevy_time::TimerMode::Repeating));
+        world.flush();
+
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(1));
+        world.run_system_cached(tick_entity_timers).unwrap();
+        assert_eq!(world.resource::<Batches>().0, [vec![foo, bar]]);
+        assert!(!world.resource::<Finished>().0);
+    }
+
+    #[test]
+    fn entities_with_tag() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+
+        let e1 = world.spawn_empty().id();
+        let e2 = world.spawn_empty().id();
+        let e3 = world.spawn_empty().id();
+
+        world
+            .commands()
+            .entity(e1)
+            .start_timer::<Foo>(Timer::from_seconds(1., bevy_time::TimerMode::Once));
+        world
+            .commands()
+            .entity(e2)
+            .start_timer::<Bar>(Timer::from_seconds(1., bevy_time::TimerMode::Once));
+        world
+            .commands()
+            .entity(e3)
+            .start_timer::<Foo>(Timer::from_seconds(1., bevy_time::TimerMode::Once))
+            .start_timer::<Bar>(Timer::from_seconds(1., bevy_time::TimerMode::Once));
+        world.flush();
+
+        let mut with_foo = world
+            .run_system_cached(|timers: TimerQuery| {
+                timers.entities_with::<Foo>().collect::<Vec<_>>()
+            })
+            .unwrap();
+        with_foo.sort();
+        assert_eq!(with_foo, [e1, e3]);
+
+        let with_gate = world
+            .run_system_cached(|timers: TimerQuery| timers.entities_with::<Gate>().count())
+            .unwrap();
+        assert_eq!(with_gate, 0);
+    }
+
+    #[test]
+    fn timer_index() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        TimerIndex::register(&mut world);
+
+        let e1 = world.spawn_empty().id();
+        let e2 = world.spawn_empty().id();
+        let foo = world.register_component::<Foo>();
+        let bar = world.register_component::<Bar>();
+
+        world
+            .commands()
+            .entity(e1)
+            .start_timer::<Foo>(Timer::from_seconds(1., bevy_time::TimerMode::Once))
+            .start_timer::<Bar>(Timer::from_seconds(1., bevy_time::TimerMode::Repeating));
+        world
+            .commands()
+            .entity(e2)
+            .start_timer::<Bar>(Timer::from_seconds(1., bevy_time::TimerMode::Repeating));
+        world.flush();
+        assert!(world.resource::<TimerIndex>().contains(foo, e1));
+        assert_eq!(world.resource::<TimerIndex>().len(bar), 2);
+
+        // The one-shot timer is removed when it finishes, the repeating ones stay.
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(1));
+        world.run_system_cached(tick_entity_timers).unwrap();
+        assert_eq!(world.resource::<TimerIndex>().len(foo), 0);
+        assert_eq!(world.resource::<TimerIndex>().len(bar), 2);
+
+        world.commands().entity(e1).cancel_timer::<Bar>();
+        world.flush();
+        assert_eq!(
+            world
+                .resource::<TimerIndex>()
+                .entities(bar)
+                .collect::<Vec<_>>(),
+            [e2]
+        );
+
+        world.despawn(e2);
+        assert_eq!(world.resource::<TimerIndex>().len(bar), 0);
+    }
+
+    #[test]
+    fn timer_index_delayed() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        TimerIndex::register(&mut world);
+        let foo = world.register_component::<Foo>();
+
+        let e1 = world.spawn_empty().id();
+        world.commands().entity(e1).start_timer_delayed::<Foo>(
+            Duration::from_secs(1),
+            Timer::from_seconds(1., bevy_time::TimerMode::Once),
+        );
+        world.flush();
+        assert!(world.resource::<TimerIndex>().contains(foo, e1));
+
+        world.commands().entity(e1).cancel_timer::<Foo>();
+        world.flush();
+        assert!(!world.resource::<TimerIndex>().contains(foo, e1));
+    }
+
+    #[test]
+    fn default_capacity() {
+        let mut world = World::new();
+        world.insert_resource(TimerSettings {
+            default_capacity: 8,
+            ..Default::default()
+        });
+
+        let e1 = world.spawn_empty().id();
+
+        world
+            .commands()
+            .entity(e1)
+            .start_timer::<Foo>(Timer::from_seconds(1., bevy_time::TimerMode::Once));
+        world.flush();
+        let capacity = world.get::<Timers>(e1).unwrap().capacity();
+        assert!(capacity >= 8);
+
+        let mut timers = world.get_mut::<Timers>(e1).unwrap();
+        for i in 1..capacity {
+            timers.insert(
+                bevy_ecs::component::ComponentId::new(100 + i),
+                Timer::from_seconds(1., bevy_time::TimerMode::Once),
+            );
+        }
+        assert_eq!(timers.len(), capacity);
+        assert_eq!(timers.capacity(), capacity);
+        assert!(Timers::with_capacity(16).capacity() >= 16);
+    }
+
+    #[derive(Resource, Default)]
+    struct Cancelled(bool);
+
+    #[test]
+    fn linked() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<Cancelled>();
+        world.add_observer(
+            |_: Trigger<OnTimerCancelled, Foo>, mut cancelled: ResMut<Cancelled>| {
+                cancelled.0 = true;
+            },
+        );
+
+        let e1 = world.spawn(Foo).id();
+        let foo = world.register_component::<Foo>();
+
+        world
+            .commands()
+            .entity(e1)
+            .start_timer_linked::<Foo>(Timer::from_seconds(1., bevy_time::TimerMode::Repeating));
+        world.flush();
+        assert!(world.get::<Timers>(e1).unwrap().contains(foo));
+
+        world.entity_mut(e1).remove::<Foo>();
+        world.flush();
+        assert!(!world.get::<Timers>(e1).unwrap().contains(foo));
+        assert!(world.resource::<Cancelled>().0);
+    }
+
+    #[derive(Resource, Default)]
+    struct FinishedDuration(Option<Duration>);
+
+    #[test]
+    fn finished_duration() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<FinishedDuration>();
+        world.add_observer(
+            |t: Trigger<OnTimerFinished, Foo>, mut duration: ResMut<FinishedDuration>| {
+                duration.0 = Some(t.event().duration);
+            },
+        );
+
+        let e1 = world.spawn_empty().id();
+
+        world
+            .commands()
+            .entity(e1)
+            .start_timer::<Foo>(Timer::from_seconds(5., bevy_time::TimerMode::Once));
+        world.flush();
+
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(6));
+        world.run_system_cached(tick_entity_timers).unwrap();
+        assert_eq!(
+            world.resource::<FinishedDuration>().0,
+            Some(Duration::from_secs(5))
+        );
+    }
+
+    #[test]
+    fn skip_zero_delta() {
+        use bevy_ecs::{
+            change_detection::DetectChanges,
+            schedule::{IntoSystemConfigs, Schedule},
+        };
+
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(tick_entity_timers.run_if(time_is_advancing));
+
+        let e1 = world.spawn_empty().id();
+
+        world
+            .commands()
+            .entity(e1)
+            .start_timer::<Foo>(Timer::from_seconds(5., bevy_time::TimerMode::Once));
+        world.flush();
+
+        let last_changed =
+            |world: &World| world.entity(e1).get_ref::<Timers>().unwrap().last_changed();
+
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(1));
+        schedule.run(&mut world);
+        let ticked = last_changed(&world);
+
+        world.resource_mut::<Time>().advance_by(Duration::ZERO);
+        schedule.run(&mut world);
+        assert_eq!(last_changed(&world), ticked);
+
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(1));
+        schedule.run(&mut world);
+        assert_ne!(last_changed(&world), ticked);
+
+        // Zero-duration timers finish on a zero delta without the condition,
+        // but wait for the next non-zero delta with it.
+        let e2 = world.spawn_empty().id();
+        let e3 = world.spawn_empty().id();
+        for entity in [e2, e3] {
+            world
+                .commands()
+                .entity(entity)
+                .start_timer::<Bar>(Timer::from_seconds(0., bevy_time::TimerMode::Once));
+        }
+        world.flush();
+        let bar = world.register_component::<Bar>();
+        world.resource_mut::<Time>().advance_by(Duration::ZERO);
+        schedule.run(&mut world);
+        assert!(world.get::<Timers>(e2).unwrap().contains(bar));
+        world.run_system_cached(tick_entity_timers).unwrap();
+        assert!(world
+            .get::<Timers>(e3)
+            .is_none_or(|timers| !timers.contains(bar)));
+    }
+
+    #[test]
+    fn finished_count() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.insert_resource(TimerSettings {
+            count_finishes: true,
+            ..Default::default()
+        });
+        let foo = world.register_component::<Foo>();
+
+        let e1 = world.spawn_empty().id();
+
+        world
+            .commands()
+            .entity(e1)
+            .start_timer::<Foo>(Timer::from_seconds(1., bevy_time::TimerMode::Repeating));
+        world.flush();
+
+        for _ in 0..3 {
+            world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs(1));
+            world.run_system_cached(tick_entity_timers).unwrap();
+        }
+        assert_eq!(world.get::<TimerFinishedCount>(e1).unwrap().get(foo), 3);
+
+        // A large delta counts every finish, and the count survives restarts.
+        world
+            .commands()
+            .entity(e1)
+            .start_timer::<Foo>(Timer::from_seconds(1., bevy_time::TimerMode::Repeating));
+        world.flush();
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(2));
+        world.run_system_cached(tick_entity_timers).unwrap();
+        assert_eq!(world.get::<TimerFinishedCount>(e1).unwrap().get(foo), 5);
+    }
+
+    #[test]
+    fn transfer() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<Finished>();
+        world.init_resource::<Cancelled>();
+        world.add_observer(
+            |_: Trigger<OnTimerCancelled, Foo>, mut c: ResMut<Cancelled>| {
+                c.0 = true;
+            },
+        );
+        world.add_observer(
+            |_: Trigger<OnTimerFinished, Foo>, mut f: ResMut<Finished>| {
+                f.0 = true;
+            },
+        );
+        let foo = world.register_component::<Foo>();
+
+        let e1 = world.spawn_empty().id();
+        let e2 = world.spawn_empty().id();
+
+        world
+            .commands()
+            .entity(e1)
+            .start_timer::<Foo>(Timer::from_seconds(2., bevy_time::TimerMode::Once));
+        world.flush();
+
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(1));
+        world.run_system_cached(tick_entity_timers).unwrap();
+
+        world.commands().entity(e1).transfer_timer::<Foo>(e2);
+        world.flush();
+        assert!(!world.get::<Timers>(e1).unwrap().contains(foo));
+        assert_eq!(
+            world.get::<Timers>(e2).unwrap().get(foo).unwrap().elapsed(),
+            Duration::from_secs(1)
+        );
+        assert!(!world.resource::<Cancelled>().0);
+
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(1));
+        world.run_system_cached(tick_entity_timers).unwrap();
+        world.flush();
+        assert!(world.resource::<Finished>().0);
+        assert!(world.get::<Timers>(e2).unwrap().is_empty());
+    }
+
+    #[test]
+    fn invalid_seconds() {
+        let mut world = World::new();
+        let foo = world.register_component::<Foo>();
+
+        let e1 = world.spawn_empty().id();
+
+        world
+            .commands()
+            .entity(e1)
+            .start_timer_secs::<Foo>(f32::NAN, bevy_time::TimerMode::Once);
+        world.flush();
+        assert!(world.get::<Timers>(e1).is_none());
+
+        world
+            .commands()
+            .entity(e1)
+            .start_timer_secs::<Foo>(-1., bevy_time::TimerMode::Once);
+        world.flush();
+        assert_eq!(
+            world
+                .get::<Timers>(e1)
+                .unwrap()
+                .get(foo)
+                .unwrap()
+                .duration(),
+            Duration::ZERO
+        );
+    }
+
+    #[test]
+    fn entity_scoped_observer() {
+        #[derive(Resource, Default)]
+        struct FinishedOn(Vec<Entity>);
+
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<FinishedOn>();
+
+        let e1 = world.spawn_empty().id();
+        let e2 = world.spawn_empty().id();
+        world.entity_mut(e1).observe(
+            |t: Trigger<OnTimerFinished, Foo>, mut finished_on: ResMut<FinishedOn>| {
+                finished_on.0.push(t.entity());
+            },
+        );
+
+        for e in [e1, e2] {
+            world
+                .commands()
+                .entity(e)
+                .start_timer::<Foo>(Timer::from_seconds(1., bevy_time::TimerMode::Once));
+        }
+        world.flush();
+
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(1));
+        world.run_system_cached(tick_entity_timers).unwrap();
+        world.flush();
+        assert_eq!(world.resource::<FinishedOn>().0, vec![e1]);
+    }
+
+    #[test]
+    fn priority() {
+        #[derive(Resource, Default)]
+        struct Order(Vec<&'static str>);
+
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<Order>();
+        world.add_observer(
+            |_: Trigger<OnTimerFinished, Foo>, mut order: ResMut<Order>| {
+                order.0.push("foo");
+            },
+        );
+        world.add_observer(
+            |_: Trigger<OnTimerFinished, Bar>, mut order: ResMut<Order>| {
+                order.0.push("bar");
+            },
+        );
+
+        let e1 = world.spawn_empty().id();
+
+        world
+            .commands()
+            .entity(e1)
+            .start_timer::<Foo>(Timer::from_seconds(1., bevy_time::TimerMode::Once))
+            .start_timer_with_priority::<Bar>(
+                Timer::from_seconds(1., bevy_time::TimerMode::Once),
+                10,
+            );
+        world.flush();
+
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(1));
+        world.run_system_cached(tick_entity_timers).unwrap();
+        world.flush();
+        assert_eq!(world.resource::<Order>().0, vec!["bar", "foo"]);
+    }
+
+    #[test]
+    fn filtered_tick() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        let foo = world.register_component::<Foo>();
+
+        let e1 = world.spawn(Gate).id();
+        let e2 = world.spawn_empty().id();
+
+        for e in [e1, e2] {
+            world
+                .commands()
+                .entity(e)
+                .start_timer::<Foo>(Timer::from_seconds(5., bevy_time::TimerMode::Once));
+        }
+        world.flush();
+
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(1));
+        world
+            .run_system_cached(tick_entity_timers_filtered::<Gate>)
+            .unwrap();
+
+        let elapsed =
+            |world: &World, e| world.get::<Timers>(e).unwrap().get(foo).unwrap().elapsed();
+        assert_eq!(elapsed(&world, e1), Duration::from_secs(1));
+        assert_eq!(elapsed(&world, e2), Duration::ZERO);
+    }
+
+    #[test]
+    fn remove_silently() {
+        let mut world = World::new();
+        world.init_resource::<Cancelled>();
+        world.add_observer(
+            |_: Trigger<OnTimerCancelled, Foo>, mut c: ResMut<Cancelled>| {
+                c.0 = true;
+            },
+        );
+        let foo = world.register_component::<Foo>();
+
+        let e1 = world.spawn_empty().id();
+
+        world
+            .commands()
+            .entity(e1)
+            .start_timer::<Foo>(Timer::from_seconds(1., bevy_time::TimerMode::Once));
+        world.flush();
+
+        world.commands().entity(e1).remove_timer::<Foo>();
+        world.flush();
+        assert!(!world.get::<Timers>(e1).unwrap().contains(foo));
+        assert!(!world.resource::<Cancelled>().0);
+    }
+
+    #[cfg(feature = "bevy_state")]
+    #[test]
+    fn pause_in_state() {
+        use bevy_app::{App, Update};
+        use bevy_state::{
+            app::{AppExtStates, StatesPlugin},
+            state::{NextState, States},
+        };
+
+        use crate::plugin::{PauseTimersInState, ScheduleTimerTickPlugin};
+
+        #[derive(States, Default, Clone, PartialEq, Eq, Hash, Debug)]
+        enum GameState {
+            #[default]
+            Playing,
+            Paused,
+        }
+
+        let mut app = App::new();
+        app.add_plugins((
+            StatesPlugin,
+            ScheduleTimerTickPlugin::new(Update),
+            PauseTimersInState(GameState::Paused),
+        ))
+        .init_resource::<Time>()
+        .init_state::<GameState>();
+        let foo = app.world_mut().register_component::<Foo>();
+
+        let e1 = app.world_mut().spawn_empty().id();
+        app.world_mut()
+            .commands()
+            .entity(e1)
+            .start_timer::<Foo>(Timer::from_seconds(5., bevy_time::TimerMode::Once));
+
+        let step = |app: &mut App| {
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs(1));
+            app.update();
+            let timers = app.world().get::<Timers>(e1).unwrap();
+            timers.get(foo).unwrap().elapsed()
+        };
+
+        assert_eq!(step(&mut app), Duration::from_secs(1));
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Paused);
+        assert_eq!(step(&mut app), Duration::from_secs(1));
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Playing);
+        assert_eq!(step(&mut app), Duration::from_secs(2));
+    }
+
+    #[test]
+    fn format_remaining() {
+        use crate::util::{format_remaining, format_remaining_secs};
+
+        let timer = |secs| Timer::from_seconds(secs, bevy_time::TimerMode::Once);
+        assert_eq!(format_remaining(&timer(63.)), "1:03");
+        assert_eq!(format_remaining(&timer(0.25)), "0:01");
+        assert_eq!(format_remaining(&timer(0.)), "0:00");
+        assert_eq!(format_remaining_secs(&timer(63.)), "63.0s");
+        assert_eq!(format_remaining_secs(&timer(0.25)), "0.3s");
+        assert_eq!(format_remaining_secs(&timer(0.01)), "0.1s");
+        assert_eq!(format_remaining_secs(&timer(0.)), "0.0s");
+    }
+
+    #[test]
+    fn delayed() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<Started>();
+        world.init_resource::<Finished>();
+        world.add_observer(|t: Trigger<OnTimerStarted, Foo>, mut s: ResMut<Started>| {
+            s.0.push(t.event().replaced);
+        });
+        world.add_observer(
+            |_: Trigger<OnTimerFinished, Foo>, mut f: ResMut<Finished>| {
+                f.0 = true;
+            },
+        );
+        let foo = world.register_component::<Foo>();
+
+        let e1 = world.spawn_empty().id();
+
+        world.commands().entity(e1).start_timer_delayed::<Foo>(
+            Duration::from_secs(2),
+            Timer::from_seconds(5., bevy_time::TimerMode::Once),
+        );
+        world.flush();
+        assert!(world.resource::<Started>().0.is_empty());
+
+        let step = |world: &mut World, secs| {
+            world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs(secs));
+            world.run_system_cached(tick_entity_timers).unwrap();
+            world.flush();
+        };
+
+        step(&mut world, 1);
+        assert_eq!(
+            world.get::<Timers>(e1).unwrap().delay_remaining(foo),
+            Some(Duration::from_secs(1))
+        );
+        assert!(world.resource::<Started>().0.is_empty());
+
+        // Ends the delay, and carries 2s into the timer.
+        step(&mut world, 3);
+        assert_eq!(world.resource::<Started>().0, vec![false]);
+        assert_eq!(
+            world.get::<Timers>(e1).unwrap().get(foo).unwrap().elapsed(),
+            Duration::from_secs(2)
+        );
+
+        step(&mut world, 2);
+        assert!(!world.resource::<Finished>().0);
+        step(&mut world, 1);
+        assert!(world.resource::<Finished>().0);
+    }
+
+    #[cfg(feature = "bevy_hierarchy")]
+    #[test]
+    fn cancel_recursive() {
+        use bevy_hierarchy::{BuildChildren, DespawnRecursiveExt};
+
+        #[derive(Resource, Default)]
+        struct CancelledOn(Vec<Entity>);
+
+        let mut world = World::new();
+        world.init_resource::<CancelledOn>();
+        world.add_observer(|t: Trigger<OnTimerCancelled>, mut c: ResMut<CancelledOn>| {
+            c.0.push(t.entity());
+        });
+
+        let child = world.spawn_empty().id();
+        let parent = world.spawn_empty().add_child(child).id();
+
+        for e in [parent, child] {
+            world
+                .commands()
+                .entity(e)
+                .start_timer::<Foo>(Timer::from_seconds(1., bevy_time::TimerMode::Once));
+        }
+        world.flush();
+
+        world.commands().entity(parent).cancel_timers_recursive();
+        world.commands().entity(parent).despawn_recursive();
+        world.flush();
+        assert_eq!(world.resource::<CancelledOn>().0, vec![parent, child]);
+        assert!(world.get_entity(child).is_err());
+    }
+
+    #[test]
+    fn ordered_tick() {
+        #[derive(Resource, Default)]
+        struct Order(Vec<Entity>);
+
+        let run = || {
+            let mut world = World::new();
+            world.init_resource::<Time>();
+            world.init_resource::<Order>();
+            world.add_observer(|t: Trigger<OnTimerFinished>, mut order: ResMut<Order>| {
+                order.0.push(t.entity());
+            });
+
+            for _ in 0..100 {
+                world
+                    .commands()
+                    .spawn_empty()
+                    .start_timer::<Foo>(Timer::from_seconds(1., bevy_time::TimerMode::Once));
+            }
+            world.flush();
+
+            world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs(1));
+            world.run_system_cached(tick_entity_timers_ordered).unwrap();
+            world.flush();
+            world.remove_resource::<Order>().unwrap().0
+        };
+
+        let first = run();
+        assert_eq!(first.len(), 100);
+        assert!(first.is_sorted());
+        assert_eq!(first, run());
+    }
+
+    #[test]
+    fn clear() {
+        let mut world = World::new();
+        let foo = world.register_component::<Foo>();
+        let bar = world.register_component::<Bar>();
+
+        let mut timers = Timers::with_capacity(8);
+        timers.insert(foo, Timer::from_seconds(1., bevy_time::TimerMode::Once));
+        timers.insert(bar, Timer::from_seconds(1., bevy_time::TimerMode::Once));
+        let capacity = timers.capacity();
+
+        timers.clear();
+        assert!(timers.is_empty());
+        assert_eq!(timers.capacity(), capacity);
+    }
+
+    #[test]
+    fn is_paused() {
+        let mut world = World::new();
+        let foo = world.register_component::<Foo>();
+        let bar = world.register_component::<Bar>();
+
+        let e1 = world.spawn_empty().id();
+
+        world
+            .commands()
+            .entity(e1)
+            .start_timer::<Foo>(Timer::from_seconds(1., bevy_time::TimerMode::Once))
+            .pause_timer::<Foo>();
+        world.flush();
+        assert_eq!(world.get::<Timers>(e1).unwrap().is_paused(foo), Some(true));
+        assert_eq!(world.get::<Timers>(e1).unwrap().is_paused(bar), None);
+
+        world.commands().entity(e1).unpause_timer::<Foo>();
+        world.flush();
+        assert_eq!(world.get::<Timers>(e1).unwrap().is_paused(foo), Some(false));
+    }
+
+    #[cfg(feature = "bevy_app")]
+    #[test]
+    fn update_plugin() {
+        use bevy_app::App;
+
+        use crate::plugin::ScheduleTimerTickPlugin;
+
+        let mut app = App::new();
+        app.add_plugins(ScheduleTimerTickPlugin::update())
+            .init_resource::<Time>()
+            .init_resource::<Finished>()
+            .add_observer(
+                |_: Trigger<OnTimerFinished, Foo>, mut f: ResMut<Finished>| {
+                    f.0 = true;
+                },
+            );
+
+        let e1 = app.world_mut().spawn_empty().id();
+        app.world_mut()
+            .commands()
+            .entity(e1)
+            .start_timer::<Foo>(Timer::from_seconds(1., bevy_time::TimerMode::Once));
+
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(1));
+        app.update();
+        assert!(app.world().resource::<Finished>().0);
+    }
+
+    #[test]
+    fn script_events() {
+        use bevy_ecs::event::Events;
+
+        use crate::script::{ScriptTimerEvent, ScriptTimerEventKind};
+
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        ScriptTimerEvent::register(&mut world);
+        let foo = world.register_component::<Foo>();
+        let bar = world.register_component::<Bar>();
+
+        let e1 = world.spawn_empty().id();
+
+        world
+            .commands()
+            .entity(e1)
+            .start_timer::<Foo>(Timer::from_seconds(1., bevy_time::TimerMode::Once))
+            .start_timer::<Bar>(Timer::from_seconds(1., bevy_time::TimerMode::Once))
+            .cancel_timer::<Bar>();
+        world.flush();
+
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(1));
+        world.run_system_cached(tick_entity_timers).unwrap();
+        world.flush();
+
+        let events = world.resource::<Events<ScriptTimerEvent>>();
+        let kinds: Vec<_> = events
+            .iter_current_update_events()
+            .map(|event| {
+                assert_eq!(event.entity, e1);
+                (event.component, event.kind)
+            })
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                (foo, ScriptTimerEventKind::Started),
+                (bar, ScriptTimerEventKind::Started),
+                (bar, ScriptTimerEventKind::Cancelled),
+                (foo, ScriptTimerEventKind::Finished),
+            ]
+        );
+    }
+
+    #[test]
+    fn script_events_batched() {
+        use bevy_ecs::event::Events;
+
+        use crate::script::{ScriptTimerEvent, ScriptTimerEventKind};
+
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.insert_resource(TimerSettings {
+            finished_events: FinishedEvents::Batched,
+            ..Default::default()
+        });
+        ScriptTimerEvent::register(&mut world);
+        let foo = world.register_component::<Foo>();
+        let bar = world.register_component::<Bar>();
+
+        let e1 = world.spawn_empty().id();
+        world
+            .commands()
+            .entity(e1)
+            .start_timer::<Foo>(Timer::from_seconds(1., bevy_time::TimerMode::Once))
+            .start_timer::<Bar>(Timer::from_seconds(1., bevy_time::TimerMode::Once));
+        world.flush();
+        world.resource_mut::<Events<ScriptTimerEvent>>().clear();
+
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(1));
+        world.run_system_cached(tick_entity_timers).unwrap();
+
+        let events = world.resource::<Events<ScriptTimerEvent>>();
+        let mut finished: Vec<_> = events
+            .iter_current_update_events()
+            .map(|event| {
+                assert_eq!(event.entity, e1);
+                assert_eq!(event.kind, ScriptTimerEventKind::Finished);
+                event.component
+            })
+            .collect();
+        finished.sort();
+        let mut expected = vec![foo, bar];
+        expected.sort();
+        assert_eq!(finished, expected);
+    }
+
+    #[test]
+    fn substeps() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<FinishedAt>();
+        world.init_resource::<TimersFinishedThisFrame>();
+        world.insert_resource(TimerSettings {
+            max_substep: Some(Duration::from_millis(500)),
+            ..Default::default()
+        });
+        world.add_observer(
+            |t: Trigger<OnTimerFinished, Foo>, mut at: ResMut<FinishedAt>| {
+                at.0.push(t.event().duration);
+            },
+        );
+        let foo = world.register_component::<Foo>();
+
+        let e1 = world.spawn_empty().id();
+
+        world
+            .commands()
+            .entity(e1)
+            .start_timer::<Foo>(Timer::from_seconds(1., bevy_time::TimerMode::Repeating));
+        world.flush();
+
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_millis(3500));
+        world.run_system_cached(tick_entity_timers).unwrap();
+        world.flush();
+
+        // One finish per substep reaching a multiple of 1s, instead of one.
+        assert_eq!(world.resource::<FinishedAt>().0.len(), 3);
+        assert_eq!(
+            world
+                .resource::<TimersFinishedThisFrame>()
+                .times_finished(e1, foo),
+            3
+        );
+        assert_eq!(
+            world.get::<Timers>(e1).unwrap().get(foo).unwrap().elapsed(),
+            Duration::from_millis(500)
+        );
+    }
+
+    #[test]
+    fn substeps_capped() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<FinishedAt>();
+        world.init_resource::<TimersFinishedThisFrame>();
+        world.insert_resource(TimerSettings {
+            max_substep: Some(Duration::from_millis(1)),
+            max_fires_per_tick: 1,
+            ..Default::default()
+        });
+        world.add_observer(
+            |t: Trigger<OnTimerFinished, Foo>, mut at: ResMut<FinishedAt>| {
+                at.0.push(t.event().duration);
+            },
+        );
+        let foo = world.register_component::<Foo>();
+
+        let e1 = world.spawn_empty().id();
+        world.commands().entity(e1).start_timer::<Foo>(Timer::new(
+            Duration::from_millis(1),
+            bevy_time::TimerMode::Repeating,
+        ));
+        world.flush();
+
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(1));
+        world.run_system_cached(tick_entity_timers).unwrap();
+        world.flush();
+
+        // The last substep covers the rest of the delta, finishing many times.
+        let max_substeps = TimerSettings::MAX_SUBSTEPS as usize;
+        assert_eq!(world.resource::<FinishedAt>().0.len(), max_substeps);
+        assert_eq!(
+            world
+                .resource::<TimersFinishedThisFrame>()
+                .times_finished(e1, foo),
+            1000
+        );
+    }
+
+    #[test]
+    fn tracked_progress() {
+        use bevy_ecs::change_detection::DetectChanges;
+
+        use crate::core::TimerProgress;
+
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        let foo = world.register_component::<Foo>();
+
+        let e1 = world.spawn_empty().id();
+
+        world
+            .commands()
+            .entity(e1)
+            .start_timer_tracked::<Foo>(Timer::from_seconds(10., bevy_time::TimerMode::Once), 0.25);
+        world.flush();
+
+        let step = |world: &mut World, secs| {
+            let before = world
+                .entity(e1)
+                .get_ref::<TimerProgress>()
+                .unwrap()
+                .last_changed();
+            world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs(secs));
+            world.run_system_cached(tick_entity_timers).unwrap();
+            world.flush();
+            let progress = world.entity(e1).get_ref::<TimerProgress>().unwrap();
+            (
+                progress.get(foo).unwrap(),
+                progress.last_changed() != before,
+            )
+        };
+
+        assert_eq!(step(&mut world, 1), (0., false));
+        assert_eq!(step(&mut world, 1), (0., false));
+        assert_eq!(step(&mut world, 1), (0.25, true));
+        assert_eq!(step(&mut world, 1), (0.25, false));
+        assert_eq!(step(&mut world, 6), (1., true));
+    }
+
+    #[test]
+    fn tag_names() {
+        let mut world = World::new();
+
+        let e1 = world.spawn_empty().id();
+
+        world
+            .commands()
+            .entity(e1)
+            .start_timer::<Foo>(Timer::from_seconds(1., bevy_time::TimerMode::Once))
+            .start_timer::<Bar>(Timer::from_seconds(1., bevy_time::TimerMode::Once));
+        world.flush();
+
+        let timers = world.get::<Timers>(e1).unwrap();
+        let names: Vec<_> = timers.tag_names(world.components()).collect();
+        assert_eq!(names, vec!["Foo", "Bar"]);
+
+        let names = world
+            .run_system_cached_with(
+                |In(e): In<Entity>, timers: TimerQuery| timers.tag_names(e).collect::<Vec<_>>(),
+                e1,
+            )
+            .unwrap();
+        assert_eq!(names, vec!["Foo", "Bar"]);
+    }
+
+    #[test]
+    fn apply_snapshot() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<Finished>();
+        world.add_observer(
+            |_: Trigger<OnTimerFinished, Foo>, mut f: ResMut<Finished>| {
+                f.0 = true;
+            },
+        );
+        let foo = world.register_component::<Foo>();
+        let bar = world.register_component::<Bar>();
+
+        let e1 = world.spawn_empty().id();
+
+        world
+            .commands()
+            .entity(e1)
+            .start_timer::<Foo>(Timer::from_seconds(10., bevy_time::TimerMode::Once))
+            .start_timer::<Bar>(Timer::from_seconds(10., bevy_time::TimerMode::Repeating))
+            .pause_timer::<Bar>();
+        world.flush();
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(8));
+        world.run_system_cached(tick_entity_timers).unwrap();
+        let saved = world.get::<Timers>(e1).unwrap().snapshot();
+
+        // Load into a fresh entity.
+        let e2 = world.spawn(Timers::new()).id();
+        world.get_mut::<Timers>(e2).unwrap().apply_snapshot(&saved);
+        let timers = world.get::<Timers>(e2).unwrap();
+        assert_eq!(timers.snapshot(), saved);
+        assert_eq!(timers.get(foo).unwrap().remaining(), Duration::from_secs(2));
+        assert_eq!(timers.is_paused(bar), Some(true));
+
+        world.entity_mut(e1).despawn();
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(2));
+        world.run_system_cached(tick_entity_timers).unwrap();
+        world.flush();
+        assert!(world.resource::<Finished>().0);
+    }
+
+    #[test]
+    fn cooldown_group() {
+        use crate::cooldown::CooldownGroups;
+
+        #[derive(Component)]
+        struct Group;
+
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        let foo = world.register_component::<Foo>();
+        let bar = world.register_component::<Bar>();
+        let group = CooldownGroups::register::<Group>(&mut world, Duration::from_secs(1));
+        CooldownGroups::add_member::<Foo, Group>(&mut world);
+        CooldownGroups::add_member::<Bar, Group>(&mut world);
+
+        let e1 = world.spawn_empty().id();
+
+        world
+            .commands()
+            .entity(e1)
+            .start_cooldown::<Foo>(Timer::from_seconds(5., bevy_time::TimerMode::Once));
+        world.flush();
+
+        let on_cooldown = |world: &World, member| {
+            let timers = world.get::<Timers>(e1).unwrap();
+            timers.is_on_cooldown(member, world.resource::<CooldownGroups>())
+        };
+        assert!(on_cooldown(&world, foo));
+        assert!(on_cooldown(&world, bar));
+        assert!(!world.get::<Timers>(e1).unwrap().is_group_ready(group));
+
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(1));
+        world.run_system_cached(tick_entity_timers).unwrap();
+        assert!(on_cooldown(&world, foo));
+        assert!(!on_cooldown(&world, bar));
+        assert!(world.get::<Timers>(e1).unwrap().is_group_ready(group));
+
+        // Restarting the group drops the callback it was started with.
+        world.init_resource::<Finished>();
+        world
+            .commands()
+            .entity(e1)
+            .start_timer_with_callback::<Group>(
+                Timer::from_seconds(1., bevy_time::TimerMode::Once),
+                |world: &mut World, _| world.resource_mut::<Finished>().0 = true,
+            )
+            .start_cooldown::<Bar>(Timer::from_seconds(5., bevy_time::TimerMode::Once));
+        world.flush();
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(1));
+        world.run_system_cached(tick_entity_timers).unwrap();
+        assert!(world.get::<Timers>(e1).unwrap().is_group_ready(group));
+        assert!(!world.resource::<Finished>().0);
+    }
+
+    #[test]
+    fn remove_tag_on_finish() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        let foo = world.register_component::<Foo>();
+
+        let e1 = world.spawn(Foo).id();
+        let e2 = world.spawn(Foo).id();
+
+        world
+            .commands()
+            .entity(e1)
+            .start_timer_removing_tag::<Foo>(Timer::from_seconds(1., bevy_time::TimerMode::Once));
+        world
+            .commands()
+            .entity(e2)
+            .start_timer::<Foo>(Timer::from_seconds(1., bevy_time::TimerMode::Once));
+        world.flush();
+
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(1));
+        world.run_system_cached(tick_entity_timers).unwrap();
+
+        assert!(!world.entity(e1).contains::<Foo>());
+        assert!(!world.get::<Timers>(e1).unwrap().contains(foo));
+        // Timers started without the option keep their tag.
+        assert!(world.entity(e2).contains::<Foo>());
+    }
+
+    #[test]
+    fn remove_tag_on_finish_batched() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.insert_resource(TimerSettings {
+            finished_events: FinishedEvents::Batched,
+            ..Default::default()
+        });
+        let foo = world.register_component::<Foo>();
+
+        let e1 = world.spawn(Foo).id();
+        world
+            .commands()
+            .entity(e1)
+            .start_timer_removing_tag::<Foo>(Timer::from_seconds(1., bevy_time::TimerMode::Once));
+        world.flush();
+
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(1));
+        world.run_system_cached(tick_entity_timers).unwrap();
+
+        // No `OnTimerFinished` is triggered, but the tag is still removed.
+        assert!(!world.entity(e1).contains::<Foo>());
+        assert!(!world.get::<Timers>(e1).unwrap().contains(foo));
+    }
+
+    #[test]
+    fn global_and_timer_pause() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<TimersPaused>();
+        let foo = world.register_component::<Foo>();
+        let bar = world.register_component::<Bar>();
+
+        let e1 = world.spawn_empty().id();
+        world
+            .commands()
+            .entity(e1)
+            .start_timer::<Foo>(Timer::from_seconds(5., bevy_time::TimerMode::Once))
+            .start_timer::<Bar>(Timer::from_seconds(5., bevy_time::TimerMode::Once))
+            .pause_timer::<Foo>();
+        world.flush();
+
+        let tick = |world: &mut World| {
+            world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs(1));
+            world.run_system_cached(tick_entity_timers).unwrap();
+        };
+
+        world.resource_mut::<TimersPaused>().0 = true;
+        tick(&mut world);
+        world.resource_mut::<TimersPaused>().0 = false;
+        tick(&mut world);
+
+        let timers = world.get::<Timers>(e1).unwrap();
+        assert_eq!(timers.is_paused(foo), Some(true));
+        assert_eq!(timers.get(foo).unwrap().elapsed(), Duration::ZERO);
+        assert_eq!(timers.get(bar).unwrap().elapsed(), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn aggregate_remaining() {
+        let mut world = World::new();
+        let foo = world.register_component::<Foo>();
+        let bar = world.register_component::<Bar>();
+        let baz = world.register_component::<Baz>();
+
+        let mut timers = Timers::default();
+        assert_eq!(timers.max_remaining(), None);
+        assert_eq!(timers.min_remaining(), None);
+
+        timers.insert(foo, Timer::from_seconds(3., bevy_time::TimerMode::Once));
+        timers.insert(bar, Timer::from_seconds(1., bevy_time::TimerMode::Once));
+        let mut paused = Timer::from_seconds(10., bevy_time::TimerMode::Once);
+        paused.pause();
+        timers.insert(baz, paused);
+        timers
+            .get_mut(foo)
+            .unwrap()
+            .tick(Duration::from_millis(500));
+
+        assert_eq!(timers.max_remaining(), Some(Duration::from_millis(2500)));
+        assert_eq!(timers.min_remaining(), Some(Duration::from_secs(1)));
+    }
+
+    #[cfg(feature = "bevy_app")]
+    #[test]
+    fn app_finished_observer() {
+        use bevy_app::App;
+
+        use crate::plugin::{AppTimersExt, ScheduleTimerTickPlugin};
+
+        let mut app = App::new();
+        app.add_plugins(ScheduleTimerTickPlugin::update())
+            .init_resource::<Time>()
+            .init_resource::<Finished>()
+            .add_timer_finished_observer::<Foo, _>(
+                |_: Trigger<OnTimerFinished, Foo>, mut f: ResMut<Finished>| {
+                    f.0 = true;
+                },
+            );
+
+        let e1 = app.world_mut().spawn_empty().id();
+        app.world_mut()
+            .commands()
+            .entity(e1)
+            .start_timer::<Foo>(Timer::from_seconds(1., bevy_time::TimerMode::Once));
+
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(1));
+        app.update();
+        assert!(app.world().resource::<Finished>().0);
+    }
+
+    #[cfg(feature = "diagnostics")]
+    #[test]
+    fn drift() {
+        use crate::diagnostics::TimerDrift;
+
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<TimerDrift>();
+        let foo = world.register_component::<Foo>();
+
+        let e1 = world.spawn_empty().id();
+        world
+            .commands()
+            .entity(e1)
+            .start_timer::<Foo>(Timer::from_seconds(1., bevy_time::TimerMode::Repeating));
+        world.flush();
+
+        for millis in [1200, 900, 500] {
+            world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_millis(millis));
+            world.run_system_cached(tick_entity_timers).unwrap();
+        }
+
+        // Finishes at 1.2s (0.2s late) and 2.1s (0.1s late).
+        let stats = *world.resource::<TimerDrift>().get(foo).unwrap();
+        assert_eq!(stats.finishes, 2);
+        assert_eq!(stats.last, Duration::from_millis(100));
+        assert_eq!(stats.average(), Duration::from_millis(150));
+    }
+
+    #[cfg(feature = "diagnostics")]
+    #[test]
+    fn drift_saturates() {
+        use crate::{command::StartTimer, core::TimerConfig, diagnostics::TimerDrift};
+
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<TimerDrift>();
+        let foo = world.register_component::<Foo>();
+        let bar = world.register_component::<Bar>();
+
+        let e1 = world.spawn_empty().id();
+        world.commands().entity(e1).queue(
+            StartTimer::<Foo>::new(Timer::from_seconds(1., bevy_time::TimerMode::Repeating))
+                .repeating_n(1),
+        );
+        // Ticked by `Duration::MAX`, which must not overflow.
+        let e2 = world
+            .spawn(TimerConfig {
+                scale: f32::INFINITY,
+            })
+            .id();
+        world
+            .commands()
+            .entity(e2)
+            .start_timer::<Bar>(Timer::from_seconds(1., bevy_time::TimerMode::Repeating));
+        world.flush();
+
+        for millis in [2250, 1000] {
+            world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_millis(millis));
+            world.run_system_cached(tick_entity_timers).unwrap();
+        }
+
+        // Late by the time past the last whole cycle ticked through, not past
+        // the first.
+        let stats = *world.resource::<TimerDrift>().get(foo).unwrap();
+        assert_eq!(stats.finishes, 1);
+        assert_eq!(stats.last, Duration::from_millis(250));
+        assert_eq!(world.resource::<TimerDrift>().get(bar).unwrap().finishes, 2);
+    }
+
+    #[test]
+    fn cancel_in_observer() {
+        use crate::query::TimerQueryMut;
+
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<Finished>();
+        let bar = world.register_component::<Bar>();
+
+        world.add_observer(
+            move |t: Trigger<OnTimerFinished, Foo>, mut timers: TimerQueryMut| {
+                assert!(timers.cancel(t.entity(), bar));
+                // Removed right away, before the cancel event is triggered.
+                assert!(!timers.get_mut(t.entity()).unwrap().contains(bar));
+            },
+        );
+        world.add_observer(
+            |_: Trigger<OnTimerCancelled, Bar>, mut f: ResMut<Finished>| {
+                f.0 = true;
+            },
+        );
+
+        let e1 = world.spawn_empty().id();
+        world
+            .commands()
+            .entity(e1)
+            .start_timer::<Foo>(Timer::from_seconds(1., bevy_time::TimerMode::Once))
+            .start_timer::<Bar>(Timer::from_seconds(5., bevy_time::TimerMode::Repeating));
+        world.flush();
+
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(1));
+        world.run_system_cached(tick_entity_timers).unwrap();
+
+        assert!(world.resource::<Finished>().0);
+        assert!(!world.get::<Timers>(e1).unwrap().contains(bar));
+    }
+
+    #[test]
+    fn reset_all() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        let foo = world.register_component::<Foo>();
+        let bar = world.register_component::<Bar>();
+
+        let e1 = world.spawn_empty().id();
+        world
+            .commands()
+            .entity(e1)
+            .start_timer::<Foo>(Timer::from_seconds(5., bevy_time::TimerMode::Once))
+            .start_timer::<Bar>(Timer::from_seconds(3., bevy_time::TimerMode::Repeating));
+        world.flush();
+
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(2));
+        world.run_system_cached(tick_entity_timers).unwrap();
+
+        world.commands().entity(e1).reset_all_timers();
+        world.flush();
+
+        let timers = world.get::<Timers>(e1).unwrap();
+        assert_eq!(timers.get(foo).unwrap().elapsed(), Duration::ZERO);
+        assert_eq!(timers.get(bar).unwrap().elapsed(), Duration::ZERO);
+    }
+
+    #[test]
+    fn timer_by_type() {
+        #[derive(Component)]
+        struct Unregistered;
+
+        let mut world = World::new();
+        let foo = world.register_component::<Foo>();
+        world.register_component::<Bar>();
+
+        let mut timers = Timers::default();
+        timers.insert(foo, Timer::from_seconds(2., bevy_time::TimerMode::Once));
+
+        let components = world.components();
+        assert_eq!(
+            timers.timer::<Foo>(components).unwrap().duration(),
+            Duration::from_secs(2)
+        );
+        // Registered, but without a timer.
+        assert!(timers.timer::<Bar>(components).is_none());
+        assert!(timers.timer::<Unregistered>(components).is_none());
+    }
+
+    #[test]
+    fn shortened_duration_finishes() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<Finished>();
+        let foo = world.register_component::<Foo>();
+
+        world.add_observer(
+            |_: Trigger<OnTimerFinished, Foo>, mut f: ResMut<Finished>| {
+                f.0 = true;
+            },
+        );
+
+        let e1 = world.spawn_empty().id();
+        world
+            .commands()
+            .entity(e1)
+            .start_timer::<Foo>(Timer::from_seconds(10., bevy_time::TimerMode::Once));
+        world.flush();
+
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(5));
+        world.run_system_cached(tick_entity_timers).unwrap();
+        assert!(!world.resource::<Finished>().0);
+
+        world
+            .commands()
+            .entity(e1)
+            .set_timer_duration::<Foo>(Duration::from_secs(3));
+        world.flush();
+
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_millis(16));
+        world.run_system_cached(tick_entity_timers).unwrap();
+        assert!(world.resource::<Finished>().0);
+        assert!(!world.get::<Timers>(e1).unwrap().contains(foo));
+    }
+
+    #[test]
+    fn observe_once() {
+        #[derive(Resource, Default)]
+        struct Runs(u32);
+
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<Runs>();
+
+        let e1 = world.spawn_empty().id();
+        world
+            .commands()
+            .entity(e1)
+            .start_timer::<Foo>(Timer::from_seconds(1., bevy_time::TimerMode::Repeating));
+        world.flush();
+        world.observe_timer_once::<Foo, _>(
+            e1,
+            |_: Trigger<OnTimerFinished, Foo>, mut runs: ResMut<Runs>| {
+                runs.0 += 1;
+            },
+        );
+
+        for _ in 0..3 {
+            world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs(1));
+            world.run_system_cached(tick_entity_timers).unwrap();
+        }
+
+        assert_eq!(world.resource::<Runs>().0, 1);
+        let mut observers = world.query::<&bevy_ecs::observer::Observer>();
+        assert_eq!(observers.iter(&world).count(), 0);
+    }
+
+    #[test]
+    fn millis_determinism() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        let foo = world.register_component::<Foo>();
+
+        let e1 = world.spawn_empty().id();
+        let e2 = world.spawn_empty().id();
+        for entity in [e1, e2] {
+            world
+                .commands()
+                .entity(entity)
+                .start_timer_millis::<Foo>(1000, bevy_time::TimerMode::Repeating);
+        }
+        world.flush();
+
+        let mut finish_ticks = [Vec::new(), Vec::new()];
+        for tick in 1..=130 {
+            world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_millis(16));
+            world.run_system_cached(tick_entity_timers).unwrap();
+            for (ticks, entity) in finish_ticks.iter_mut().zip([e1, e2]) {
+                let timers = world.get::<Timers>(entity).unwrap();
+                if timers.get(foo).unwrap().just_finished() {
+                    ticks.push(tick);
+                }
+            }
+        }
+
+        // 63 * 16ms = 1008ms, and 125 * 16ms = 2000ms exactly.
+        assert_eq!(finish_ticks[0], [63, 125]);
+        assert_eq!(finish_ticks[0], finish_ticks[1]);
+    }
+
+    #[test]
+    fn has_timers_marker() {
+        use crate::marker::HasTimers;
+
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        HasTimers::register(&mut world);
+
+        let e1 = world.spawn_empty().id();
+        world
+            .commands()
+            .entity(e1)
+            .start_timer::<Foo>(Timer::from_seconds(1., bevy_time::TimerMode::Once))
+            .start_timer::<Bar>(Timer::from_seconds(5., bevy_time::TimerMode::Once));
+        world.flush();
+        assert!(world.entity(e1).contains::<HasTimers>());
+
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(1));
+        world.run_system_cached(tick_entity_timers).unwrap();
+        // `Bar` is still running.
+        assert!(world.entity(e1).contains::<HasTimers>());
+
+        world.commands().entity(e1).cancel_timer::<Bar>();
+        world.flush();
+        assert!(world.entity(e1).contains::<Timers>());
+        assert!(!world.entity(e1).contains::<HasTimers>());
+
+        // Delayed timers count as well.
+        world.commands().entity(e1).start_timer_delayed::<Foo>(
+            Duration::from_secs(1),
+            Timer::from_seconds(1., bevy_time::TimerMode::Once),
+        );
+        world.flush();
+        assert!(world.entity(e1).contains::<HasTimers>());
+
+        world.entity_mut(e1).remove::<Timers>();
+        world.flush();
+        assert!(!world.entity(e1).contains::<HasTimers>());
+    }
+
+    #[test]
+    fn cancel_reasons() {
+        use crate::event::CancelReason;
+
+        #[derive(Resource, Default)]
+        struct Reasons(Vec<CancelReason>);
+
+        let mut world = World::new();
+        world.init_resource::<Reasons>();
+        world.add_observer(|t: Trigger<OnTimerCancelled>, mut r: ResMut<Reasons>| {
+            r.0.push(t.event().reason);
+        });
+
+        let e1 = world.spawn(Bar).id();
+        world
+            .commands()
+            .entity(e1)
+            .start_timer::<Foo>(Timer::from_seconds(5., bevy_time::TimerMode::Once))
+            .start_timer_linked::<Bar>(Timer::from_seconds(5., bevy_time::TimerMode::Once))
+            .cancel_timer::<Foo>()
+            .remove::<Bar>();
+        world.flush();
+        assert_eq!(
+            world.resource::<Reasons>().0,
+            [CancelReason::Manual, CancelReason::TagRemoved]
+        );
+
+        // Despawning reports running timers, linked ones included.
+        world.resource_mut::<Reasons>().0.clear();
+        world
+            .commands()
+            .entity(e1)
+            .insert(Bar)
+            .start_timer::<Foo>(Timer::from_seconds(5., bevy_time::TimerMode::Once))
+            .start_timer_linked::<Bar>(Timer::from_seconds(5., bevy_time::TimerMode::Once));
+        world.flush();
+        world.despawn(e1);
+        world.flush();
+        assert_eq!(
+            world.resource::<Reasons>().0,
+            [CancelReason::Despawn, CancelReason::Despawn]
+        );
+
+        // Removing `Timers` from a live entity drops its timers silently.
+        world.resource_mut::<Reasons>().0.clear();
+        let e2 = world.spawn_empty().id();
+        world
+            .commands()
+            .entity(e2)
+            .start_timer::<Foo>(Timer::from_seconds(5., bevy_time::TimerMode::Once));
+        world.flush();
+        world.entity_mut(e2).remove::<Timers>();
+        world.flush();
+        assert!(world.resource::<Reasons>().0.is_empty());
+    }
+
+    #[cfg(feature = "test-util")]
+    #[test]
+    fn advance_timers() {
+        use crate::testing::advance_timers;
+
+        let mut world = World::new();
+        world.init_resource::<Finished>();
+        world.add_observer(
+            |_: Trigger<OnTimerFinished, Foo>, mut f: ResMut<Finished>| {
+                f.0 = true;
+            },
+        );
+
+        let e1 = world.spawn_empty().id();
+        world
+            .commands()
+            .entity(e1)
+            .start_timer::<Foo>(Timer::from_seconds(2., bevy_time::TimerMode::Once));
+
+        advance_timers(&mut world, Duration::from_secs(1));
+        assert!(!world.resource::<Finished>().0);
+        advance_timers(&mut world, Duration::from_secs(1));
+        assert!(world.resource::<Finished>().0);
+    }
+
+    #[test]
+    fn many_timers_order() {
+        use bevy_ecs::component::ComponentId;
+
+        let mut timers = Timers::default();
+        for i in 0..12 {
+            let timer = Timer::from_seconds(i as f32 + 1., bevy_time::TimerMode::Once);
+            assert!(timers.insert(ComponentId::new(i), timer).is_none());
+        }
+        assert!(timers
+            .insert(
+                ComponentId::new(3),
+                Timer::from_seconds(100., bevy_time::TimerMode::Once)
+            )
+            .is_some());
+        assert!(timers.remove(ComponentId::new(1)).is_some());
+        assert!(timers.remove(ComponentId::new(1)).is_none());
+
+        let order: Vec<_> = timers.iter().map(|(id, _)| id.index()).collect();
+        assert_eq!(order, [0, 11, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+        for i in [0, 2, 4, 5, 6, 7, 8, 9, 10, 11] {
+            let timer = timers.get(ComponentId::new(i)).unwrap();
+            assert_eq!(timer.duration().as_secs(), i as u64 + 1);
+        }
+        assert_eq!(
+            timers
+                .get(ComponentId::new(3))
+                .unwrap()
+                .duration()
+                .as_secs(),
+            100
+        );
+
+        for i in 2..12 {
+            timers.remove(ComponentId::new(i));
+        }
+        assert_eq!(timers.len(), 1);
+        assert!(timers.contains(ComponentId::new(0)));
+    }
+
+    #[test]
+    fn next_to_finish() {
+        let mut world = World::new();
+        let foo = world.register_component::<Foo>();
+        let bar = world.register_component::<Bar>();
+        let baz = world.register_component::<Baz>();
+
+        let mut timers = Timers::default();
+        assert_eq!(timers.next_to_finish(), None);
+
+        timers.insert(foo, Timer::from_seconds(3., bevy_time::TimerMode::Once));
+        timers.insert(bar, Timer::from_seconds(2., bevy_time::TimerMode::Once));
+        timers.insert(baz, Timer::from_seconds(2., bevy_time::TimerMode::Once));
+        assert_eq!(timers.next_to_finish(), Some((bar, Duration::from_secs(2))));
+
+        timers
+            .get_mut(foo)
+            .unwrap()
+            .tick(Duration::from_millis(1500));
+        assert_eq!(
+            timers.next_to_finish(),
+            Some((foo, Duration::from_millis(1500)))
+        );
+
+        timers.get_mut(foo).unwrap().pause();
+        assert_eq!(timers.next_to_finish(), Some((bar, Duration::from_secs(2))));
+    }
+
+    #[cfg(feature = "bevy_reflect")]
+    #[test]
+    fn timer_tag_paths() {
+        use bevy_app::App;
+        use bevy_ecs::reflect::AppTypeRegistry;
+        use core::any::TypeId;
+
+        use bevy_reflect::{
+            serde::{TypedReflectDeserializer, TypedReflectSerializer},
+            FromReflect, Reflect, TypePath,
+        };
+        use serde::de::DeserializeSeed;
+
+        use crate::{
+            plugin::{AppTimersExt, ScheduleTimerTickPlugin},
+            reflect::TimerTagPaths,
+        };
+
+        #[derive(Component, Reflect)]
+        struct Poisoned;
+
+        let mut app = App::new();
+        app.add_plugins(ScheduleTimerTickPlugin::pre_update())
+            .register_timer_tag::<Poisoned>();
+        let world = app.world_mut();
+        let poisoned = world.register_component::<Poisoned>();
+        let foo = world.register_component::<Foo>();
+        assert!(world
+            .resource::<AppTypeRegistry>()
+            .read()
+            .get_with_type_path(Poisoned::type_path())
+            .is_some());
+
+        let mut timers = Timers::default();
+        let mut timer = Timer::from_seconds(4., bevy_time::TimerMode::Once);
+        timer.tick(Duration::from_secs(1));
+        timers.insert(poisoned, timer);
+        timers.insert(foo, Timer::from_seconds(1., bevy_time::TimerMode::Once));
+
+        let saved = world.resource::<TimerTagPaths>().snapshot(&timers);
+        assert_eq!(saved.len(), 1);
+        assert_eq!(saved[0].0, Poisoned::type_path());
+
+        // Goes through an actual serializer and back.
+        let registry = world.resource::<AppTypeRegistry>().read();
+        let json: Vec<_> = saved
+            .iter()
+            .map(|(path, snapshot)| {
+                let serializer = TypedReflectSerializer::new(snapshot, &registry);
+                (
+                    path.to_string(),
+                    serde_json::to_string(&serializer).unwrap(),
+                )
+            })
+            .collect();
+        let registration = registry.get(TypeId::of::<TimerSnapshot>()).unwrap();
+        let restored: Vec<_> = json
+            .iter()
+            .map(|(path, json)| {
+                let deserializer = TypedReflectDeserializer::new(registration, &registry);
+                let mut json = serde_json::Deserializer::from_str(json);
+                let reflected = deserializer.deserialize(&mut json).unwrap();
+                (
+                    path.clone(),
+                    TimerSnapshot::from_reflect(&*reflected).unwrap(),
+                )
+            })
+            .collect();
+        drop(registry);
+        assert_eq!(restored[0].1, saved[0].1);
+
+        // Loading into a world that assigned a different id to the tag.
+        let mut other = App::new();
+        other.world_mut().register_component::<Foo>();
+        other.register_timer_tag::<Poisoned>();
+        let world = other.world_mut();
+        let poisoned = world.register_component::<Poisoned>();
+        let mut loaded = Timers::default();
+        world.resource::<TimerTagPaths>().apply_snapshot(
+            &mut loaded,
+            restored
+                .iter()
+                .map(|(path, snapshot)| (path.as_str(), *snapshot)),
+        );
+        assert_eq!(loaded.len(), 1);
+        assert_eq!(
+            loaded.get(poisoned).unwrap().remaining(),
+            Duration::from_secs(3)
+        );
+    }
+
+    #[test]
+    fn thresholds() {
+        #[derive(Resource, Default)]
+        struct Events(Vec<&'static str>);
+
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<Events>();
+        world.add_observer(
+            |t: Trigger<OnTimerThreshold, Foo>, mut events: ResMut<Events>| {
+                events.0.push(if t.event().fraction == 0.5 {
+                    "half"
+                } else {
+                    "full"
+                });
+            },
+        );
+        world.add_observer(
+            |_: Trigger<OnTimerFinished, Foo>, mut events: ResMut<Events>| {
+                events.0.push("finished");
+            },
+        );
+
+        let e1 = world.spawn_empty().id();
+        world
+            .commands()
+            .entity(e1)
+            .start_timer_with_thresholds::<Foo>(
+                Timer::from_seconds(2., bevy_time::TimerMode::Once),
+                vec![1.0, 0.5],
+            );
+        world.flush();
+
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_millis(500));
+        world.run_system_cached(tick_entity_timers).unwrap();
+        assert!(world.resource::<Events>().0.is_empty());
+
+        // Crosses both thresholds and finishes in a single tick.
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(2));
+        world.run_system_cached(tick_entity_timers).unwrap();
+        assert_eq!(world.resource::<Events>().0, ["half", "full", "finished"]);
+    }
+
+    #[test]
+    fn timer_mode() {
+        let mut world = World::new();
+        let foo = world.register_component::<Foo>();
+        let bar = world.register_component::<Bar>();
+
+        let e1 = world.spawn_empty().id();
+        world
+            .commands()
+            .entity(e1)
+            .start_timer::<Foo>(Timer::from_seconds(1., bevy_time::TimerMode::Repeating));
+        world.flush();
+
+        let timers = world.get::<Timers>(e1).unwrap();
+        assert_eq!(timers.mode(foo), Some(bevy_time::TimerMode::Repeating));
+        assert_eq!(timers.mode(bar), None);
+    }
+
+    #[test]
+    fn tick_single_timer() {
+        #[derive(Resource, Default)]
+        struct FinishCount(u32);
+
+        let mut world = World::new();
+        world.init_resource::<FinishCount>();
+        world.add_observer(
+            |_: Trigger<OnTimerFinished, Foo>, mut finished: ResMut<FinishCount>| {
+                finished.0 += 1;
+            },
+        );
+        let foo = world.register_component::<Foo>();
+        let bar = world.register_component::<Bar>();
+
+        let e1 = world.spawn_empty().id();
+        world
+            .commands()
+            .entity(e1)
+            .start_timer::<Foo>(Timer::from_seconds(2., bevy_time::TimerMode::Once))
+            .start_timer::<Bar>(Timer::from_seconds(2., bevy_time::TimerMode::Once));
+        world.flush();
+
+        world.tick_timer::<Foo>(e1, Duration::from_secs(1));
+        let timers = world.get::<Timers>(e1).unwrap();
+        assert_eq!(timers.get(foo).unwrap().elapsed(), Duration::from_secs(1));
+        assert_eq!(timers.get(bar).unwrap().elapsed(), Duration::ZERO);
+
+        world.tick_timer::<Foo>(e1, Duration::from_secs(1));
+        assert_eq!(world.resource::<FinishCount>().0, 1);
+        let timers = world.get::<Timers>(e1).unwrap();
+        assert!(!timers.contains(foo));
+        assert_eq!(timers.get(bar).unwrap().elapsed(), Duration::ZERO);
+    }
+
+    #[test]
+    fn despawn_in_finish_observer() {
+        #[derive(Component)]
+        struct Hit;
+
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.add_observer(
+            |t: Trigger<OnTimerFinished, Foo>, mut commands: bevy_ecs::system::Commands| {
+                commands.entity(t.entity()).despawn();
+            },
+        );
+        // Would panic if run for the despawned entity.
+        world.add_observer(
+            |t: Trigger<OnTimerFinished, Bar>, mut commands: bevy_ecs::system::Commands| {
+                commands.entity(t.entity()).insert(Hit);
+            },
+        );
+
+        let e1 = world.spawn_empty().id();
+        world
+            .commands()
+            .entity(e1)
+            .start_timer_with_priority::<Foo>(
+                Timer::from_seconds(1., bevy_time::TimerMode::Once),
+                1,
+            )
+            .start_timer::<Bar>(Timer::from_seconds(1., bevy_time::TimerMode::Once));
+        world.flush();
+
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(1));
+        world.run_system_cached(tick_entity_timers).unwrap();
+        assert!(world.get_entity(e1).is_err());
+    }
+
+    #[test]
+    fn one_shot_removal_delay() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.insert_resource(TimerSettings {
+            one_shot_removal_delay: 1,
+            ..Default::default()
+        });
+        TimerIndex::register(&mut world);
+        let foo = world.register_component::<Foo>();
+
+        let e1 = world.spawn_empty().id();
+        world
+            .commands()
+            .entity(e1)
+            .start_timer::<Foo>(Timer::from_seconds(1., bevy_time::TimerMode::Once));
+        world.flush();
+
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(1));
+        world.run_system_cached(tick_entity_timers).unwrap();
+        // Still readable after the tick it finished in.
+        let timers = world.get::<Timers>(e1).unwrap();
+        assert!(timers.get(foo).unwrap().finished());
+        assert!(world.resource::<TimerIndex>().contains(foo, e1));
+
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_millis(16));
+        world.run_system_cached(tick_entity_timers).unwrap();
+        assert!(!world.get::<Timers>(e1).unwrap().contains(foo));
+        assert!(!world.resource::<TimerIndex>().contains(foo, e1));
+    }
+
+    #[test]
+    fn world_start_timer_key() {
+        #[derive(Resource, Default)]
+        struct CancelCount(u32);
+
+        let mut world = World::new();
+        world.init_resource::<CancelCount>();
+        world.add_observer(
+            |_: Trigger<OnTimerCancelled, Foo>, mut cancelled: ResMut<CancelCount>| {
+                cancelled.0 += 1;
+            },
+        );
+
+        let e1 = world.spawn_empty().id();
+        let key = world.start_timer::<Foo>(e1, Timer::from_seconds(2., bevy_time::TimerMode::Once));
+        assert_eq!(key, world.register_component::<Foo>());
+        assert!(world.get::<Timers>(e1).unwrap().contains(key));
+
+        assert!(world.cancel_timer_by_id(e1, key));
+        assert!(!world.get::<Timers>(e1).unwrap().contains(key));
+        assert_eq!(world.resource::<CancelCount>().0, 1);
+        assert!(!world.cancel_timer_by_id(e1, key));
+    }
+
+    #[test]
+    fn remap_timer_ids() {
+        #[derive(Resource, Default)]
+        struct FinishCount(u32);
+
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<FinishCount>();
+        world.add_observer(
+            |_: Trigger<OnTimerFinished, Foo>, mut finished: ResMut<FinishCount>| {
+                finished.0 += 1;
+            },
+        );
+        // A previous, dynamic registration of the tag.
+        let old = world.register_component_with_descriptor(
+            bevy_ecs::component::ComponentDescriptor::new::<Foo>(),
+        );
+        let foo = world.register_component::<Foo>();
+        assert_ne!(old, foo);
+
+        let e1 = world.spawn_empty().id();
+        let mut timers = Timers::default();
+        timers.insert(old, Timer::from_seconds(1., bevy_time::TimerMode::Once));
+        world.entity_mut(e1).insert(timers);
+
+        assert_eq!(world.remap_timer_ids(old, foo), 1);
+        let timers = world.get::<Timers>(e1).unwrap();
+        assert!(!timers.contains(old));
+        assert!(timers.contains(foo));
+
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(1));
+        world.run_system_cached(tick_entity_timers).unwrap();
+        assert_eq!(world.resource::<FinishCount>().0, 1);
+    }
+
+    #[test]
+    fn remap_gated_and_linked_timers() {
+        // Stands in for a tag re-registered under a new id.
+        #[derive(Component)]
+        struct Tag;
+
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        let foo = world.register_component::<Foo>();
+        let bar = world.register_component::<Bar>();
+        let gate = world.register_component::<Gate>();
+        let baz = world.register_component::<Baz>();
+        let tag = world.register_component::<Tag>();
+
+        let e1 = world.spawn_empty().id();
+        let e2 = world.spawn(Bar).id();
+        world
+            .commands()
+            .entity(e1)
+            .start_timer_gated::<Foo, Gate>(Timer::from_seconds(5., bevy_time::TimerMode::Once));
+        world
+            .commands()
+            .entity(e2)
+            .start_timer_linked::<Bar>(Timer::from_seconds(5., bevy_time::TimerMode::Once));
+        world.flush();
+
+        world.remap_timer_ids(gate, baz);
+        world.remap_timer_ids(bar, tag);
+
+        let advance = |world: &mut World| {
+            world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs(1));
+            world.run_system_cached(tick_entity_timers).unwrap();
+            world.get::<Timers>(e1).unwrap().get(foo).unwrap().elapsed()
+        };
+        assert_eq!(advance(&mut world), Duration::ZERO);
+        world.entity_mut(e1).insert(Baz);
+        assert_eq!(advance(&mut world), Duration::from_secs(1));
+        world.entity_mut(e1).remove::<Baz>();
+        assert_eq!(advance(&mut world), Duration::from_secs(1));
+
+        world.entity_mut(e2).insert(Tag);
+        world.entity_mut(e2).remove::<Tag>();
+        world.flush();
+        assert!(!world.get::<Timers>(e2).unwrap().contains(tag));
+    }
+
+    #[test]
+    fn aligned_timer() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<FinishedAt>();
+        world.add_observer(
+            |_: Trigger<OnTimerFinished, Foo>,
+             time: Res<Time>,
+             mut finished: ResMut<FinishedAt>| {
+                finished.0.push(time.elapsed());
+            },
+        );
+
+        let step = |world: &mut World, millis| {
+            world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_millis(millis));
+            world.run_system_cached(tick_entity_timers).unwrap();
+        };
+
+        // Started mid-second.
+        step(&mut world, 1300);
+        let e1 = world.spawn_empty().id();
+        world
+            .commands()
+            .entity(e1)
+            .start_timer_aligned::<Foo>(Duration::from_secs(1));
+        world.flush();
+
+        step(&mut world, 600);
+        assert!(world.resource::<FinishedAt>().0.is_empty());
+        step(&mut world, 100);
+        step(&mut world, 500);
+        step(&mut world, 500);
+        assert_eq!(
+            world.resource::<FinishedAt>().0,
+            [Duration::from_secs(2), Duration::from_secs(3)]
+        );
+    }
+
+    #[test]
+    fn repetitions_remaining() {
+        #[derive(Resource, Default)]
+        struct Remaining(Vec<Option<u32>>);
+
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<Remaining>();
+        world.add_observer(
+            |t: Trigger<OnTimerFinished, Foo>, mut remaining: ResMut<Remaining>| {
+                remaining.0.push(t.event().repetitions_remaining);
+            },
+        );
+        let foo = world.register_component::<Foo>();
+
+        let e1 = world.spawn_empty().id();
+        world.commands().entity(e1).start_timer_repeating_n::<Foo>(
+            Timer::from_seconds(1., bevy_time::TimerMode::Repeating),
+            3,
+        );
+        world.flush();
+        assert_eq!(
+            world.get::<Timers>(e1).unwrap().repetitions_remaining(foo),
+            Some(3)
+        );
+
+        for _ in 0..4 {
+            world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs(1));
+            world.run_system_cached(tick_entity_timers).unwrap();
+        }
+        assert_eq!(world.resource::<Remaining>().0, [Some(2), Some(1), Some(0)]);
+        assert!(!world.get::<Timers>(e1).unwrap().contains(foo));
+    }
+
+    #[test]
+    fn repeating_n_thresholds() {
+        use crate::command::StartTimer;
+
+        #[derive(Resource, Default)]
+        struct Fractions(Vec<f32>);
+
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<Fractions>();
+        world.add_observer(
+            |t: Trigger<OnTimerThreshold, Foo>, mut fractions: ResMut<Fractions>| {
+                fractions.0.push(t.event().fraction);
+            },
+        );
+
+        let e1 = world.spawn_empty().id();
+        world.commands().entity(e1).queue(
+            StartTimer::<Foo>::new(Timer::from_seconds(1., bevy_time::TimerMode::Repeating))
+                .repeating_n(1)
+                .with_thresholds(vec![0.25]),
+        );
+        world.flush();
+
+        // The last repetition finishes, so no cycle is started past it.
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_millis(1500));
+        world.run_system_cached(tick_entity_timers).unwrap();
+        assert_eq!(world.resource::<Fractions>().0, [0.25]);
+    }
+
+    #[test]
+    fn boost_timer() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        let foo = world.register_component::<Foo>();
+
+        let e1 = world.spawn_empty().id();
+        world
+            .commands()
+            .entity(e1)
+            .start_timer::<Foo>(Timer::from_seconds(10., bevy_time::TimerMode::Once))
+            .boost_timer::<Foo>(2., Duration::from_secs(1));
+        world.flush();
+
+        let mut elapsed_after = |millis| {
+            world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_millis(millis));
+            world.run_system_cached(tick_entity_timers).unwrap();
+            world.get::<Timers>(e1).unwrap().get(foo).unwrap().elapsed()
+        };
+        assert_eq!(elapsed_after(500), Duration::from_secs(1));
+        // Only the first half of this tick is boosted.
+        assert_eq!(elapsed_after(1000), Duration::from_millis(2500));
+        assert_eq!(elapsed_after(1000), Duration::from_millis(3500));
+    }
+
+    #[test]
+    fn missing_tick_warning() {
+        use crate::core::TickWarningLogged;
+
+        let mut world = World::new();
+        let e1 = world.spawn_empty().id();
+        world
+            .commands()
+            .entity(e1)
+            .start_timer::<Foo>(Timer::from_seconds(1., bevy_time::TimerMode::Once));
+        world.flush();
+        assert!(world.contains_resource::<TickWarningLogged>());
+
+        #[cfg(feature = "bevy_app")]
+        {
+            use bevy_app::App;
+
+            use crate::plugin::ScheduleTimerTickPlugin;
+
+            let mut app = App::new();
+            app.add_plugins(ScheduleTimerTickPlugin::pre_update());
+            let world = app.world_mut();
+            let e1 = world.spawn_empty().id();
+            world
+                .commands()
+                .entity(e1)
+                .start_timer::<Foo>(Timer::from_seconds(1., bevy_time::TimerMode::Once));
+            world.flush();
+            assert!(!world.contains_resource::<TickWarningLogged>());
+        }
+    }
+
+    #[test]
+    fn unified_events() {
+        use bevy_ecs::system::SystemState;
+
+        use crate::{
+            event::CancelReason,
+            lifecycle::{OnTimerEvent, TimerEventKind},
+            query::TimerQueryMut,
+        };
+
+        #[derive(Resource, Default)]
+        struct Kinds(Vec<TimerEventKind>);
+
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<Kinds>();
+        OnTimerEvent::register(&mut world);
+        world.add_observer(|t: Trigger<OnTimerEvent, Foo>, mut kinds: ResMut<Kinds>| {
+            kinds.0.push(t.event().kind);
+        });
+
+        let e1 = world.spawn_empty().id();
+        world
+            .commands()
+            .entity(e1)
+            .start_timer_with_thresholds::<Foo>(
+                Timer::from_seconds(1., bevy_time::TimerMode::Repeating),
+                vec![0.5],
+            );
+        world.flush();
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_millis(1000));
+        world.run_system_cached(tick_entity_timers).unwrap();
+        // Pausing an already paused timer reports nothing.
+        world
+            .commands()
+            .entity(e1)
+            .pause_timer::<Foo>()
+            .pause_timer::<Foo>()
+            .unpause_timer::<Foo>();
+        world.flush();
+        let foo = world.register_component::<Foo>();
+        let mut state = SystemState::<TimerQueryMut>::new(&mut world);
+        assert!(state.get_mut(&mut world).pause(e1, foo));
+        state.apply(&mut world);
+        world.commands().entity(e1).cancel_timer::<Foo>();
+        world.flush();
+
+        assert_eq!(
+            world.resource::<Kinds>().0,
+            [
+                TimerEventKind::Started { replaced: false },
+                TimerEventKind::Threshold { fraction: 0.5 },
+                TimerEventKind::Finished {
+                    duration: Duration::from_secs(1),
+                    repetitions_remaining: None,
+                },
+                TimerEventKind::Paused,
+                TimerEventKind::Unpaused,
+                TimerEventKind::Paused,
+                TimerEventKind::Cancelled {
+                    reason: CancelReason::Manual,
+                },
+            ]
+        );
+
+        world.resource_mut::<Kinds>().0.clear();
+        world
+            .commands()
+            .entity(e1)
+            .start_timer_with_second_ticks::<Foo>(Timer::from_seconds(
+                2.,
+                bevy_time::TimerMode::Once,
+            ));
+        world.flush();
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(1));
+        world.run_system_cached(tick_entity_timers).unwrap();
+        assert_eq!(
+            world.resource::<Kinds>().0,
+            [
+                TimerEventKind::Started { replaced: false },
+                TimerEventKind::SecondTick { remaining_secs: 1 },
+            ]
+        );
+    }
+
+    #[test]
+    fn timers_view() {
+        let mut world = World::new();
+        let foo = world.register_component::<Foo>();
+        let bar = world.register_component::<Bar>();
+
+        let mut timers = Timers::default();
+        timers.insert(foo, Timer::from_seconds(1., bevy_time::TimerMode::Once));
+        timers.insert(bar, Timer::from_seconds(2., bevy_time::TimerMode::Once));
+
+        let view = timers.view();
+        assert_eq!(view.len(), 2);
+        assert_eq!(view.get(bar).unwrap().duration(), Duration::from_secs(2));
+        let listed: Vec<_> = view
+            .iter()
+            .map(|(component, timer)| {
+                (
+                    view.name(component, world.components()).unwrap(),
+                    timer.duration().as_secs(),
+                )
+            })
+            .collect();
+        assert_eq!(listed, [("Foo".to_string(), 1), ("Bar".to_string(), 2)]);
+    }
+
+    #[test]
+    fn swap_timers() {
+        let mut world = World::new();
+        let foo = world.register_component::<Foo>();
+        let bar = world.register_component::<Bar>();
+        let baz = world.register_component::<Baz>();
+
+        let e1 = world.spawn_empty().id();
+        let mut timers = Timers::default();
+        let mut timer = Timer::from_seconds(2., bevy_time::TimerMode::Once);
+        timer.tick(Duration::from_secs(1));
+        timers.insert(foo, timer);
+        let mut timer = Timer::from_seconds(4., bevy_time::TimerMode::Repeating);
+        timer.tick(Duration::from_secs(2));
+        timers.insert(bar, timer);
+        world.entity_mut(e1).insert(timers);
+
+        world.commands().entity(e1).swap_timers::<Foo, Bar>();
+        world.flush();
+        let timers = world.get::<Timers>(e1).unwrap();
+        assert_eq!(timers.get(foo).unwrap().duration(), Duration::from_secs(4));
+        assert_eq!(timers.get(foo).unwrap().elapsed(), Duration::from_secs(2));
+        assert_eq!(timers.mode(foo), Some(bevy_time::TimerMode::Repeating));
+        assert_eq!(timers.get(bar).unwrap().duration(), Duration::from_secs(2));
+        assert_eq!(timers.get(bar).unwrap().elapsed(), Duration::from_secs(1));
+
+        // With one missing, the other moves.
+        world.commands().entity(e1).swap_timers::<Bar, Baz>();
+        world.flush();
+        let timers = world.get::<Timers>(e1).unwrap();
+        assert!(!timers.contains(bar));
+        assert_eq!(timers.get(baz).unwrap().elapsed(), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn timer_callback() {
+        #[derive(Resource, Default)]
+        struct Fired(Vec<Entity>);
+
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<Fired>();
+        let foo = world.register_component::<Foo>();
+
+        let e1 = world.spawn_empty().id();
+        world
+            .commands()
+            .entity(e1)
+            .start_timer_with_callback::<Foo>(
+                Timer::from_seconds(1., bevy_time::TimerMode::Repeating),
+                |world: &mut World, entity| world.resource_mut::<Fired>().0.push(entity),
+            )
+            .start_timer_with_callback::<Bar>(
+                Timer::from_seconds(1., bevy_time::TimerMode::Once),
+                |world: &mut World, entity| {
+                    world.entity_mut(entity).insert(Bar);
+                },
+            );
+        world.flush();
+
+        for _ in 0..2 {
+            world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs(1));
+            world.run_system_cached(tick_entity_timers).unwrap();
+        }
+        assert_eq!(world.resource::<Fired>().0, [e1, e1]);
+        assert!(world.entity(e1).contains::<Bar>());
+
+        // Restarting without a callback drops it.
+        world
+            .commands()
+            .entity(e1)
+            .start_timer::<Foo>(Timer::from_seconds(1., bevy_time::TimerMode::Once));
+        world.flush();
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(1));
+        world.run_system_cached(tick_entity_timers).unwrap();
+        assert_eq!(world.resource::<Fired>().0.len(), 2);
+        assert!(!world.get::<Timers>(e1).unwrap().contains(foo));
+    }
+
+    #[test]
+    fn timer_callback_batched_multi_fire() {
+        #[derive(Resource, Default)]
+        struct Calls(u32);
+
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<Calls>();
+        world.insert_resource(TimerSettings {
+            finished_events: FinishedEvents::Batched,
+            max_fires_per_tick: 10,
+            ..Default::default()
+        });
+
+        let e1 = world.spawn_empty().id();
+        world
+            .commands()
+            .entity(e1)
+            .start_timer_with_callback::<Foo>(
+                Timer::from_seconds(1., bevy_time::TimerMode::Repeating),
+                |world: &mut World, _| world.resource_mut::<Calls>().0 += 1,
+            );
+        world.flush();
+
+        // Three fires in one tick run the callback once, without any
+        // `OnTimerFinished`.
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(3));
+        world.run_system_cached(tick_entity_timers).unwrap();
+        assert_eq!(world.resource::<Calls>().0, 1);
+
+        // The callback is kept for the next finish of the repeating timer.
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(1));
+        world.run_system_cached(tick_entity_timers).unwrap();
+        assert_eq!(world.resource::<Calls>().0, 2);
+    }
+
+    #[test]
+    fn timer_callback_follows_timer() {
+        use crate::{command::TransferTimer, core::ConflictPolicy};
+
+        fn record(world: &mut World, _: Entity) {
+            let elapsed = world.resource::<Time>().elapsed();
+            world.resource_mut::<FinishedAt>().0.push(elapsed);
+        }
+
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<FinishedAt>();
+
+        let e1 = world.spawn_empty().id();
+        world
+            .commands()
+            .entity(e1)
+            .start_timer_with_callback::<Foo>(
+                Timer::from_seconds(1., bevy_time::TimerMode::Once),
+                record,
+            )
+            .start_timer::<Bar>(Timer::from_seconds(5., bevy_time::TimerMode::Once))
+            .swap_timers::<Foo, Bar>();
+        world.flush();
+
+        // The callback runs when the 1s timer, now tagged `Bar`, finishes.
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(1));
+        world.run_system_cached(tick_entity_timers).unwrap();
+        assert_eq!(world.resource::<FinishedAt>().0, [Duration::from_secs(1)]);
+
+        // Merging into another entity moves the callback along.
+        let e2 = world.spawn_empty().id();
+        world
+            .commands()
+            .entity(e1)
+            .start_timer_with_callback::<Baz>(
+                Timer::from_seconds(1., bevy_time::TimerMode::Once),
+                record,
+            );
+        world.flush();
+        world.merge_timers(e1, e2, ConflictPolicy::KeepOther);
+        assert!(world.get::<Timers>(e1).is_none());
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(1));
+        world.run_system_cached(tick_entity_timers).unwrap();
+        assert_eq!(
+            world.resource::<FinishedAt>().0,
+            [Duration::from_secs(1), Duration::from_secs(2)]
+        );
+
+        // Transferring to another entity moves the callback along, even when
+        // the source observes `OnTimerCancelled`.
+        let e3 = world.spawn_empty().id();
+        world
+            .commands()
+            .entity(e2)
+            .start_timer_with_callback::<Baz>(
+                Timer::from_seconds(1., bevy_time::TimerMode::Once),
+                record,
+            )
+            .queue(TransferTimer::<Baz>::new(e3).with_events());
+        world.flush();
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(1));
+        world.run_system_cached(tick_entity_timers).unwrap();
+        assert_eq!(
+            world.resource::<FinishedAt>().0,
+            [
+                Duration::from_secs(1),
+                Duration::from_secs(2),
+                Duration::from_secs(3)
+            ]
+        );
+    }
+
+    #[test]
+    fn time_until_all_idle() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        let foo = world.register_component::<Foo>();
+        let bar = world.register_component::<Bar>();
+
+        let e1 = world.spawn_empty().id();
+        world
+            .commands()
+            .entity(e1)
+            .start_timer::<Foo>(Timer::from_seconds(3., bevy_time::TimerMode::Once))
+            .start_timer_repeating_n::<Bar>(
+                Timer::from_seconds(1., bevy_time::TimerMode::Repeating),
+                2,
+            );
+        world.flush();
+        assert_eq!(
+            world.get::<Timers>(e1).unwrap().time_until_all_idle(),
+            Some(Duration::from_secs(3))
+        );
+
+        world
+            .get_mut::<Timers>(e1)
+            .unwrap()
+            .get_mut(foo)
+            .unwrap()
+            .pause();
+        // Both cycles of the limited timer are left.
+        assert_eq!(
+            world.get::<Timers>(e1).unwrap().time_until_all_idle(),
+            Some(Duration::from_secs(2))
+        );
+
+        world
+            .get_mut::<Timers>(e1)
+            .unwrap()
+            .get_mut(bar)
+            .unwrap()
+            .pause();
+        assert_eq!(world.get::<Timers>(e1).unwrap().time_until_all_idle(), None);
+        assert_eq!(Timers::default().time_until_all_idle(), None);
+    }
+
+    #[test]
+    fn multi_fire() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<FinishedAt>();
+        world.init_resource::<TimersFinishedThisFrame>();
+        world.insert_resource(TimerSettings {
+            max_fires_per_tick: 100,
+            ..Default::default()
+        });
+        world.add_observer(
+            |t: Trigger<OnTimerFinished, Foo>, mut at: ResMut<FinishedAt>| {
+                at.0.push(t.event().duration);
+            },
+        );
+        let foo = world.register_component::<Foo>();
+
+        let e1 = world.spawn_empty().id();
+        world.commands().entity(e1).start_timer::<Foo>(Timer::new(
+            Duration::from_micros(300),
+            bevy_time::TimerMode::Repeating,
+        ));
+        world.flush();
+
+        let mut fired = 0;
+        for _ in 0..10 {
+            world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_millis(16));
+            world.run_system_cached(tick_entity_timers).unwrap();
+            world.flush();
+            let now = world.resource::<FinishedAt>().0.len();
+            // 53 or 54 finishes, depending on the carried elapsed time.
+            assert!((53..=54).contains(&(now - fired)));
+            fired = now;
+        }
+        // The rest is carried over without drifting.
+        assert_eq!(fired, 160_000 / 300);
+        assert_eq!(
+            world.get::<Timers>(e1).unwrap().get(foo).unwrap().elapsed(),
+            Duration::from_micros(160_000 % 300)
+        );
+
+        // Finishes past the cap are counted but not triggered.
+        world.resource_mut::<TimerSettings>().max_fires_per_tick = 10;
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_millis(16));
+        world.run_system_cached(tick_entity_timers).unwrap();
+        world.flush();
+        assert_eq!(world.resource::<FinishedAt>().0.len() - fired, 10);
+        assert!(
+            world
+                .resource::<TimersFinishedThisFrame>()
+                .times_finished(e1, foo)
+                > 10
+        );
+    }
+
+    #[test]
+    fn multi_fire_default() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<FinishedAt>();
+        world.add_observer(
+            |t: Trigger<OnTimerFinished, Foo>, mut at: ResMut<FinishedAt>| {
+                at.0.push(t.event().duration);
+            },
+        );
+
+        let e1 = world.spawn_empty().id();
+        world.commands().entity(e1).start_timer::<Foo>(Timer::new(
+            Duration::from_millis(1),
+            bevy_time::TimerMode::Repeating,
+        ));
+        world.flush();
+
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_millis(16));
+        world.run_system_cached(tick_entity_timers).unwrap();
+        world.flush();
+        assert_eq!(world.resource::<FinishedAt>().0.len(), 16);
+
+        // A huge delta is capped.
+        world.resource_mut::<FinishedAt>().0.clear();
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(1));
+        world.run_system_cached(tick_entity_timers).unwrap();
+        world.flush();
+        assert_eq!(
+            world.resource::<FinishedAt>().0.len(),
+            TimerSettings::DEFAULT_MAX_FIRES_PER_TICK as usize
+        );
+    }
+
+    #[test]
+    fn copy_timer_from() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<Started>();
+        world.add_observer(
+            |t: Trigger<OnTimerStarted, Foo>, mut started: ResMut<Started>| {
+                started.0.push(t.event().replaced);
+            },
+        );
+        let foo = world.register_component::<Foo>();
+
+        let e1 = world.spawn_empty().id();
+        let e2 = world.spawn_empty().id();
+
+        world
+            .commands()
+            .entity(e1)
+            .start_timer::<Foo>(Timer::from_seconds(2., bevy_time::TimerMode::Once));
+        world.flush();
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(1));
+        world.run_system_cached(tick_entity_timers).unwrap();
+
+        world.commands().entity(e2).copy_timer_from::<Foo>(e1);
+        world.flush();
+        let remaining = |world: &World, entity| {
+            world
+                .get::<Timers>(entity)
+                .unwrap()
+                .get(foo)
+                .unwrap()
+                .remaining()
+        };
+        assert_eq!(remaining(&world, e2), Duration::from_secs(1));
+        assert_eq!(remaining(&world, e1), Duration::from_secs(1));
+        assert_eq!(world.resource::<Started>().0, vec![false, false]);
+
+        // Missing timers and entities are ignored.
+        world.commands().entity(e1).copy_timer_from::<Bar>(e2);
+        let e3 = world.spawn_empty().id();
+        world.despawn(e3);
+        world.commands().entity(e2).copy_timer_from::<Foo>(e3);
+        world.flush();
+        assert_eq!(remaining(&world, e2), Duration::from_secs(1));
+        assert_eq!(world.resource::<Started>().0.len(), 2);
+    }
+
+    #[test]
+    fn default_mode() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.insert_resource(TimerSettings {
+            default_mode: bevy_time::TimerMode::Repeating,
+            ..Default::default()
+        });
+        let foo = world.register_component::<Foo>();
+        let bar = world.register_component::<Bar>();
+
+        let e1 = world.spawn_empty().id();
+        world
+            .commands()
+            .entity(e1)
+            .start_timer_secs::<Foo>(1., None)
+            .start_timer_millis::<Bar>(1000, bevy_time::TimerMode::Once);
+        world.flush();
+
+        for _ in 0..2 {
+            world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs(1));
+            world.run_system_cached(tick_entity_timers).unwrap();
+            world.flush();
+        }
+        let timers = world.get::<Timers>(e1).unwrap();
+        assert_eq!(
+            timers.get(foo).unwrap().mode(),
+            bevy_time::TimerMode::Repeating
+        );
+        // An explicit mode wins over the default.
+        assert!(!timers.contains(bar));
+    }
+
+    #[test]
+    fn has_ever_finished() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        let foo = world.register_component::<Foo>();
+
+        let e1 = world.spawn_empty().id();
+        world
+            .commands()
+            .entity(e1)
+            .start_timer::<Foo>(Timer::from_seconds(1., bevy_time::TimerMode::Repeating));
+        world.flush();
+        assert!(!world.get::<Timers>(e1).unwrap().has_ever_finished(foo));
+
+        for _ in 0..3 {
+            world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_millis(600));
+            world.run_system_cached(tick_entity_timers).unwrap();
+            world.flush();
+        }
+        // Finished during the second tick, and no longer during the third.
+        let timers = world.get::<Timers>(e1).unwrap();
+        assert!(!timers.get(foo).unwrap().finished());
+        assert!(timers.has_ever_finished(foo));
+
+        world.commands().entity(e1).reset_timer::<Foo>();
+        world.flush();
+        assert!(world.get::<Timers>(e1).unwrap().has_ever_finished(foo));
+
+        world
+            .commands()
+            .entity(e1)
+            .start_timer::<Foo>(Timer::from_seconds(1., bevy_time::TimerMode::Repeating));
+        world.flush();
+        assert!(!world.get::<Timers>(e1).unwrap().has_ever_finished(foo));
+    }
+
+    #[cfg(feature = "bevy_app")]
+    #[test]
+    fn deterministic_dispatch() {
+        use bevy_app::{App, Update};
+
+        use crate::plugin::ScheduleTimerTickPlugin;
+
+        #[derive(Resource, Default)]
+        struct Order(Vec<(Entity, &'static str)>);
+
+        let run = || {
+            let mut app = App::new();
+            app.add_plugins(ScheduleTimerTickPlugin::new(Update).with_deterministic_dispatch(true))
+                .init_resource::<Time>()
+                .init_resource::<Order>()
+                .add_observer(
+                    |t: Trigger<OnTimerFinished, Foo>, mut order: ResMut<Order>| {
+                        order.0.push((t.entity(), "foo"));
+                    },
+                )
+                .add_observer(
+                    |t: Trigger<OnTimerFinished, Bar>, mut order: ResMut<Order>| {
+                        order.0.push((t.entity(), "bar"));
+                    },
+                );
+
+            for _ in 0..50 {
+                app.world_mut()
+                    .commands()
+                    .spawn_empty()
+                    .start_timer::<Bar>(Timer::from_seconds(1., bevy_time::TimerMode::Once))
+                    .start_timer::<Foo>(Timer::from_seconds(1., bevy_time::TimerMode::Once));
+            }
+            app.world_mut().flush();
+
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs(1));
+            app.update();
+            app.world_mut().remove_resource::<Order>().unwrap().0
+        };
+
+        let first = run();
+        assert_eq!(first.len(), 100);
+        assert!(first.is_sorted_by_key(|&(entity, _)| entity));
+        assert_eq!(first, run());
+    }
+
+    #[test]
+    fn ticks_remaining() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        let foo = world.register_component::<Foo>();
+
+        let e1 = world.spawn_empty().id();
+        world
+            .commands()
+            .entity(e1)
+            .start_timer_millis::<Foo>(250, bevy_time::TimerMode::Once);
+        world.flush();
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_millis(50));
+        world.run_system_cached(tick_entity_timers).unwrap();
+
+        let step = Duration::from_millis(64);
+        let mut timers = world.get_mut::<Timers>(e1).unwrap();
+        // 200ms left: three whole steps and a partial one.
+        assert_eq!(timers.ticks_remaining(foo, step), Some(4));
+        assert_eq!(
+            timers.ticks_remaining(foo, Duration::from_millis(50)),
+            Some(4)
+        );
+        assert_eq!(timers.ticks_remaining(foo, Duration::ZERO), None);
+        timers.get_mut(foo).unwrap().pause();
+        assert_eq!(timers.ticks_remaining(foo, step), None);
+    }
+
+    #[test]
+    fn swap_linked_timers() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        let bar = world.register_component::<Bar>();
+
+        let e1 = world.spawn(Foo).id();
+        world
+            .commands()
+            .entity(e1)
+            .start_timer_linked::<Foo>(Timer::from_seconds(1., bevy_time::TimerMode::Once))
+            .swap_timers::<Foo, Bar>();
+        world.flush();
+        world.entity_mut(e1).insert(Bar);
+        assert!(world.get::<Timers>(e1).unwrap().contains(bar));
+
+        // The timer is now linked to `Bar`.
+        world.entity_mut(e1).remove::<Bar>();
+        world.flush();
+        assert!(!world.get::<Timers>(e1).unwrap().contains(bar));
+    }
+
+    #[test]
+    fn merge() {
+        use crate::core::ConflictPolicy;
+
+        let mut world = World::new();
+        let foo = world.register_component::<Foo>();
+        let bar = world.register_component::<Bar>();
+        let baz = world.register_component::<Baz>();
+
+        let maps = || {
+            let mut a = Timers::default();
+            a.insert(foo, Timer::from_seconds(1., bevy_time::TimerMode::Once));
+            a.insert(bar, Timer::from_seconds(5., bevy_time::TimerMode::Once));
+            let mut b = Timers::default();
+            b.insert(baz, Timer::from_seconds(3., bevy_time::TimerMode::Once));
+            b.insert(foo, Timer::from_seconds(2., bevy_time::TimerMode::Once));
+            (a, b)
+        };
+        let merged = |policy| {
+            let (mut a, b) = maps();
+            a.merge(b, policy);
+            let order: Vec<_> = a.iter().map(|(&component, _)| component).collect();
+            assert_eq!(order, vec![foo, bar, baz]);
+            a.get(foo).unwrap().duration().as_secs()
+        };
+
+        assert_eq!(merged(ConflictPolicy::KeepSelf), 1);
+        assert_eq!(merged(ConflictPolicy::KeepOther), 2);
+        assert_eq!(merged(ConflictPolicy::KeepLongerRemaining), 2);
+
+        let (a, mut b) = maps();
+        b.merge(a, ConflictPolicy::KeepLongerRemaining);
+        assert_eq!(b.get(foo).unwrap().duration().as_secs(), 2);
+    }
+
+    #[cfg(feature = "bevy_app")]
+    #[test]
+    fn timer_clocks() {
+        use bevy_app::{App, FixedPreUpdate, PreUpdate};
+        use bevy_time::{Fixed, Real, Virtual};
+
+        use crate::{core::TimerClock, plugin::ScheduleTimerTickPlugin};
+
+        let mut app = App::new();
+        app.add_plugins(ScheduleTimerTickPlugin::pre_update().with_timer_clocks(true))
+            .init_resource::<Time<Virtual>>()
+            .init_resource::<Time<Fixed>>()
+            .init_resource::<Time<Real>>();
+        let foo = app.world_mut().register_component::<Foo>();
+        let bar = app.world_mut().register_component::<Bar>();
+
+        let e1 = app.world_mut().spawn_empty().id();
+        app.world_mut()
+            .commands()
+            .entity(e1)
+            .start_timer::<Foo>(Timer::from_seconds(10., bevy_time::TimerMode::Once))
+            .start_timer_on_clock::<Bar>(
+                Timer::from_seconds(10., bevy_time::TimerMode::Once),
+                TimerClock::Fixed,
+            );
+        app.world_mut().flush();
+
+        let elapsed = |app: &App| {
+            let timers = app.world().get::<Timers>(e1).unwrap();
+            (
+                timers.get(foo).unwrap().elapsed(),
+                timers.get(bar).unwrap().elapsed(),
+            )
+        };
+
+        app.world_mut()
+            .resource_mut::<Time<Virtual>>()
+            .advance_by(Duration::from_secs(1));
+        app.world_mut().run_schedule(PreUpdate);
+        assert_eq!(elapsed(&app), (Duration::from_secs(1), Duration::ZERO));
+
+        for _ in 0..3 {
+            app.world_mut()
+                .resource_mut::<Time<Fixed>>()
+                .advance_by(Duration::from_millis(500));
+            app.world_mut().run_schedule(FixedPreUpdate);
+        }
+        assert_eq!(
+            elapsed(&app),
+            (Duration::from_secs(1), Duration::from_millis(1500))
+        );
+        assert_eq!(
+            app.world().get::<Timers>(e1).unwrap().clock(bar),
+            Some(TimerClock::Fixed)
+        );
+    }
+
+    #[cfg(feature = "bevy_app")]
+    #[test]
+    #[should_panic(expected = "timer clocks and deterministic dispatch cannot be combined")]
+    fn timer_clocks_deterministic_dispatch() {
+        use bevy_app::App;
+
+        use crate::plugin::ScheduleTimerTickPlugin;
+
+        App::new().add_plugins(
+            ScheduleTimerTickPlugin::pre_update()
+                .with_timer_clocks(true)
+                .with_deterministic_dispatch(true),
+        );
+    }
+
+    #[test]
+    fn remaining_secs_f64() {
+        let mut world = World::new();
+        let foo = world.register_component::<Foo>();
+
+        let mut timers = Timers::default();
+        timers.insert(
+            foo,
+            Timer::new(Duration::from_secs(3 * 3600), bevy_time::TimerMode::Once),
+        );
+        timers.get_mut(foo).unwrap().tick(Duration::from_micros(1));
+
+        let remaining = timers.remaining(foo).unwrap();
+        assert_eq!(remaining, Duration::from_micros(3 * 3600 * 1_000_000 - 1));
+        assert_eq!(timers.remaining_secs_f64(foo), Some(10_799.999_999));
+        // An f32 cannot tell the microsecond apart.
+        assert_eq!(remaining.as_secs_f32(), 10_800.);
+        assert_eq!(
+            timers.remaining_secs_f64(world.register_component::<Bar>()),
+            None
+        );
+    }
+
+    #[test]
+    fn timer_config_scale() {
+        use crate::core::TimerConfig;
+
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        let foo = world.register_component::<Foo>();
+
+        let slow = world.spawn(TimerConfig { scale: 0.5 }).id();
+        let normal = world.spawn_empty().id();
+        for entity in [slow, normal] {
+            world
+                .commands()
+                .entity(entity)
+                .start_timer::<Foo>(Timer::from_seconds(10., bevy_time::TimerMode::Once));
+        }
+        world.flush();
+        // Required by Timers, so inserted with the default scale.
+        assert_eq!(
+            world.get::<TimerConfig>(normal),
+            Some(&TimerConfig::default())
+        );
+
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(2));
+        world.run_system_cached(tick_entity_timers).unwrap();
+        let elapsed = |world: &World, entity| {
+            world
+                .get::<Timers>(entity)
+                .unwrap()
+                .get(foo)
+                .unwrap()
+                .elapsed()
+        };
+        assert_eq!(elapsed(&world, slow), Duration::from_secs(1));
+        assert_eq!(elapsed(&world, normal), Duration::from_secs(2));
+
+        world.get_mut::<TimerConfig>(slow).unwrap().scale = 0.;
+        world.run_system_cached(tick_entity_timers).unwrap();
+        assert_eq!(elapsed(&world, slow), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn refresh_timer() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        let foo = world.register_component::<Foo>();
+
+        let e1 = world.spawn_empty().id();
+        world
+            .commands()
+            .entity(e1)
+            .start_timer::<Foo>(Timer::from_seconds(2., bevy_time::TimerMode::Repeating));
+        world.flush();
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(1));
+        world.run_system_cached(tick_entity_timers).unwrap();
+        world.commands().entity(e1).pause_timer::<Foo>();
+        world.flush();
+
+        world.commands().entity(e1).refresh_timer::<Foo>();
+        world.flush();
+        let timer = world.get::<Timers>(e1).unwrap().get(foo).unwrap();
+        assert_eq!(timer.elapsed(), Duration::ZERO);
+        assert!(!timer.paused());
+        assert_eq!(timer.mode(), bevy_time::TimerMode::Repeating);
+        assert_eq!(timer.duration(), Duration::from_secs(2));
+
+        // Removed one-shot timers are not re-armed.
+        world.commands().entity(e1).refresh_timer::<Bar>();
+        world.flush();
+        assert_eq!(world.get::<Timers>(e1).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn timer_bundle() {
+        use crate::command::StartTimer;
+
+        #[derive(Resource, Default)]
+        struct Fired(Vec<bevy_ecs::component::ComponentId>);
+
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<Fired>();
+        world.add_observer(|t: Trigger<OnTimerFinished>, mut fired: ResMut<Fired>| {
+            fired.0.extend(t.components());
+        });
+        let foo = world.register_component::<Foo>();
+        let bar = world.register_component::<Bar>();
+        let baz = world.register_component::<Baz>();
+
+        let e1 = world.spawn_empty().id();
+        world.commands().entity(e1).start_timer_bundle((
+            StartTimer::<Foo>::new(Timer::from_seconds(1., bevy_time::TimerMode::Once)),
+            StartTimer::<Bar>::new(Timer::from_seconds(1., bevy_time::TimerMode::Repeating)),
+            StartTimer::<Baz>::new(Timer::from_seconds(1., bevy_time::TimerMode::Once))
+                .persistent(),
+        ));
+        world.flush();
+        assert_eq!(world.get::<Timers>(e1).unwrap().len(), 3);
+
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(1));
+        world.run_system_cached(tick_entity_timers).unwrap();
+        world.flush();
+        let mut fired = world.resource::<Fired>().0.clone();
+        fired.sort();
+        let mut expected = vec![foo, bar, baz];
+        expected.sort();
+        assert_eq!(fired, expected);
+    }
+
+    #[test]
+    fn all_timers_cleared() {
+        use crate::event::OnAllTimersCleared;
+
+        #[derive(Resource, Default)]
+        struct Cleared(Vec<Entity>);
+
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<Cleared>();
+        OnAllTimersCleared::register(&mut world);
+        world.add_observer(
+            |t: Trigger<OnAllTimersCleared>, mut cleared: ResMut<Cleared>| {
+                cleared.0.push(t.entity());
+            },
+        );
+
+        let e1 = world.spawn_empty().id();
+        world
+            .commands()
+            .entity(e1)
+            .start_timer::<Foo>(Timer::from_seconds(1., bevy_time::TimerMode::Once))
+            .start_timer::<Bar>(Timer::from_seconds(2., bevy_time::TimerMode::Once));
+        world.flush();
+
+        let step = |world: &mut World| {
+            world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs(1));
+            world.run_system_cached(tick_entity_timers).unwrap();
+            world.flush();
+        };
+        step(&mut world);
+        assert!(world.resource::<Cleared>().0.is_empty());
+        step(&mut world);
+        assert_eq!(world.resource::<Cleared>().0, vec![e1]);
+
+        // Timers ending together, or cancelled, clear the entity once.
+        world
+            .commands()
+            .entity(e1)
+            .start_timer::<Foo>(Timer::from_seconds(1., bevy_time::TimerMode::Once))
+            .start_timer::<Bar>(Timer::from_seconds(1., bevy_time::TimerMode::Once));
+        world.flush();
+        step(&mut world);
+        assert_eq!(world.resource::<Cleared>().0, vec![e1, e1]);
+
+        world
+            .commands()
+            .entity(e1)
+            .start_timer::<Foo>(Timer::from_seconds(1., bevy_time::TimerMode::Once))
+            .cancel_timer::<Foo>();
+        world.flush();
+        assert_eq!(world.resource::<Cleared>().0.len(), 3);
+
+        // Despawning is not clearing.
+        world
+            .commands()
+            .entity(e1)
+            .start_timer::<Foo>(Timer::from_seconds(1., bevy_time::TimerMode::Once));
+        world.flush();
+        world.despawn(e1);
+        world.flush();
+        assert_eq!(world.resource::<Cleared>().0.len(), 3);
+    }
+
+    #[test]
+    fn local_time_scale() {
+        use crate::core::LocalTimeScale;
+
+        #[derive(Resource, Default)]
+        struct FinishedOn(Vec<Entity>);
+
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<FinishedOn>();
+        world.add_observer(
+            |t: Trigger<OnTimerFinished, Foo>, mut on: ResMut<FinishedOn>| {
+                on.0.push(t.entity());
+            },
+        );
+
+        let slow = world.spawn(LocalTimeScale(0.5)).id();
+        let normal = world.spawn_empty().id();
+        let frozen = world.spawn(LocalTimeScale(-1.)).id();
+        for entity in [slow, normal, frozen] {
+            world
+                .commands()
+                .entity(entity)
+                .start_timer::<Foo>(Timer::from_seconds(1., bevy_time::TimerMode::Once));
+        }
+        world.flush();
+
+        let step = |world: &mut World| {
+            world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs(1));
+            world.run_system_cached(tick_entity_timers).unwrap();
+            world.flush();
+        };
+        step(&mut world);
+        assert_eq!(world.resource::<FinishedOn>().0, vec![normal]);
+        step(&mut world);
+        assert_eq!(world.resource::<FinishedOn>().0, vec![normal, slow]);
+        let foo = world.register_component::<Foo>();
+        let timers = world.get::<Timers>(frozen).unwrap();
+        assert_eq!(timers.get(foo).unwrap().elapsed(), Duration::ZERO);
+    }
+
+    #[test]
+    fn is_on_cooldown() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        let e1 = world.spawn_empty().id();
+        world
+            .commands()
+            .entity(e1)
+            .start_timer::<Foo>(Timer::from_seconds(2., bevy_time::TimerMode::Once));
+        world.flush();
+
+        let on_cooldown = |world: &mut World| {
+            world
+                .run_system_cached_with(
+                    |In(e): In<Entity>, timers: TimerQuery| timers.is_on_cooldown::<Foo>(e),
+                    e1,
+                )
+                .unwrap()
+        };
+        let step = |world: &mut World| {
+            world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs(1));
+            world.run_system_cached(tick_entity_timers).unwrap();
+            world.flush();
+        };
+
+        step(&mut world);
+        assert!(on_cooldown(&mut world));
+        world.commands().entity(e1).pause_timer::<Foo>();
+        world.flush();
+        step(&mut world);
+        assert!(on_cooldown(&mut world));
+        world.commands().entity(e1).unpause_timer::<Foo>();
+        world.flush();
+        step(&mut world);
+        assert!(!on_cooldown(&mut world));
+        assert!(world.get::<Timers>(e1).unwrap().is_empty());
+    }
+
+    #[cfg(feature = "bevy_app")]
+    #[test]
+    fn tick_set_ordering() {
+        use bevy_app::{App, Update};
+        use bevy_ecs::{
+            schedule::IntoSystemConfigs,
+            system::{Commands, Local},
+        };
+
+        use crate::plugin::{ScheduleTimerTickPlugin, TimerTickSet};
+
+        #[derive(Resource)]
+        struct Target(Entity);
+
+        fn start<T: Component>(target: Res<Target>, mut commands: Commands, mut done: Local<bool>) {
+            if !core::mem::replace(&mut *done, true) {
+                commands
+                    .entity(target.0)
+                    .start_timer::<T>(Timer::from_seconds(5., bevy_time::TimerMode::Once));
+            }
+        }
+
+        let mut app = App::new();
+        let e1 = app.world_mut().spawn_empty().id();
+        app.add_plugins(ScheduleTimerTickPlugin::update())
+            .init_resource::<Time>()
+            .insert_resource(Target(e1))
+            .add_systems(Update, start::<Foo>.before(TimerTickSet))
+            .add_systems(Update, start::<Bar>.after(TimerTickSet));
+        let foo = app.world_mut().register_component::<Foo>();
+        let bar = app.world_mut().register_component::<Bar>();
+
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(1));
+        app.update();
+        let timers = app.world().get::<Timers>(e1).unwrap();
+        // Ticked in the frame it was started in.
+        assert_eq!(timers.get(foo).unwrap().elapsed(), Duration::from_secs(1));
+        // Started after the tick, so first ticked on the next frame.
+        assert_eq!(timers.get(bar).unwrap().elapsed(), Duration::ZERO);
+    }
+
+    #[test]
+    fn tick_by_turns() {
+        use crate::core::tick_entity_timers_by;
+
+        const TURN: Duration = Duration::from_secs(1);
+
+        let run = || {
+            let mut world = World::new();
+            world.init_resource::<FinishedAt>();
+            world.add_observer(|t: Trigger<OnTimerFinished>, mut at: ResMut<FinishedAt>| {
+                at.0.push(t.event().duration);
+            });
+            let e1 = world.spawn_empty().id();
+            world
+                .commands()
+                .entity(e1)
+                .start_timer::<Foo>(Timer::new(TURN * 3, bevy_time::TimerMode::Once))
+                .start_timer::<Bar>(Timer::new(TURN * 2, bevy_time::TimerMode::Repeating));
+            world.flush();
+
+            let mut log = Vec::new();
+            for _ in 0..6 {
+                tick_entity_timers_by(&mut world, TURN);
+                log.push(core::mem::take(&mut world.resource_mut::<FinishedAt>().0));
+            }
+            log
+        };
+
+        let log = run();
+        let turns = |duration| {
+            log.iter()
+                .enumerate()
+                .filter(|(_, finished)| finished.contains(&duration))
+                .map(|(turn, _)| turn + 1)
+                .collect::<Vec<_>>()
+        };
+        // No Time resource is needed, and finishes land on exact turns.
+        assert_eq!(turns(TURN * 3), vec![3]);
+        assert_eq!(turns(TURN * 2), vec![2, 4, 6]);
+        assert_eq!(log, run());
+    }
+
+    #[test]
+    fn tag_data_in_observer() {
+        #[derive(Component)]
+        struct Poison {
+            damage: u32,
+        }
+
+        #[derive(Resource, Default)]
+        struct Damage(Vec<Option<u32>>);
+
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<Damage>();
+        world.add_observer(
+            |t: Trigger<OnTimerFinished, Poison>,
+             poison: bevy_ecs::system::Query<&Poison>,
+             mut damage: ResMut<Damage>| {
+                damage
+                    .0
+                    .push(poison.get(t.entity()).ok().map(|poison| poison.damage));
+            },
+        );
+
+        let e1 = world.spawn(Poison { damage: 5 }).id();
+        let e2 = world.spawn_empty().id();
+        world
+            .commands()
+            .entity(e1)
+            .start_timer_removing_tag::<Poison>(Timer::from_seconds(
+                1.,
+                bevy_time::TimerMode::Once,
+            ));
+        world
+            .commands()
+            .entity(e2)
+            .start_timer::<Poison>(Timer::from_seconds(2., bevy_time::TimerMode::Once));
+        world.flush();
+
+        for _ in 0..2 {
+            world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs(1));
+            world.run_system_cached(tick_entity_timers).unwrap();
+            world.flush();
+        }
+        // The tag is still there while observing, and removed afterwards. An
+        // entity timing the tag without having it has no data to read.
+        assert_eq!(world.resource::<Damage>().0, vec![Some(5), None]);
+        assert!(world.get::<Poison>(e1).is_none());
+    }
+
+    #[cfg(all(feature = "debug-invariants", debug_assertions))]
+    #[test]
+    #[should_panic(expected = "finished one-shot timer that was not removed")]
+    fn debug_invariants() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        let id = world.register_component::<Foo>();
+
+        // Ticking never leaves a finished one-shot timer in place.
+        let mut timer = Timer::from_seconds(1., bevy_time::TimerMode::Once);
+        timer.tick(Duration::from_secs(1));
+        let mut timers = Timers::new();
+        timers.insert(id, timer);
+        world.spawn(timers);
+
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(1));
+        world.run_system_cached(tick_entity_timers).unwrap();
+    }
+
+    #[test]
+    fn second_ticks() {
+        #[derive(Resource, Default)]
+        struct Events(Vec<Option<u32>>);
+
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<Events>();
+        world.add_observer(
+            |t: Trigger<OnTimerSecondTick, Foo>, mut events: ResMut<Events>| {
+                events.0.push(Some(t.event().remaining_secs));
+            },
+        );
+        world.add_observer(
+            |_: Trigger<OnTimerFinished, Foo>, mut events: ResMut<Events>| {
+                events.0.push(None);
+            },
+        );
+
+        let e1 = world.spawn_empty().id();
+        let e2 = world.spawn_empty().id();
+        world
+            .commands()
+            .entity(e1)
+            .start_timer_with_second_ticks::<Foo>(Timer::from_seconds(
+                4.,
+                bevy_time::TimerMode::Once,
+            ));
+        world.flush();
+
+        for _ in 0..4 {
+            world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs(1));
+            world.run_system_cached(tick_entity_timers).unwrap();
+        }
+        assert_eq!(
+            world.resource::<Events>().0,
+            [Some(3), Some(2), Some(1), None]
+        );
+
+        // A large delta reports each skipped second in order.
+        world.resource_mut::<Events>().0.clear();
+        world
+            .commands()
+            .entity(e2)
+            .start_timer_with_second_ticks::<Foo>(Timer::from_seconds(
+                4.,
+                bevy_time::TimerMode::Once,
+            ));
+        world.flush();
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_millis(2500));
+        world.run_system_cached(tick_entity_timers).unwrap();
+        assert_eq!(world.resource::<Events>().0, [Some(3), Some(2)]);
+    }
+
+    #[test]
+    fn missing_entity_policy() {
+        let mut world = World::new();
+        for policy in [MissingEntityPolicy::Silent, MissingEntityPolicy::Warn] {
+            world.insert_resource(TimerSettings {
+                missing_entity_policy: policy,
+                ..Default::default()
+            });
+            let e1 = world.spawn_empty().id();
+            world.commands().entity(e1).despawn();
+            world
+                .commands()
+                .entity(e1)
+                .start_timer::<Foo>(Timer::from_seconds(1., bevy_time::TimerMode::Once));
+            world.flush();
+            assert_eq!(world.query::<&Timers>().iter(&world).count(), 0);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "the entity does not exist")]
+    fn missing_entity_policy_panic() {
+        let mut world = World::new();
+        world.insert_resource(TimerSettings {
+            missing_entity_policy: MissingEntityPolicy::Panic,
+            ..Default::default()
+        });
+        let e1 = world.spawn_empty().id();
+        world.commands().entity(e1).despawn();
+        world
+            .commands()
+            .entity(e1)
+            .start_timer::<Foo>(Timer::from_seconds(1., bevy_time::TimerMode::Once));
+        world.flush();
+    }
+
+    #[test]
+    fn iter_owned() {
+        let mut world = World::new();
+        let foo = world.register_component::<Foo>();
+        let bar = world.register_component::<Bar>();
+
+        let mut timers = Timers::new();
+        timers.insert(foo, Timer::from_seconds(1., bevy_time::TimerMode::Once));
+        timers.insert(
+            bar,
+            Timer::from_seconds(2., bevy_time::TimerMode::Repeating),
+        );
+
+        let owned = timers.iter_owned();
+        let live: Vec<_> = timers.iter().map(|(&c, t)| (c, t.clone())).collect();
+        // Not borrowing the timers, which can be changed meanwhile.
+        timers.clear();
+        let owned: Vec<_> = owned.collect();
+        assert_eq!(owned, live);
+        assert_eq!(owned[0].0, foo);
+        assert_eq!(owned[1].0, bar);
+    }
+
+    #[test]
+    fn cancel_category() {
+        const DEBUFF: TimerCategory = TimerCategory("debuff");
+
+        #[derive(Resource, Default)]
+        struct CancelledTags(Vec<bevy_ecs::component::ComponentId>);
+
+        let mut world = World::new();
+        world.init_resource::<CancelledTags>();
+        world.add_observer(
+            |t: Trigger<OnTimerCancelled>, mut cancelled: ResMut<CancelledTags>| {
+                cancelled.0.push(t.components()[0]);
+            },
+        );
+        let foo = TimerCategories::register::<Foo>(&mut world, DEBUFF);
+        let bar = TimerCategories::register::<Bar>(&mut world, DEBUFF);
+        let baz = world.register_component::<Baz>();
+
+        let e1 = world.spawn_empty().id();
+        world
+            .commands()
+            .entity(e1)
+            .start_timer::<Foo>(Timer::from_seconds(1., bevy_time::TimerMode::Once))
+            .start_timer::<Baz>(Timer::from_seconds(1., bevy_time::TimerMode::Once))
+            .start_timer::<Bar>(Timer::from_seconds(1., bevy_time::TimerMode::Once))
+            .cancel_category(DEBUFF);
+        world.flush();
+
+        assert_eq!(world.resource::<CancelledTags>().0, [foo, bar]);
+        let timers = world.get::<Timers>(e1).unwrap();
+        assert!(timers.contains(baz));
+        assert_eq!(timers.len(), 1);
+    }
+
+    #[test]
+    fn set_fraction_timer() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        let foo = world.register_component::<Foo>();
+        let bar = world.register_component::<Bar>();
+
+        let e1 = world.spawn_empty().id();
+        world
+            .commands()
+            .entity(e1)
+            .start_timer::<Foo>(Timer::from_seconds(4., bevy_time::TimerMode::Once))
+            .start_timer::<Bar>(Timer::from_seconds(4., bevy_time::TimerMode::Once))
+            .set_fraction_timer::<Foo>(0.5)
+            .set_fraction_timer::<Bar>(2.);
+        world.flush();
+
+        let timers = world.get::<Timers>(e1).unwrap();
+        assert_eq!(timers.remaining(foo), Some(Duration::from_secs(2)));
+        assert_eq!(timers.remaining(bar), Some(Duration::ZERO));
+
+        // Clamped to the whole duration, so the timer finishes on the next tick.
+        world.run_system_cached(tick_entity_timers).unwrap();
+        let timers = world.get::<Timers>(e1).unwrap();
+        assert!(timers.contains(foo));
+        assert!(!timers.contains(bar));
+    }
+
+    #[test]
+    fn iter_paused() {
+        let mut world = World::new();
+        let foo = world.register_component::<Foo>();
+        let bar = world.register_component::<Bar>();
+        let baz = world.register_component::<Baz>();
+
+        let e1 = world.spawn_empty().id();
+        world
+            .commands()
+            .entity(e1)
+            .start_timer::<Foo>(Timer::from_seconds(1., bevy_time::TimerMode::Once))
+            .start_timer::<Bar>(Timer::from_seconds(1., bevy_time::TimerMode::Once))
+            .start_timer::<Baz>(Timer::from_seconds(1., bevy_time::TimerMode::Once))
+            .pause_timer::<Foo>()
+            .pause_timer::<Baz>();
+        world.flush();
+
+        let timers = world.get::<Timers>(e1).unwrap();
+        let paused: Vec<_> = timers.iter_paused().map(|(&c, _)| c).collect();
+        let running: Vec<_> = timers.iter_running().map(|(&c, _)| c).collect();
+        assert_eq!(paused, [foo, baz]);
+        assert_eq!(running, [bar]);
+    }
+
+    #[test]
+    fn missing_time() {
+        let mut world = World::new();
+        let foo = world.register_component::<Foo>();
+
+        let e1 = world.spawn_empty().id();
+        world
+            .commands()
+            .entity(e1)
+            .start_timer::<Foo>(Timer::from_seconds(0., bevy_time::TimerMode::Once));
+        world.flush();
+
+        // Skips ticking rather than panicking.
+        world.run_system_cached(tick_entity_timers).unwrap();
+        world.run_system_cached(tick_entity_timers_ordered).unwrap();
+        assert!(world.run_system_cached(time_is_advancing).unwrap());
+        assert!(world.get::<Timers>(e1).unwrap().contains(foo));
+    }
+
+    #[test]
+    fn has_timers_marker_removing_tag() {
+        use crate::{event::OnAllTimersCleared, marker::HasTimers};
+
+        #[derive(Resource, Default)]
+        struct Cleared(Vec<Entity>);
+
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<Cleared>();
+        OnAllTimersCleared::register(&mut world);
+        world.add_observer(
+            |t: Trigger<OnAllTimersCleared>, mut cleared: ResMut<Cleared>| {
+                cleared.0.push(t.entity());
+            },
+        );
+
+        let e1 = world.spawn(Foo).id();
+        world
+            .commands()
+            .entity(e1)
+            .start_timer_removing_tag::<Foo>(Timer::from_seconds(1., bevy_time::TimerMode::Once));
+        world.flush();
+        assert!(world.entity(e1).contains::<HasTimers>());
+
+        // The last timer removing its tag clears the entity like any other.
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(1));
+        world.run_system_cached(tick_entity_timers).unwrap();
+        world.flush();
+        assert!(world.get::<Timers>(e1).unwrap().is_empty());
+        assert!(!world.entity(e1).contains::<HasTimers>());
+        assert!(!world.entity(e1).contains::<Foo>());
+        assert_eq!(world.resource::<Cleared>().0, vec![e1]);
+    }
+
+    #[test]
+    fn next_to_finish_delayed() {
+        let mut world = World::new();
+        let foo = world.register_component::<Foo>();
+        let bar = world.register_component::<Bar>();
+
+        let e1 = world.spawn_empty().id();
+        world
+            .commands()
+            .entity(e1)
+            .start_timer_delayed::<Foo>(
+                Duration::from_secs(5),
+                Timer::from_seconds(0.1, bevy_time::TimerMode::Once),
+            )
+            .start_timer::<Bar>(Timer::from_seconds(1., bevy_time::TimerMode::Once));
+        world.flush();
+
+        // The delay counts, so the short delayed timer is not next.
+        let timers = world.get::<Timers>(e1).unwrap();
+        assert_eq!(timers.next_to_finish(), Some((bar, Duration::from_secs(1))));
+
+        world.commands().entity(e1).cancel_timer::<Bar>();
+        world.flush();
+        let timers = world.get::<Timers>(e1).unwrap();
+        assert_eq!(
+            timers.next_to_finish(),
+            Some((foo, Duration::from_secs(5) + Duration::from_secs_f32(0.1)))
+        );
+    }
+
+    #[test]
+    fn min_max_remaining_delayed() {
+        let mut world = World::new();
+
+        let e1 = world.spawn_empty().id();
+        world
+            .commands()
+            .entity(e1)
+            .start_timer_delayed::<Foo>(
+                Duration::from_secs(5),
+                Timer::from_seconds(1., bevy_time::TimerMode::Once),
+            )
+            .start_timer::<Bar>(Timer::from_seconds(2., bevy_time::TimerMode::Once));
+        world.flush();
+
+        let timers = world.get::<Timers>(e1).unwrap();
+        assert_eq!(timers.max_remaining(), Some(Duration::from_secs(6)));
+        assert_eq!(timers.min_remaining(), Some(Duration::from_secs(2)));
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn wait_for_finish() {
+        use bevy_tasks::{block_on, poll_once};
+
+        use crate::wait::TimerWaitResult;
+
+        let mut world = World::new();
+        world.init_resource::<Time>();
+
+        let e1 = world.spawn_empty().id();
+
+        let mut commands = world.commands();
+        commands
+            .entity(e1)
+            .start_timer::<Foo>(Timer::from_seconds(1., bevy_time::TimerMode::Once));
+        let mut wait = Timers::wait_for::<Foo>(&mut commands, e1);
+        world.flush();
+        assert_eq!(block_on(poll_once(&mut wait)), None);
+
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(1));
+        world.run_system_cached(tick_entity_timers).unwrap();
+        assert_eq!(block_on(wait), TimerWaitResult::Finished);
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn wait_for_cancelled() {
+        use bevy_tasks::{block_on, poll_once};
+
+        use crate::wait::TimerWaitResult;
+
+        let mut world = World::new();
+        world.init_resource::<Time>();
+
+        let e1 = world.spawn_empty().id();
+        let e2 = world.spawn_empty().id();
+        let e3 = world.spawn_empty().id();
+
+        let mut commands = world.commands();
+        commands
+            .entity(e1)
+            .start_timer::<Foo>(Timer::from_seconds(1., bevy_time::TimerMode::Once));
+        commands
+            .entity(e2)
+            .start_timer::<Foo>(Timer::from_seconds(1., bevy_time::TimerMode::Once));
+        let mut cancelled = Timers::wait_for::<Foo>(&mut commands, e1);
+        let mut despawned = Timers::wait_for::<Foo>(&mut commands, e2);
+        // No timer is started on `e3`, so only dropping the observers with
+        // the entity resolves the future.
+        let mut never_started = Timers::wait_for::<Foo>(&mut commands, e3);
+        world.flush();
+        assert_eq!(block_on(poll_once(&mut cancelled)), None);
+        assert_eq!(block_on(poll_once(&mut despawned)), None);
+        assert_eq!(block_on(poll_once(&mut never_started)), None);
+
+        world.commands().entity(e1).cancel_timer::<Foo>();
+        world.flush();
+        assert_eq!(block_on(cancelled), TimerWaitResult::Cancelled);
+
+        world.despawn(e2);
+        world.despawn(e3);
+        assert_eq!(block_on(despawned), TimerWaitResult::Cancelled);
+        assert_eq!(block_on(never_started), TimerWaitResult::Cancelled);
+
+        // Waiting on a missing entity resolves as cancelled.
+        let mut commands = world.commands();
+        let missing = Timers::wait_for::<Foo>(&mut commands, e3);
+        world.flush();
+        assert_eq!(block_on(missing), TimerWaitResult::Cancelled);
+    }
 }
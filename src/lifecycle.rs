@@ -0,0 +1,191 @@
+//! Provides [`OnTimerEvent`], a single observer event mirroring every timer
+//! lifecycle event.
+
+use core::time::Duration;
+
+use bevy_ecs::{
+    component::ComponentId,
+    entity::Entity,
+    event::Event,
+    observer::Trigger,
+    system::{Commands, Resource},
+    world::World,
+};
+
+use crate::{
+    event::{
+        CancelReason, OnTimerCancelled, OnTimerFinished, OnTimerSecondTick, OnTimerStarted,
+        OnTimerThreshold,
+    },
+    targets::TargetBoth,
+};
+
+/// [`Event`] mirroring the lifecycle events of this crate, so that a single
+/// observer can react to every transition of a timer. The [`Trigger`] will
+/// contain the [`Component`] that identifies the timer.
+///
+/// The events are opt-in, since mirroring them costs an observer run per
+/// timer event. Enable them with [`OnTimerEvent::register`]. The specific
+/// events are still triggered, and each [`OnTimerEvent`] is triggered once the
+/// observers of the specific event it mirrors have run. Triggering only
+/// [`OnTimerEvent`], instead of the specific events, is not supported: other
+/// features of this crate, such as the [`TimerIndex`] and finish callbacks,
+/// are kept up to date by observing the specific events.
+///
+/// Pausing and unpausing a timer triggers no specific event, so their kinds
+/// are triggered directly, when the timer's paused state changes, by
+/// [`pause_timer`], [`unpause_timer`], [`refresh_timer`],
+/// [`TimerQueryMut::pause`] and [`TimerQueryMut::unpause`]. Pausing every
+/// timer at once with [`TimersPaused`] is not reported.
+/// [`OnTimersFinishedBatch`] is not mirrored either, since it lacks the data
+/// of [`TimerEventKind::Finished`]: under [`FinishedEvents::Batched`], no
+/// finish is reported through [`OnTimerEvent`], so observe the batch event
+/// directly.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_observed_timers::{lifecycle::{OnTimerEvent, TimerEventKind}, prelude::*};
+/// # #[derive(Component)]
+/// # struct Casting;
+/// # let mut world = World::new();
+/// OnTimerEvent::register(&mut world);
+/// world.add_observer(|t: Trigger<OnTimerEvent, Casting>| match t.event().kind {
+///     TimerEventKind::Started { .. } => println!("casting"),
+///     TimerEventKind::Finished { .. } => println!("cast"),
+///     TimerEventKind::Cancelled { .. } => println!("interrupted"),
+///     TimerEventKind::Threshold { .. } | TimerEventKind::SecondTick { .. } => {}
+///     TimerEventKind::Paused | TimerEventKind::Unpaused => {}
+/// });
+/// ```
+///
+/// [`Trigger`]: bevy_ecs::observer::Trigger
+/// [`Component`]: bevy_ecs::component::Component
+/// [`pause_timer`]: crate::command::EntityCommandTimersExt::pause_timer
+/// [`unpause_timer`]: crate::command::EntityCommandTimersExt::unpause_timer
+/// [`refresh_timer`]: crate::command::EntityCommandTimersExt::refresh_timer
+/// [`TimerQueryMut::pause`]: crate::query::TimerQueryMut::pause
+/// [`TimerQueryMut::unpause`]: crate::query::TimerQueryMut::unpause
+/// [`TimersPaused`]: crate::core::TimersPaused
+/// [`OnTimersFinishedBatch`]: crate::event::OnTimersFinishedBatch
+/// [`FinishedEvents::Batched`]: crate::settings::FinishedEvents::Batched
+/// [`TimerIndex`]: crate::index::TimerIndex
+#[cfg_attr(
+    feature = "bevy_app",
+    doc = "",
+    doc = "With the `bevy_app` feature, they can also be enabled with",
+    doc = "[`ScheduleTimerTickPlugin::with_unified_events`].",
+    doc = "",
+    doc = "[`ScheduleTimerTickPlugin::with_unified_events`]: crate::plugin::ScheduleTimerTickPlugin::with_unified_events"
+)]
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct OnTimerEvent {
+    /// What happened to the timer.
+    pub kind: TimerEventKind,
+}
+
+/// What happened to the timer of an [`OnTimerEvent`], along with the data of
+/// the event it mirrors.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TimerEventKind {
+    /// The timer was started, mirroring [`OnTimerStarted`].
+    Started {
+        /// See [`OnTimerStarted::replaced`].
+        replaced: bool,
+    },
+    /// The timer crossed a threshold, mirroring [`OnTimerThreshold`].
+    Threshold {
+        /// See [`OnTimerThreshold::fraction`].
+        fraction: f32,
+    },
+    /// The timer crossed a whole second, mirroring [`OnTimerSecondTick`].
+    SecondTick {
+        /// See [`OnTimerSecondTick::remaining_secs`].
+        remaining_secs: u32,
+    },
+    /// The timer finished, mirroring [`OnTimerFinished`].
+    Finished {
+        /// See [`OnTimerFinished::duration`].
+        duration: Duration,
+        /// See [`OnTimerFinished::repetitions_remaining`].
+        repetitions_remaining: Option<u32>,
+    },
+    /// The timer was cancelled, mirroring [`OnTimerCancelled`].
+    Cancelled {
+        /// See [`OnTimerCancelled::reason`].
+        reason: CancelReason,
+    },
+    /// The timer was paused.
+    Paused,
+    /// The timer was unpaused.
+    Unpaused,
+}
+
+/// [`Resource`] marking that the [`OnTimerEvent`] observers were added.
+#[derive(Resource)]
+struct MirrorTimerEvents;
+
+impl OnTimerEvent {
+    /// Adds the observers that trigger [`OnTimerEvent`].
+    ///
+    /// Does nothing if they were already added.
+    pub fn register(world: &mut World) {
+        if world.contains_resource::<MirrorTimerEvents>() {
+            return;
+        }
+        world.insert_resource(MirrorTimerEvents);
+        world.add_observer(|t: Trigger<OnTimerStarted>, commands: Commands| {
+            let replaced = t.event().replaced;
+            mirror(&t, TimerEventKind::Started { replaced }, commands);
+        });
+        world.add_observer(|t: Trigger<OnTimerThreshold>, commands: Commands| {
+            let fraction = t.event().fraction;
+            mirror(&t, TimerEventKind::Threshold { fraction }, commands);
+        });
+        world.add_observer(|t: Trigger<OnTimerSecondTick>, commands: Commands| {
+            let remaining_secs = t.event().remaining_secs;
+            mirror(&t, TimerEventKind::SecondTick { remaining_secs }, commands);
+        });
+        world.add_observer(|t: Trigger<OnTimerFinished>, commands: Commands| {
+            let kind = TimerEventKind::Finished {
+                duration: t.event().duration,
+                repetitions_remaining: t.event().repetitions_remaining,
+            };
+            mirror(&t, kind, commands);
+        });
+        world.add_observer(|t: Trigger<OnTimerCancelled>, commands: Commands| {
+            let reason = t.event().reason;
+            mirror(&t, TimerEventKind::Cancelled { reason }, commands);
+        });
+    }
+}
+
+/// Triggers an [`OnTimerEvent`] for a timer whose paused state changed, if
+/// the events are enabled.
+pub(crate) fn mirror_pause(
+    world: &mut World,
+    entity: Entity,
+    component: ComponentId,
+    paused: bool,
+) {
+    if !world.contains_resource::<MirrorTimerEvents>() {
+        return;
+    }
+    let kind = if paused {
+        TimerEventKind::Paused
+    } else {
+        TimerEventKind::Unpaused
+    };
+    world.trigger_targets(OnTimerEvent { kind }, TargetBoth::new(entity, component));
+}
+
+/// Triggers an [`OnTimerEvent`] for each component targeted by the trigger.
+fn mirror<E: Event>(trigger: &Trigger<E>, kind: TimerEventKind, mut commands: Commands) {
+    for &component in trigger.components() {
+        commands.trigger_targets(
+            OnTimerEvent { kind },
+            TargetBoth::new(trigger.entity(), component),
+        );
+    }
+}
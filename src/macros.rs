@@ -0,0 +1,63 @@
+//! Macros for declaring timer tags.
+
+/// Declares a zero-sized [`Component`] to be used as a timer tag.
+///
+/// ```
+/// # use bevy_observed_timers::timer_tag;
+/// timer_tag!(pub Reload);
+/// ```
+///
+/// expands to:
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// #[derive(Component)]
+/// pub struct Reload;
+/// ```
+///
+/// Attributes, including doc comments, are forwarded to the struct.
+///
+/// # Finish observer
+///
+/// An observer can be attached to the tag with `=> on_finished`, followed by
+/// an observer system (a fully typed closure or a function path). The macro
+/// then also generates an associated `on_finished` function returning the
+/// [`Observer`], which can be spawned to register it:
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_observed_timers::{prelude::*, timer_tag};
+/// # #[derive(Component)]
+/// # struct Ammo(u32);
+/// timer_tag!(Reload => on_finished |t: Trigger<OnTimerFinished, Reload>, mut ammo: Query<&mut Ammo>| {
+///     ammo.get_mut(t.entity()).unwrap().0 = 30;
+/// });
+///
+/// # let mut world = World::new();
+/// world.spawn(Reload::on_finished());
+/// ```
+///
+/// [`Component`]: bevy_ecs::component::Component
+/// [`Observer`]: bevy_ecs::observer::Observer
+#[macro_export]
+macro_rules! timer_tag {
+    ($(#[$meta:meta])* $vis:vis $name:ident) => {
+        $(#[$meta])*
+        #[derive($crate::__macro_exports::Component)]
+        $vis struct $name;
+    };
+    ($(#[$meta:meta])* $vis:vis $name:ident => on_finished $observer:expr) => {
+        $crate::timer_tag!($(#[$meta])* $vis $name);
+
+        impl $name {
+            #[doc = concat!(
+                "Returns an observer that runs when a timer tagged with `",
+                stringify!($name),
+                "` finishes."
+            )]
+            $vis fn on_finished() -> $crate::__macro_exports::Observer {
+                $crate::__macro_exports::Observer::new($observer)
+            }
+        }
+    };
+}
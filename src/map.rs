@@ -0,0 +1,186 @@
+//! The insertion-ordered map backing [`Timers`].
+//!
+//! [`Timers`]: crate::core::Timers
+
+use bevy_ecs::component::ComponentId;
+use bevy_utils::HashMap;
+use smallvec::SmallVec;
+
+/// The number of entries stored inline, without allocating.
+const INLINE: usize = 4;
+
+/// The number of entries above which lookups go through a hash index rather
+/// than a linear scan.
+const INDEX_THRESHOLD: usize = 8;
+
+/// An insertion-ordered map from [`ComponentId`]s to values, optimized for the
+/// few entries most entities hold.
+///
+/// Behaves like an `IndexMap`, including [`swap_remove`](Self::swap_remove)
+/// moving the last entry into the removed slot. Up to [`INLINE`] entries are
+/// stored inline, and lookups scan the entries linearly until there are more
+/// than [`INDEX_THRESHOLD`] of them, which is faster than hashing for small
+/// maps.
+pub(crate) struct TimerMap<V> {
+    entries: SmallVec<[(ComponentId, V); INLINE]>,
+    /// Positions of the entries, built once the map grows past
+    /// [`INDEX_THRESHOLD`], and dropped when it is cleared.
+    index: Option<HashMap<ComponentId, usize>>,
+}
+
+impl<V> Default for TimerMap<V> {
+    fn default() -> Self {
+        Self {
+            entries: SmallVec::new(),
+            index: None,
+        }
+    }
+}
+
+impl<V> TimerMap<V> {
+    pub(crate) fn with_capacity(capacity: usize) -> Self {
+        Self {
+            entries: SmallVec::with_capacity(capacity),
+            index: None,
+        }
+    }
+
+    pub(crate) fn capacity(&self) -> usize {
+        self.entries.capacity()
+    }
+
+    pub(crate) fn reserve(&mut self, additional: usize) {
+        self.entries.reserve(additional);
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    fn position(&self, key: &ComponentId) -> Option<usize> {
+        match &self.index {
+            Some(index) => index.get(key).copied(),
+            None => self.entries.iter().position(|(k, _)| k == key),
+        }
+    }
+
+    pub(crate) fn contains_key(&self, key: &ComponentId) -> bool {
+        self.position(key).is_some()
+    }
+
+    pub(crate) fn get(&self, key: &ComponentId) -> Option<&V> {
+        self.position(key).map(|i| &self.entries[i].1)
+    }
+
+    pub(crate) fn get_mut(&mut self, key: &ComponentId) -> Option<&mut V> {
+        self.position(key).map(|i| &mut self.entries[i].1)
+    }
+
+    /// Inserts a value, returning the previous value of the key. A replaced
+    /// entry keeps its position, new entries are appended.
+    pub(crate) fn insert(&mut self, key: ComponentId, value: V) -> Option<V> {
+        if let Some(i) = self.position(&key) {
+            return Some(core::mem::replace(&mut self.entries[i].1, value));
+        }
+        self.entries.push((key, value));
+        match &mut self.index {
+            Some(index) => {
+                index.insert(key, self.entries.len() - 1);
+            }
+            None if self.entries.len() > INDEX_THRESHOLD => {
+                let index = self
+                    .entries
+                    .iter()
+                    .enumerate()
+                    .map(|(i, (k, _))| (*k, i))
+                    .collect();
+                self.index = Some(index);
+            }
+            None => {}
+        }
+        None
+    }
+
+    /// Removes an entry by moving the last entry into its position.
+    pub(crate) fn swap_remove(&mut self, key: &ComponentId) -> Option<V> {
+        let i = self.position(key)?;
+        let (_, value) = self.entries.swap_remove(i);
+        if let Some(index) = &mut self.index {
+            index.remove(key);
+            if let Some((moved, _)) = self.entries.get(i) {
+                index.insert(*moved, i);
+            }
+        }
+        Some(value)
+    }
+
+    /// Replaces the key of an entry, keeping its position, and returns `true`
+    /// if there was such an entry. An entry already using the new key is
+    /// removed first.
+    pub(crate) fn rename(&mut self, old: &ComponentId, new: ComponentId) -> bool {
+        if *old == new {
+            return self.contains_key(old);
+        }
+        if !self.contains_key(old) {
+            return false;
+        }
+        self.swap_remove(&new);
+        let Some(i) = self.position(old) else {
+            return false;
+        };
+        self.entries[i].0 = new;
+        if let Some(index) = &mut self.index {
+            index.remove(old);
+            index.insert(new, i);
+        }
+        true
+    }
+
+    /// Swaps the values of two entries, keeping their keys and positions. Does
+    /// nothing unless both entries exist.
+    pub(crate) fn swap_values(&mut self, a: &ComponentId, b: &ComponentId) {
+        let (Some(i), Some(j)) = (self.position(a), self.position(b)) else {
+            return;
+        };
+        if i == j {
+            return;
+        }
+        let (low, high) = (i.min(j), i.max(j));
+        let (left, right) = self.entries.split_at_mut(high);
+        core::mem::swap(&mut left[low].1, &mut right[0].1);
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.entries.clear();
+        self.index = None;
+    }
+
+    pub(crate) fn drain(&mut self) -> impl Iterator<Item = (ComponentId, V)> + '_ {
+        self.index = None;
+        self.entries.drain(..)
+    }
+
+    pub(crate) fn keys(&self) -> impl Iterator<Item = &ComponentId> {
+        self.entries.iter().map(|(k, _)| k)
+    }
+
+    pub(crate) fn values(&self) -> impl Iterator<Item = &V> {
+        self.entries.iter().map(|(_, v)| v)
+    }
+
+    pub(crate) fn values_mut(&mut self) -> impl Iterator<Item = &mut V> {
+        self.entries.iter_mut().map(|(_, v)| v)
+    }
+
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (&ComponentId, &V)> {
+        self.entries.iter().map(|(k, v)| (k, v))
+    }
+
+    pub(crate) fn iter_mut(&mut self) -> impl Iterator<Item = (&ComponentId, &mut V)> {
+        self.entries.iter_mut().map(|(k, v)| (&*k, v))
+    }
+}
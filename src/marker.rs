@@ -0,0 +1,133 @@
+//! Provides the [`HasTimers`] marker component, for filtering entities with
+//! at least one timer at the archetype level.
+
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    observer::Trigger,
+    system::{Commands, Query, Resource},
+    world::{OnRemove, World},
+};
+
+use crate::{
+    core::Timers,
+    event::{OnTimerCancelled, OnTimerFinished, OnTimerStarted, OnTimersFinishedBatch},
+};
+
+/// Marker [`Component`] present on every entity with at least one [`Timer`].
+///
+/// [`Timers`] stays on an entity after its last timer is gone, so
+/// `With<Timers>` also matches entities without any timer. This marker allows
+/// filtering for "any entity with at least one timer" cheaply, with
+/// `Query<Entity, With<HasTimers>>`, without checking every [`Timers`] map.
+///
+/// The marker is opt-in, since adding and removing it moves entities between
+/// archetypes. Enable it with [`HasTimers::register`]. It is inserted when a
+/// timer is started, including delayed and transferred timers, and removed
+/// when the last timer finishes, is cancelled or removed, or when [`Timers`]
+/// is removed. Like the [`TimerIndex`], changes made to [`Timers`] directly are
+/// not tracked. Updates from observers are applied through [`Commands`], so
+/// they are visible once commands are flushed.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_observed_timers::prelude::*;
+/// # let mut world = World::new();
+/// HasTimers::register(&mut world);
+///
+/// fn busy_entities(busy: Query<Entity, With<HasTimers>>) {
+///     for entity in &busy {
+///         // ...
+///     }
+/// }
+/// # bevy_ecs::system::assert_is_system(busy_entities);
+/// ```
+///
+/// [`Timer`]: bevy_time::Timer
+/// [`TimerIndex`]: crate::index::TimerIndex
+#[cfg_attr(
+    feature = "bevy_app",
+    doc = "",
+    doc = "With the `bevy_app` feature, it can also be enabled with",
+    doc = "[`ScheduleTimerTickPlugin::with_has_timers`].",
+    doc = "",
+    doc = "[`ScheduleTimerTickPlugin::with_has_timers`]: crate::plugin::ScheduleTimerTickPlugin::with_has_timers"
+)]
+#[derive(Component, Default, Debug, Clone, Copy)]
+pub struct HasTimers;
+
+/// [`Resource`] recording that [`HasTimers`] is maintained.
+#[derive(Resource, Default)]
+struct TrackHasTimers;
+
+impl HasTimers {
+    /// Adds the observers that maintain the [`HasTimers`] marker.
+    ///
+    /// Does nothing if they were already added.
+    pub fn register(world: &mut World) {
+        if world.contains_resource::<TrackHasTimers>() {
+            return;
+        }
+        world.init_resource::<TrackHasTimers>();
+        world.add_observer(
+            |t: Trigger<OnTimerStarted>, timers: Query<&Timers>, commands: Commands| {
+                sync_deferred(t.entity(), &timers, commands);
+            },
+        );
+        world.add_observer(
+            |t: Trigger<OnTimerFinished>, timers: Query<&Timers>, commands: Commands| {
+                sync_deferred(t.entity(), &timers, commands);
+            },
+        );
+        world.add_observer(
+            |t: Trigger<OnTimersFinishedBatch>, timers: Query<&Timers>, commands: Commands| {
+                sync_deferred(t.entity(), &timers, commands);
+            },
+        );
+        world.add_observer(
+            |t: Trigger<OnTimerCancelled>, timers: Query<&Timers>, commands: Commands| {
+                sync_deferred(t.entity(), &timers, commands);
+            },
+        );
+        world.add_observer(|t: Trigger<OnRemove, Timers>, mut commands: Commands| {
+            if let Some(mut entity) = commands.get_entity(t.entity()) {
+                entity.remove::<HasTimers>();
+            }
+        });
+    }
+}
+
+/// Inserts or removes [`HasTimers`] on the given entity to match its
+/// [`Timers`], if the marker is maintained.
+pub(crate) fn sync(world: &mut World, entity: Entity) {
+    if !world.contains_resource::<TrackHasTimers>() {
+        return;
+    }
+    let Ok(mut emut) = world.get_entity_mut(entity) else {
+        return;
+    };
+    let has_timers = emut
+        .get::<Timers>()
+        .is_some_and(|timers| !timers.is_empty());
+    if has_timers != emut.contains::<HasTimers>() {
+        if has_timers {
+            emut.insert(HasTimers);
+        } else {
+            emut.remove::<HasTimers>();
+        }
+    }
+}
+
+fn sync_deferred(entity: Entity, timers: &Query<&Timers>, mut commands: Commands) {
+    let has_timers = timers.get(entity).is_ok_and(|timers| !timers.is_empty());
+    let Some(mut entity) = commands.get_entity(entity) else {
+        return;
+    };
+    if has_timers {
+        entity.insert(HasTimers);
+    } else {
+        entity.remove::<HasTimers>();
+    }
+}
@@ -1,15 +1,69 @@
 //! Provides a Bevy [`Plugin`] that schedules the [`tick_entity_timers`] system.
 
-use bevy_app::{App, FixedPreUpdate, Plugin, PreUpdate};
-use bevy_ecs::schedule::{InternedScheduleLabel, ScheduleLabel};
+use core::time::Duration;
 
-use crate::core::tick_entity_timers;
+use bevy_app::{App, FixedPreUpdate, Plugin, PreUpdate, Update};
+#[cfg(feature = "bevy_state")]
+use bevy_ecs::system::ResMut;
+use bevy_ecs::{
+    component::Component,
+    schedule::{InternedScheduleLabel, IntoSystemConfigs, ScheduleLabel, SystemSet},
+    system::IntoObserverSystem,
+};
+#[cfg(feature = "bevy_reflect")]
+use bevy_reflect::{GetTypeRegistration, TypePath};
+#[cfg(feature = "bevy_state")]
+use bevy_state::state::{OnEnter, OnExit, States};
+use bevy_time::{Fixed, Real, TimerMode, Virtual};
+
+#[cfg(feature = "bevy_state")]
+use crate::core::TimersPaused;
+#[cfg(feature = "bevy_reflect")]
+use crate::{
+    core::{TimerConfig, TimerSnapshot},
+    reflect::TimerTagPaths,
+};
+
+use crate::{
+    category::{TimerCategories, TimerCategory},
+    core::{
+        tick_entity_timers, tick_entity_timers_on_clock, tick_entity_timers_ordered,
+        time_is_advancing, TimerTickScheduled,
+    },
+    event::OnTimerFinished,
+    index::TimerIndex,
+    lifecycle::OnTimerEvent,
+    marker::HasTimers,
+    script::ScriptTimerEvent,
+    settings::{MissingEntityPolicy, TimerSettings},
+};
 
 /// [`Plugin`] that schedules the [`tick_entity_timers`] system in a given
 /// schedule.
 pub struct ScheduleTimerTickPlugin {
     /// The schedule in which the [`tick_entity_timers`] system is scheduled.
     pub tick_in: InternedScheduleLabel,
+    /// The [`TimerSettings`] inserted as a resource.
+    pub settings: TimerSettings,
+    /// Whether to maintain the [`TimerIndex`] resource.
+    pub index: bool,
+    /// Whether to maintain the [`HasTimers`] marker.
+    pub has_timers: bool,
+    /// Whether to write [`ScriptTimerEvent`]s.
+    pub script_events: bool,
+    /// Whether to trigger [`OnTimerEvent`]s.
+    pub unified_events: bool,
+    /// Whether to schedule [`tick_entity_timers_ordered`] instead of
+    /// [`tick_entity_timers`].
+    pub deterministic_dispatch: bool,
+    /// Whether to tick timers per [`TimerClock`], with
+    /// [`tick_entity_timers_on_clock`].
+    ///
+    /// [`TimerClock`]: crate::core::TimerClock
+    pub timer_clocks: bool,
+    /// Whether to skip the tick system while time is not advancing, with
+    /// [`time_is_advancing`].
+    pub skip_zero_delta: bool,
 }
 
 impl ScheduleTimerTickPlugin {
@@ -17,28 +71,430 @@ impl ScheduleTimerTickPlugin {
     pub fn new(schedule: impl ScheduleLabel) -> Self {
         Self {
             tick_in: schedule.intern(),
+            settings: TimerSettings::default(),
+            index: false,
+            has_timers: false,
+            script_events: false,
+            unified_events: false,
+            deterministic_dispatch: false,
+            timer_clocks: false,
+            skip_zero_delta: false,
         }
     }
 
     /// Creates a new plugin that ticks entity timers in the [`PreUpdate`]
     /// schedule.
     pub fn pre_update() -> Self {
-        Self {
-            tick_in: PreUpdate.intern(),
-        }
+        Self::new(PreUpdate)
+    }
+
+    /// Creates a new plugin that ticks entity timers in the [`Update`]
+    /// schedule.
+    ///
+    /// Timers then reflect changes made earlier in the same frame, such as
+    /// input handled in [`PreUpdate`]. Order systems reading timers relative
+    /// to [`tick_entity_timers`] as needed.
+    pub fn update() -> Self {
+        Self::new(Update)
     }
 
     /// Creates a new plugin that ticks entity timers in the [`FixedPreUpdate`]
     /// schedule.
     pub fn fixed_pre_update() -> Self {
-        Self {
-            tick_in: FixedPreUpdate.intern(),
-        }
+        Self::new(FixedPreUpdate)
+    }
+
+    /// Enables or disables the [`TimerIndex`] resource, which is disabled by
+    /// default since maintaining it has a cost for every timer event.
+    pub fn with_index(mut self, index: bool) -> Self {
+        self.index = index;
+        self
+    }
+
+    /// Enables or disables [`TimerSettings::count_finishes`], which is
+    /// disabled by default since it adds a component to every entity whose
+    /// timers finish.
+    ///
+    /// Call this after [`Self::with_settings`], which overwrites it.
+    pub fn with_finished_count(mut self, count: bool) -> Self {
+        self.settings.count_finishes = count;
+        self
+    }
+
+    /// Enables or disables the [`HasTimers`] marker, which is disabled by
+    /// default since adding and removing it moves entities between
+    /// archetypes.
+    pub fn with_has_timers(mut self, has_timers: bool) -> Self {
+        self.has_timers = has_timers;
+        self
+    }
+
+    /// Enables or disables writing [`ScriptTimerEvent`]s, which is disabled by
+    /// default since it has a cost for every timer event.
+    pub fn with_script_events(mut self, script_events: bool) -> Self {
+        self.script_events = script_events;
+        self
+    }
+
+    /// Enables or disables triggering [`OnTimerEvent`]s alongside the
+    /// specific timer events, which is disabled by default since it has a
+    /// cost for every timer event.
+    pub fn with_unified_events(mut self, unified_events: bool) -> Self {
+        self.unified_events = unified_events;
+        self
+    }
+
+    /// Enables or disables deterministic dispatch, which is disabled by
+    /// default.
+    ///
+    /// When enabled, [`tick_entity_timers_ordered`] is scheduled instead of
+    /// [`tick_entity_timers`], so the events of a tick are triggered in the
+    /// same order on every run: sorted by [`Entity`], and within an entity in
+    /// the usual priority order. Tests and replays can then rely on the order
+    /// in which observers run. The cost is that entities are collected and
+    /// sorted on every tick, and ticked on a single thread, which is
+    /// noticeably slower with many entities.
+    ///
+    /// # Panics
+    ///
+    /// The plugin panics when added if timer clocks are also enabled, see
+    /// [`Self::with_timer_clocks`].
+    ///
+    /// [`Entity`]: bevy_ecs::entity::Entity
+    pub fn with_deterministic_dispatch(mut self, deterministic_dispatch: bool) -> Self {
+        self.deterministic_dispatch = deterministic_dispatch;
+        self
+    }
+
+    /// Enables or disables ticking timers per [`TimerClock`], which is
+    /// disabled by default.
+    ///
+    /// When enabled, [`tick_entity_timers_on_clock`] is scheduled instead of
+    /// [`tick_entity_timers`]: for [`Virtual`] and [`Real`] timers in the tick
+    /// schedule, and for [`Fixed`] timers in [`FixedPreUpdate`]. Each timer
+    /// is then only ticked by its own clock, see [`TimerClock`]. The tick
+    /// schedule must run once per frame, such as [`PreUpdate`], or
+    /// [`Virtual`] and [`Real`] timers would be ticked once per fixed step.
+    /// [`Virtual`] timers are ticked before [`Real`] ones.
+    ///
+    /// # Panics
+    ///
+    /// The plugin panics when added if deterministic dispatch is also
+    /// enabled, see [`Self::with_deterministic_dispatch`], as it is not
+    /// available with this option.
+    ///
+    /// [`TimerClock`]: crate::core::TimerClock
+    pub fn with_timer_clocks(mut self, timer_clocks: bool) -> Self {
+        self.timer_clocks = timer_clocks;
+        self
+    }
+
+    /// Enables or disables skipping the tick system while the [`Time`] delta
+    /// is zero, such as while [`Time<Virtual>`] is paused, which is disabled
+    /// by default.
+    ///
+    /// This saves iterating every entity with timers on paused frames, at the
+    /// cost of a few differences, see [`time_is_advancing`]. It does not apply
+    /// to timer clocks, see [`Self::with_timer_clocks`].
+    ///
+    /// [`Time`]: bevy_time::Time
+    /// [`Time<Virtual>`]: bevy_time::Virtual
+    pub fn with_skip_zero_delta(mut self, skip_zero_delta: bool) -> Self {
+        self.skip_zero_delta = skip_zero_delta;
+        self
+    }
+
+    /// Sets [`TimerSettings::max_substep`], splitting large deltas into
+    /// substeps no longer than `max_substep`.
+    ///
+    /// Call this after [`Self::with_settings`], which overwrites it.
+    pub fn with_max_substep(mut self, max_substep: Duration) -> Self {
+        self.settings.max_substep = Some(max_substep);
+        self
+    }
+
+    /// Sets [`TimerSettings::batch_size`], the number of entities per batch
+    /// when ticking in parallel.
+    ///
+    /// Call this after [`Self::with_settings`], which overwrites it.
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.settings.batch_size = Some(batch_size);
+        self
+    }
+
+    /// Sets [`TimerSettings::one_shot_removal_delay`], the number of ticks
+    /// finished one-shot timers are kept for before being removed.
+    ///
+    /// Call this after [`Self::with_settings`], which overwrites it.
+    pub fn with_one_shot_removal_delay(mut self, ticks: u32) -> Self {
+        self.settings.one_shot_removal_delay = ticks;
+        self
+    }
+
+    /// Sets [`TimerSettings::max_fires_per_tick`], the most times
+    /// [`OnTimerFinished`] is triggered for a single timer during a tick.
+    ///
+    /// Call this after [`Self::with_settings`], which overwrites it.
+    pub fn with_max_fires_per_tick(mut self, max_fires: u32) -> Self {
+        self.settings.max_fires_per_tick = max_fires;
+        self
+    }
+
+    /// Sets [`TimerSettings::default_mode`], the mode of timers started
+    /// without one.
+    ///
+    /// Call this after [`Self::with_settings`], which overwrites it.
+    pub fn with_default_mode(mut self, mode: TimerMode) -> Self {
+        self.settings.default_mode = mode;
+        self
+    }
+
+    /// Sets [`TimerSettings::missing_entity_policy`], what start commands do
+    /// when their entity does not exist.
+    ///
+    /// Call this after [`Self::with_settings`], which overwrites it.
+    pub fn with_missing_entity_policy(mut self, policy: MissingEntityPolicy) -> Self {
+        self.settings.missing_entity_policy = policy;
+        self
+    }
+
+    /// Sets the [`TimerSettings`] inserted as a resource.
+    pub fn with_settings(mut self, settings: TimerSettings) -> Self {
+        self.settings = settings;
+        self
+    }
+}
+
+impl Default for ScheduleTimerTickPlugin {
+    /// Ticks entity timers in the [`PreUpdate`] schedule, like
+    /// [`ScheduleTimerTickPlugin::pre_update`].
+    fn default() -> Self {
+        Self::pre_update()
     }
 }
 
 impl Plugin for ScheduleTimerTickPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(self.tick_in, tick_entity_timers);
+        assert!(
+            !(self.timer_clocks && self.deterministic_dispatch),
+            "timer clocks and deterministic dispatch cannot be combined"
+        );
+        app.insert_resource(self.settings.clone())
+            .init_resource::<TimerTickScheduled>();
+        #[cfg(feature = "bevy_reflect")]
+        app.register_type::<TimerConfig>()
+            .register_type::<TimerSnapshot>();
+        if self.timer_clocks {
+            app.add_systems(
+                self.tick_in,
+                (
+                    tick_entity_timers_on_clock::<Virtual>,
+                    tick_entity_timers_on_clock::<Real>,
+                )
+                    // Both take `&mut Timers`, so order them explicitly.
+                    .chain()
+                    .in_set(TimerTickSet),
+            )
+            .add_systems(
+                FixedPreUpdate,
+                tick_entity_timers_on_clock::<Fixed>.in_set(TimerTickSet),
+            );
+        } else {
+            let mut tick = if self.deterministic_dispatch {
+                tick_entity_timers_ordered.in_set(TimerTickSet)
+            } else {
+                tick_entity_timers.in_set(TimerTickSet)
+            };
+            if self.skip_zero_delta {
+                tick = tick.run_if(time_is_advancing);
+            }
+            app.add_systems(self.tick_in, tick);
+        }
+        if self.index {
+            TimerIndex::register(app.world_mut());
+        }
+        if self.has_timers {
+            HasTimers::register(app.world_mut());
+        }
+        if self.script_events {
+            ScriptTimerEvent::register(app.world_mut());
+        }
+        if self.unified_events {
+            OnTimerEvent::register(app.world_mut());
+        }
+    }
+}
+
+/// [`SystemSet`] containing the tick systems scheduled by the
+/// [`ScheduleTimerTickPlugin`].
+///
+/// Order systems relative to this set to control whether the timers they
+/// start are ticked in the same frame:
+/// - A system ordered `.before(TimerTickSet)` in the same schedule has its
+///   commands applied before the tick, since Bevy inserts a sync point
+///   between systems with commands and the systems ordered after them. Timers
+///   it starts are ticked in the same frame, by the whole frame delta.
+/// - A system ordered `.after(TimerTickSet)`, or running in a later schedule,
+///   starts timers that are first ticked on the next frame.
+/// - Without an ordering, either can happen, and may change between builds of
+///   the schedule.
+///
+/// When scheduling [`tick_entity_timers`] manually, order systems relative
+/// to it in the same way.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_app::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_observed_timers::{plugin::TimerTickSet, prelude::*};
+/// # fn spawn_projectiles() {}
+/// App::new()
+///     .add_plugins(ScheduleTimerTickPlugin::update())
+///     // Projectile lifetimes are ticked from the frame they are spawned in.
+///     .add_systems(Update, spawn_projectiles.before(TimerTickSet));
+/// ```
+#[derive(SystemSet, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TimerTickSet;
+
+/// [`Plugin`] that pauses all timers while in the given [`States`] value, by
+/// setting the [`TimersPaused`] resource on entering it, and clearing it on
+/// exiting it.
+///
+/// Requires the `bevy_state` feature. Individually paused timers are left
+/// untouched, see [`TimersPaused`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy_app::prelude::*;
+/// # use bevy_state::prelude::*;
+/// # use bevy_observed_timers::{prelude::*, plugin::PauseTimersInState};
+/// #[derive(States, Default, Clone, PartialEq, Eq, Hash, Debug)]
+/// enum GameState {
+///     #[default]
+///     Playing,
+///     Paused,
+/// }
+///
+/// App::new()
+///     .add_plugins(ScheduleTimerTickPlugin::pre_update())
+///     .add_plugins(PauseTimersInState(GameState::Paused));
+/// ```
+#[cfg(feature = "bevy_state")]
+pub struct PauseTimersInState<S: States>(pub S);
+
+#[cfg(feature = "bevy_state")]
+impl<S: States> Plugin for PauseTimersInState<S> {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TimersPaused>()
+            .add_systems(
+                OnEnter(self.0.clone()),
+                |mut paused: ResMut<TimersPaused>| paused.0 = true,
+            )
+            .add_systems(
+                OnExit(self.0.clone()),
+                |mut paused: ResMut<TimersPaused>| paused.0 = false,
+            );
+    }
+}
+
+/// [`App`] extension trait for registering observers of this crate's events.
+pub trait AppTimersExt {
+    /// Adds a global observer that runs when a [`Timer`] tagged with the
+    /// [`Component`] `T` finishes on any entity.
+    ///
+    /// This is sugar over [`App::add_observer`] with a
+    /// `Trigger<OnTimerFinished, T>`, which keeps finish observers readable
+    /// when registering many of them in a plugin.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_observed_timers::{plugin::AppTimersExt, prelude::*};
+    /// # #[derive(Component)]
+    /// # struct Stunned;
+    /// App::new()
+    ///     .add_plugins(ScheduleTimerTickPlugin::pre_update())
+    ///     .add_timer_finished_observer::<Stunned, _>(|t: Trigger<OnTimerFinished, Stunned>| {
+    ///         println!("{} is no longer stunned", t.entity());
+    ///     });
+    /// ```
+    ///
+    /// [`Timer`]: bevy_time::Timer
+    fn add_timer_finished_observer<T: Component, M>(
+        &mut self,
+        observer: impl IntoObserverSystem<OnTimerFinished, T, M>,
+    ) -> &mut Self;
+
+    /// Registers the [`Component`] `T` as a timer tag in the type registry
+    /// and in the [`TimerTagPaths`] resource, so that its timers can be saved
+    /// and loaded by type path.
+    ///
+    /// Requires the `bevy_reflect` feature. Timers whose tag was not
+    /// registered this way are skipped by [`TimerTagPaths::snapshot`], and
+    /// are never serialized.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_reflect::Reflect;
+    /// # use bevy_observed_timers::{plugin::AppTimersExt, prelude::*};
+    /// #[derive(Component, Reflect)]
+    /// struct Poisoned;
+    ///
+    /// App::new()
+    ///     .add_plugins(ScheduleTimerTickPlugin::pre_update())
+    ///     .register_timer_tag::<Poisoned>();
+    /// ```
+    ///
+    /// [`TimerTagPaths`]: crate::reflect::TimerTagPaths
+    /// [`TimerTagPaths::snapshot`]: crate::reflect::TimerTagPaths::snapshot
+    #[cfg(feature = "bevy_reflect")]
+    fn register_timer_tag<T: Component + GetTypeRegistration + TypePath>(&mut self) -> &mut Self;
+
+    /// Adds the timer tag `T` to the given category, so that
+    /// [`cancel_category`](crate::command::EntityCommandTimersExt::cancel_category)
+    /// cancels its timers, see [`TimerCategories`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_observed_timers::{category::TimerCategory, plugin::AppTimersExt, prelude::*};
+    /// const DEBUFF: TimerCategory = TimerCategory("debuff");
+    ///
+    /// #[derive(Component)]
+    /// struct Poisoned;
+    ///
+    /// App::new()
+    ///     .add_plugins(ScheduleTimerTickPlugin::pre_update())
+    ///     .tag_category::<Poisoned>(DEBUFF);
+    /// ```
+    fn tag_category<T: Component>(&mut self, category: TimerCategory) -> &mut Self;
+}
+
+impl AppTimersExt for App {
+    fn add_timer_finished_observer<T: Component, M>(
+        &mut self,
+        observer: impl IntoObserverSystem<OnTimerFinished, T, M>,
+    ) -> &mut Self {
+        self.add_observer(observer)
+    }
+
+    #[cfg(feature = "bevy_reflect")]
+    fn register_timer_tag<T: Component + GetTypeRegistration + TypePath>(&mut self) -> &mut Self {
+        TimerTagPaths::register::<T>(self.world_mut());
+        self.register_type::<T>()
+    }
+
+    fn tag_category<T: Component>(&mut self, category: TimerCategory) -> &mut Self {
+        TimerCategories::register::<T>(self.world_mut(), category);
+        self
     }
 }
@@ -0,0 +1,241 @@
+//! [`SystemParam`]s for reading and controlling [`Timers`] across entities.
+
+use bevy_ecs::{
+    change_detection::Mut,
+    component::{Component, ComponentId, Components},
+    entity::Entity,
+    system::{Commands, Query, SystemParam},
+    world::World,
+};
+
+use crate::{
+    core::Timers,
+    event::{CancelReason, OnTimerCancelled},
+    lifecycle,
+    targets::TargetBoth,
+};
+
+/// [`SystemParam`] for looking up [`Timers`] across entities by their tag
+/// [`Component`].
+///
+/// Since all timers of an entity live in a single [`Timers`] component,
+/// archetype filtering cannot tell which tags an entity is timing. Lookups by
+/// tag therefore iterate every entity with [`Timers`] and check its map, which
+/// is `O(n)` in the number of such entities.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_observed_timers::prelude::*;
+/// # #[derive(Component)]
+/// # struct Reload;
+/// fn show_reloading(timers: TimerQuery) {
+///     for entity in timers.entities_with::<Reload>() {
+///         // ...
+///     }
+/// }
+/// # bevy_ecs::system::assert_is_system(show_reloading);
+/// ```
+#[derive(SystemParam)]
+pub struct TimerQuery<'w, 's> {
+    timers: Query<'w, 's, (Entity, &'static Timers)>,
+    components: &'w Components,
+}
+
+impl TimerQuery<'_, '_> {
+    /// Returns the [`Timers`] of the given entity, if it has any.
+    pub fn get(&self, entity: Entity) -> Option<&Timers> {
+        self.timers.get(entity).ok().map(|(_, timers)| timers)
+    }
+
+    /// Returns an iterator over the short type names of the tags of the given
+    /// entity's timers, in insertion order.
+    ///
+    /// Returns no names if the entity has no [`Timers`]. See
+    /// [`Timers::tag_names`].
+    pub fn tag_names(&self, entity: Entity) -> impl Iterator<Item = String> + '_ {
+        self.get(entity)
+            .into_iter()
+            .flat_map(|timers| timers.tag_names(self.components))
+    }
+
+    /// Returns `true` if the given entity has a timer tagged with the
+    /// [`Component`] `T` that has not finished yet.
+    ///
+    /// This is the usual check before using an ability. Paused timers, and
+    /// delayed timers that have not started yet, count as on cooldown. A
+    /// one-shot timer stops counting once it finishes: it is removed, or,
+    /// when persistent, stays but is finished. Returns `false` if `T` was
+    /// never registered.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_observed_timers::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Dash;
+    /// fn dash(player: Single<Entity, With<Dash>>, timers: TimerQuery) {
+    ///     if timers.is_on_cooldown::<Dash>(*player) {
+    ///         return;
+    ///     }
+    ///     // ...
+    /// }
+    /// # bevy_ecs::system::assert_is_system(dash);
+    /// ```
+    pub fn is_on_cooldown<T: Component>(&self, entity: Entity) -> bool {
+        let Some(component) = self.components.component_id::<T>() else {
+            return false;
+        };
+        self.get(entity)
+            .and_then(|timers| timers.get(component))
+            .is_some_and(|timer| !timer.finished())
+    }
+
+    /// Returns an iterator over the entities that have a timer tagged with the
+    /// [`Component`] `T`.
+    ///
+    /// Returns no entities if `T` was never registered.
+    pub fn entities_with<T: Component>(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.components
+            .component_id::<T>()
+            .into_iter()
+            .flat_map(|component| self.entities_with_id(component))
+    }
+
+    /// Returns an iterator over the entities that have a timer identified by
+    /// the given [`ComponentId`].
+    pub fn entities_with_id(&self, component: ComponentId) -> impl Iterator<Item = Entity> + '_ {
+        self.timers
+            .iter()
+            .filter(move |(_, timers)| timers.contains(component))
+            .map(|(entity, _)| entity)
+    }
+}
+
+/// [`SystemParam`] for controlling [`Timers`] immediately, rather than through
+/// deferred commands such as
+/// [`cancel_timer`](crate::command::EntityCommandTimersExt::cancel_timer).
+///
+/// This is mostly useful in observers: inside an [`OnTimerFinished`]
+/// observer, the finished timer's tag is available as a [`ComponentId`]
+/// through [`Trigger::components`], and timers cancelled with
+/// [`TimerQueryMut::cancel`] are gone before any later observer or system
+/// looks at them. Only the [`OnTimerCancelled`] event is deferred, since
+/// triggering needs [`Commands`].
+///
+/// A one-shot timer is already removed when its [`OnTimerFinished`]
+/// observers run, so cancelling it there does nothing and returns `false`.
+/// Cancelling a repeating timer stops its future finishes, but finishes
+/// from the current tick that were already queued are still triggered.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_observed_timers::prelude::*;
+/// # #[derive(Component)]
+/// # struct Burning;
+/// # let mut world = World::new();
+/// // Stop a repeating damage timer once the entity is out of the fire.
+/// world.add_observer(|t: Trigger<OnTimerFinished, Burning>, mut timers: TimerQueryMut| {
+///     # let out_of_fire = true;
+///     if out_of_fire {
+///         timers.cancel(t.entity(), t.components()[0]);
+///     }
+/// });
+/// ```
+///
+/// [`OnTimerFinished`]: crate::event::OnTimerFinished
+/// [`Trigger::components`]: bevy_ecs::observer::Trigger::components
+#[derive(SystemParam)]
+pub struct TimerQueryMut<'w, 's> {
+    timers: Query<'w, 's, &'static mut Timers>,
+    commands: Commands<'w, 's>,
+}
+
+impl TimerQueryMut<'_, '_> {
+    /// Returns the [`Timers`] of the given entity, if it has any.
+    pub fn get_mut(&mut self, entity: Entity) -> Option<Mut<'_, Timers>> {
+        self.timers.get_mut(entity).ok()
+    }
+
+    /// Removes the timer identified by the given [`ComponentId`] from the
+    /// given entity immediately, and queues its [`OnTimerCancelled`] event.
+    ///
+    /// Returns `true` if the timer existed.
+    pub fn cancel(&mut self, entity: Entity, component: ComponentId) -> bool {
+        let Some(mut timers) = self.get_mut(entity) else {
+            return false;
+        };
+        if timers.remove(component).is_none() {
+            return false;
+        }
+        self.commands.trigger_targets(
+            OnTimerCancelled {
+                reason: CancelReason::Manual,
+            },
+            TargetBoth::new(entity, component),
+        );
+        true
+    }
+
+    /// Resets the timer identified by the given [`ComponentId`] on the given
+    /// entity immediately.
+    ///
+    /// Returns `true` if the timer existed. See [`Timer::reset`].
+    ///
+    /// [`Timer::reset`]: bevy_time::Timer::reset
+    pub fn reset(&mut self, entity: Entity, component: ComponentId) -> bool {
+        let Some(mut timers) = self.get_mut(entity) else {
+            return false;
+        };
+        let Some(timer) = timers.get_mut(component) else {
+            return false;
+        };
+        timer.reset();
+        true
+    }
+
+    /// Pauses the timer identified by the given [`ComponentId`] on the given
+    /// entity immediately.
+    ///
+    /// Returns `true` if the timer existed. See [`Timer::pause`].
+    ///
+    /// [`Timer::pause`]: bevy_time::Timer::pause
+    pub fn pause(&mut self, entity: Entity, component: ComponentId) -> bool {
+        self.set_paused(entity, component, true)
+    }
+
+    /// Unpauses the timer identified by the given [`ComponentId`] on the given
+    /// entity immediately.
+    ///
+    /// Returns `true` if the timer existed. See [`Timer::unpause`].
+    ///
+    /// [`Timer::unpause`]: bevy_time::Timer::unpause
+    pub fn unpause(&mut self, entity: Entity, component: ComponentId) -> bool {
+        self.set_paused(entity, component, false)
+    }
+
+    fn set_paused(&mut self, entity: Entity, component: ComponentId, paused: bool) -> bool {
+        let Some(mut timers) = self.get_mut(entity) else {
+            return false;
+        };
+        let Some(timer) = timers.get_mut(component) else {
+            return false;
+        };
+        if timer.paused() == paused {
+            return true;
+        }
+        if paused {
+            timer.pause();
+        } else {
+            timer.unpause();
+        }
+        self.commands.queue(move |world: &mut World| {
+            lifecycle::mirror_pause(world, entity, component, paused);
+        });
+        true
+    }
+}
@@ -0,0 +1,90 @@
+//! Provides the [`TimerTagPaths`] resource, mapping timer tags to their type
+//! paths so that [`Timers`] can be saved and loaded across worlds.
+//!
+//! Requires the `bevy_reflect` feature.
+
+use bevy_ecs::{
+    component::{Component, ComponentId},
+    system::Resource,
+    world::World,
+};
+use bevy_reflect::TypePath;
+use bevy_utils::HashMap;
+
+use crate::core::{TimerSnapshot, Timers};
+
+/// [`Resource`] mapping timer tags to their stable [`TypePath`]s, and back.
+///
+/// [`ComponentId`]s are assigned per world, in registration order, so they
+/// cannot be saved as-is. This resource translates [`TimerSnapshot`]s to and
+/// from type paths such as `"my_game::effects::Poison"`, which stay the same
+/// across runs.
+///
+/// Tags are added with [`TimerTagPaths::register`], or with
+/// `app.register_timer_tag::<T>()`, which also registers `T` in the type
+/// registry. Timers whose tag was not registered are skipped when saving, and
+/// saved paths that are not registered are skipped when loading, so
+/// unregistered tags are never serialized.
+#[derive(Resource, Default)]
+pub struct TimerTagPaths {
+    paths: HashMap<ComponentId, &'static str>,
+    components: HashMap<&'static str, ComponentId>,
+}
+
+impl TimerTagPaths {
+    /// Registers the [`Component`] `T` as a timer tag, inserting the
+    /// [`TimerTagPaths`] resource if needed, and returns its [`ComponentId`].
+    pub fn register<T: Component + TypePath>(world: &mut World) -> ComponentId {
+        let component = world.register_component::<T>();
+        let mut tags = world.get_resource_or_insert_with(TimerTagPaths::default);
+        tags.paths.insert(component, T::type_path());
+        tags.components.insert(T::type_path(), component);
+        component
+    }
+
+    /// Returns the type path of the tag with the given [`ComponentId`], or
+    /// `None` if it is not registered.
+    pub fn path(&self, component: ComponentId) -> Option<&'static str> {
+        self.paths.get(&component).copied()
+    }
+
+    /// Returns the [`ComponentId`] of the tag with the given type path, or
+    /// `None` if it is not registered.
+    pub fn component(&self, path: &str) -> Option<ComponentId> {
+        self.components.get(path).copied()
+    }
+
+    /// Returns a snapshot of `timers` keyed by tag type path, in insertion
+    /// order, skipping timers whose tag is not registered.
+    ///
+    /// See [`Timers::snapshot`].
+    pub fn snapshot(&self, timers: &Timers) -> Vec<(&'static str, TimerSnapshot)> {
+        timers
+            .snapshot()
+            .into_iter()
+            .filter_map(|snapshot| Some((self.path(snapshot.component)?, snapshot)))
+            .collect()
+    }
+
+    /// Restores `timers` from a snapshot taken with
+    /// [`TimerTagPaths::snapshot`], possibly in another world, skipping paths
+    /// that are not registered.
+    ///
+    /// See [`Timers::apply_snapshot`].
+    pub fn apply_snapshot<'a>(
+        &self,
+        timers: &mut Timers,
+        snapshots: impl IntoIterator<Item = (&'a str, TimerSnapshot)>,
+    ) {
+        let snapshots: Vec<_> = snapshots
+            .into_iter()
+            .filter_map(|(path, snapshot)| {
+                Some(TimerSnapshot {
+                    component: self.component(path)?,
+                    ..snapshot
+                })
+            })
+            .collect();
+        timers.apply_snapshot(&snapshots);
+    }
+}
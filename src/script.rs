@@ -0,0 +1,120 @@
+//! Provides [`ScriptTimerEvent`], a buffered, type-erased copy of the timer
+//! events for scripting integrations.
+
+use bevy_ecs::{
+    component::ComponentId,
+    entity::Entity,
+    event::{Event, EventRegistry, Events},
+    observer::Trigger,
+    system::{Res, ResMut},
+    world::World,
+};
+
+use crate::{
+    event::{OnTimerCancelled, OnTimerFinished, OnTimerStarted, OnTimersFinishedBatch},
+    settings::{FinishedEvents, TimerSettings},
+};
+
+/// Buffered [`Event`] mirroring the observer events of this crate, identifying
+/// timers by [`ComponentId`] rather than by tag type.
+///
+/// Observers need the tag type at compile time, which scripting layers with
+/// dynamically defined tags do not have. Such layers can instead read these
+/// events with an [`EventReader`], and dispatch them by [`ComponentId`].
+///
+/// The events are opt-in, since buffering them costs an observer run per
+/// timer event. Enable them with [`ScriptTimerEvent::register`]. Outside of
+/// a Bevy app, [`Events::update`] must be called manually to drop old events.
+///
+/// [`EventReader`]: bevy_ecs::event::EventReader
+#[cfg_attr(
+    feature = "bevy_app",
+    doc = "",
+    doc = "With the `bevy_app` feature, they can also be enabled with",
+    doc = "[`ScheduleTimerTickPlugin::with_script_events`].",
+    doc = "",
+    doc = "[`ScheduleTimerTickPlugin::with_script_events`]: crate::plugin::ScheduleTimerTickPlugin::with_script_events"
+)]
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScriptTimerEvent {
+    /// The entity owning the timer.
+    pub entity: Entity,
+    /// The [`ComponentId`] of the tag identifying the timer.
+    pub component: ComponentId,
+    /// What happened to the timer.
+    pub kind: ScriptTimerEventKind,
+}
+
+/// What happened to the timer of a [`ScriptTimerEvent`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScriptTimerEventKind {
+    /// The timer was started, mirroring [`OnTimerStarted`].
+    Started,
+    /// The timer finished, mirroring [`OnTimerFinished`]. Written once per
+    /// finish, like [`OnTimerFinished`], or under [`FinishedEvents::Batched`],
+    /// once per timer listed in [`OnTimersFinishedBatch`].
+    Finished,
+    /// The timer was cancelled, mirroring [`OnTimerCancelled`].
+    Cancelled,
+}
+
+impl ScriptTimerEvent {
+    /// Registers the [`ScriptTimerEvent`] event in the world, along with the
+    /// observers that write it.
+    ///
+    /// Does nothing if the event is already registered.
+    pub fn register(world: &mut World) {
+        if world.contains_resource::<Events<ScriptTimerEvent>>() {
+            return;
+        }
+        EventRegistry::register_event::<ScriptTimerEvent>(world);
+        world.add_observer(
+            |t: Trigger<OnTimerStarted>, events: ResMut<Events<ScriptTimerEvent>>| {
+                write(&t, ScriptTimerEventKind::Started, events);
+            },
+        );
+        world.add_observer(
+            |t: Trigger<OnTimerFinished>, events: ResMut<Events<ScriptTimerEvent>>| {
+                write(&t, ScriptTimerEventKind::Finished, events);
+            },
+        );
+        world.add_observer(
+            |t: Trigger<OnTimersFinishedBatch>,
+             settings: Option<Res<TimerSettings>>,
+             mut events: ResMut<Events<ScriptTimerEvent>>| {
+                // With `Both`, the individual events were already written.
+                let finished_events = settings.map(|s| s.finished_events).unwrap_or_default();
+                if finished_events != FinishedEvents::Batched {
+                    return;
+                }
+                for &component in &t.event().components {
+                    events.send(ScriptTimerEvent {
+                        entity: t.entity(),
+                        component,
+                        kind: ScriptTimerEventKind::Finished,
+                    });
+                }
+            },
+        );
+        world.add_observer(
+            |t: Trigger<OnTimerCancelled>, events: ResMut<Events<ScriptTimerEvent>>| {
+                write(&t, ScriptTimerEventKind::Cancelled, events);
+            },
+        );
+    }
+}
+
+/// Writes a [`ScriptTimerEvent`] for each component targeted by the trigger.
+fn write<E: Event>(
+    trigger: &Trigger<E>,
+    kind: ScriptTimerEventKind,
+    mut events: ResMut<Events<ScriptTimerEvent>>,
+) {
+    for &component in trigger.components() {
+        events.send(ScriptTimerEvent {
+            entity: trigger.entity(),
+            component,
+            kind,
+        });
+    }
+}
@@ -0,0 +1,219 @@
+//! Global settings for this crate, stored in the [`TimerSettings`] resource.
+
+use core::time::Duration;
+
+use bevy_ecs::{entity::Entity, system::Resource};
+use bevy_time::TimerMode;
+use bevy_utils::tracing::warn;
+
+/// [`Resource`] holding the global settings of this crate.
+///
+/// When this resource is absent, the default settings are used.
+#[cfg_attr(
+    feature = "bevy_app",
+    doc = "With the `bevy_app` feature, it is inserted by the [`ScheduleTimerTickPlugin`],",
+    doc = "and can be configured through [`ScheduleTimerTickPlugin::with_settings`].",
+    doc = "",
+    doc = "[`ScheduleTimerTickPlugin`]: crate::plugin::ScheduleTimerTickPlugin",
+    doc = "[`ScheduleTimerTickPlugin::with_settings`]: crate::plugin::ScheduleTimerTickPlugin::with_settings"
+)]
+#[derive(Resource, Clone, Debug)]
+pub struct TimerSettings {
+    /// Which events are triggered when timers finish. Defaults to
+    /// [`FinishedEvents::Individual`].
+    pub finished_events: FinishedEvents,
+    /// The capacity of the [`Timers`] component inserted when a timer is
+    /// started on an entity that has none. Defaults to `0`, which defers
+    /// allocating until the first timer is inserted.
+    ///
+    /// Raise it when entities are known to hold many timers, to avoid
+    /// reallocating as timers are added.
+    ///
+    /// [`Timers`]: crate::core::Timers
+    pub default_capacity: usize,
+    /// Whether [`tick_entity_timers`] inserts and maintains a
+    /// [`TimerFinishedCount`] on every entity whose timers finish. Defaults to
+    /// `false`.
+    ///
+    /// [`tick_entity_timers`]: crate::core::tick_entity_timers
+    /// [`TimerFinishedCount`]: crate::core::TimerFinishedCount
+    pub count_finishes: bool,
+    /// The longest delta timers are ticked by at once. Defaults to `None`,
+    /// ticking by the whole frame delta.
+    ///
+    /// When set, [`tick_entity_timers`] splits larger deltas into substeps,
+    /// and triggers [`OnTimerFinished`] for each substep a timer finishes in,
+    /// instead of once for the whole frame. After a lag spike, a repeating
+    /// timer then fires once per substep rather than a single time. Every
+    /// substep iterates over all of an entity's timers again, so the cost of
+    /// a tick grows with the number of substeps. A zero duration disables
+    /// substeps.
+    ///
+    /// A tick takes at most [`Self::MAX_SUBSTEPS`] substeps, the last one
+    /// covering whatever delta is left, so that a huge delta after a lag spike
+    /// or from a scaled or boosted timer does not stall the tick.
+    ///
+    /// [`tick_entity_timers`]: crate::core::tick_entity_timers
+    /// [`OnTimerFinished`]: crate::event::OnTimerFinished
+    pub max_substep: Option<Duration>,
+    /// The number of entities per batch when [`tick_entity_timers`] ticks
+    /// entities in parallel. Defaults to `None`, which lets Bevy pick a batch
+    /// size from the number of entities and threads.
+    ///
+    /// The automatic size suits most apps. Servers ticking tens of thousands
+    /// of entities may gain throughput from a fixed size, in the thousands,
+    /// which lowers the overhead of spawning tasks. Only the parallel tick
+    /// systems are affected, not [`tick_entity_timers_ordered`].
+    ///
+    /// [`tick_entity_timers`]: crate::core::tick_entity_timers
+    /// [`tick_entity_timers_ordered`]: crate::core::tick_entity_timers_ordered
+    pub batch_size: Option<usize>,
+    /// The number of ticks finished one-shot timers are kept for before being
+    /// removed. Defaults to `0`, removing them as soon as they finish.
+    ///
+    /// With a delay of `n`, a one-shot timer finishing during a tick stays in
+    /// [`Timers`], with [`Timer::finished`] returning `true`, and is removed
+    /// at the start of the `n`-th following tick. Systems running after the
+    /// tick can then read finished timers instead of observing
+    /// [`OnTimerFinished`]. The tradeoff is that finished timers keep taking
+    /// up space, and are still counted by methods such as [`Timers::len`] and
+    /// [`Timers::min_remaining`] until they are removed. Restarting a timer
+    /// during its delay keeps it.
+    ///
+    /// [`Timers`]: crate::core::Timers
+    /// [`Timer::finished`]: bevy_time::Timer::finished
+    /// [`OnTimerFinished`]: crate::event::OnTimerFinished
+    /// [`Timers::len`]: crate::core::Timers::len
+    /// [`Timers::min_remaining`]: crate::core::Timers::min_remaining
+    pub one_shot_removal_delay: u32,
+    /// The most times [`OnTimerFinished`] is triggered for a single timer
+    /// during a tick. Defaults to [`Self::DEFAULT_MAX_FIRES_PER_TICK`].
+    ///
+    /// A repeating timer finishes `(elapsed + delta) / duration` times in a
+    /// tick, rounded down, and keeps the rest as its elapsed time, so it does
+    /// not drift. A 1ms timer ticked by 16ms thus finishes 16 times, and
+    /// [`OnTimerFinished`] is triggered once per finish, with
+    /// [`OnTimerFinished::repetitions_remaining`] counting down across the
+    /// triggers. Finishes past the cap are still counted in
+    /// [`TimersFinishedThisFrame`], but not triggered. Keep the cap finite: a
+    /// zero-duration repeating timer finishes [`u32::MAX`] times per tick.
+    /// Set it to `1` to trigger [`OnTimerFinished`] at most once per timer and
+    /// tick, and leave the count to [`TimersFinishedThisFrame`]. `0` behaves
+    /// as `1`. Finish counts and caps apply per substep, see
+    /// [`Self::max_substep`].
+    ///
+    /// [`OnTimerFinished`]: crate::event::OnTimerFinished
+    /// [`OnTimerFinished::repetitions_remaining`]: crate::event::OnTimerFinished::repetitions_remaining
+    /// [`TimersFinishedThisFrame`]: crate::core::TimersFinishedThisFrame
+    pub max_fires_per_tick: u32,
+    /// The mode of timers started without one, such as with
+    /// [`start_timer_secs`] given `None`. Defaults to [`TimerMode::Once`].
+    ///
+    /// Only read when the start command is applied, so changing it does not
+    /// affect running timers.
+    ///
+    /// [`start_timer_secs`]: crate::command::EntityCommandTimersExt::start_timer_secs
+    pub default_mode: TimerMode,
+    /// What start commands do when their entity does not exist, for example
+    /// because it was despawned before the commands were applied. Defaults
+    /// to [`MissingEntityPolicy::Silent`].
+    pub missing_entity_policy: MissingEntityPolicy,
+}
+
+impl TimerSettings {
+    /// The most substeps a tick is split into, see [`Self::max_substep`].
+    pub const MAX_SUBSTEPS: u32 = 64;
+
+    /// The default of [`Self::max_fires_per_tick`].
+    pub const DEFAULT_MAX_FIRES_PER_TICK: u32 = 64;
+}
+
+impl Default for TimerSettings {
+    fn default() -> Self {
+        Self {
+            finished_events: FinishedEvents::default(),
+            default_capacity: 0,
+            count_finishes: false,
+            max_substep: None,
+            batch_size: None,
+            one_shot_removal_delay: 0,
+            max_fires_per_tick: Self::DEFAULT_MAX_FIRES_PER_TICK,
+            default_mode: TimerMode::Once,
+            missing_entity_policy: MissingEntityPolicy::default(),
+        }
+    }
+}
+
+/// Selects which events [`tick_entity_timers`] triggers when timers finish.
+///
+/// [`tick_entity_timers`]: crate::core::tick_entity_timers
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FinishedEvents {
+    /// Trigger [`OnTimerFinished`] for each finished timer.
+    ///
+    /// [`OnTimerFinished`]: crate::event::OnTimerFinished
+    #[default]
+    Individual,
+    /// Trigger a single [`OnTimersFinishedBatch`] per entity, listing all of
+    /// its timers that finished during the tick.
+    ///
+    /// [`OnTimersFinishedBatch`]: crate::event::OnTimersFinishedBatch
+    Batched,
+    /// Trigger both. For each entity, the [`OnTimerFinished`] triggers run
+    /// first, followed by the [`OnTimersFinishedBatch`] trigger.
+    ///
+    /// [`OnTimerFinished`]: crate::event::OnTimerFinished
+    /// [`OnTimersFinishedBatch`]: crate::event::OnTimersFinishedBatch
+    Both,
+}
+
+impl FinishedEvents {
+    /// Returns `true` if [`OnTimerFinished`] is triggered for each timer.
+    ///
+    /// [`OnTimerFinished`]: crate::event::OnTimerFinished
+    pub fn individual(self) -> bool {
+        matches!(self, Self::Individual | Self::Both)
+    }
+
+    /// Returns `true` if [`OnTimersFinishedBatch`] is triggered per entity.
+    ///
+    /// [`OnTimersFinishedBatch`]: crate::event::OnTimersFinishedBatch
+    pub fn batched(self) -> bool {
+        matches!(self, Self::Batched | Self::Both)
+    }
+}
+
+/// What start commands, such as
+/// [`start_timer`](crate::command::EntityCommandTimersExt::start_timer), do
+/// when their entity does not exist, see
+/// [`TimerSettings::missing_entity_policy`].
+///
+/// The timer is never started. Bevy's own commands panic on missing
+/// entities, while this crate's are lenient by default, since entities are
+/// commonly despawned by an observer before the timers queued on them are
+/// started. Use [`MissingEntityPolicy::Panic`] during development to catch
+/// timers queued on entities that are already gone.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MissingEntityPolicy {
+    /// Ignore the command.
+    #[default]
+    Silent,
+    /// Log a warning and ignore the command.
+    Warn,
+    /// Panic.
+    Panic,
+}
+
+impl MissingEntityPolicy {
+    /// Reports that the timer tagged `tag` could not be started on the
+    /// missing `entity`.
+    pub(crate) fn report(self, entity: Entity, tag: &str) {
+        match self {
+            Self::Silent => {}
+            Self::Warn => warn!("not starting timer {tag} on {entity}: the entity does not exist"),
+            Self::Panic => {
+                panic!("could not start timer {tag} on {entity}: the entity does not exist")
+            }
+        }
+    }
+}
@@ -0,0 +1,77 @@
+//! [`TriggerTargets`] used when triggering this crate's [`Event`]s.
+//!
+//! [`Event`]: bevy_ecs::event::Event
+
+use bevy_ecs::{component::ComponentId, entity::Entity, observer::TriggerTargets};
+
+/// [`TriggerTargets`] that targets an entity and a [`Component`] at the same
+/// time.
+///
+/// This is how this crate triggers its events: observers watching the
+/// [`Component`] (such as `Trigger<OnTimerFinished, MyTag>`) and observers
+/// watching the entity both run, and [`Trigger::entity`] returns the targeted
+/// entity. Entity-scoped observers watching the [`Component`], added with
+/// [`EntityWorldMut::observe`], only run when the targeted entity is theirs.
+/// Third-party timer-like systems can use it to trigger events that observers
+/// written for this crate will pick up.
+///
+/// # Example
+///
+/// ```
+/// # use std::time::Duration;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_observed_timers::{prelude::*, targets::TargetBoth};
+/// # #[derive(Component)]
+/// # struct Cooldown;
+/// # #[derive(Resource, Default)]
+/// # struct Finished(Option<Entity>);
+/// # let mut world = World::new();
+/// # world.init_resource::<Finished>();
+/// world.add_observer(|t: Trigger<OnTimerFinished, Cooldown>, mut finished: ResMut<Finished>| {
+///     finished.0 = Some(t.entity());
+/// });
+///
+/// let e1 = world.spawn_empty().id();
+/// let cooldown = world.register_component::<Cooldown>();
+/// world.trigger_targets(
+///     OnTimerFinished {
+///         duration: Duration::from_secs(5),
+///         repetitions_remaining: Some(0),
+///     },
+///     TargetBoth::new(e1, cooldown),
+/// );
+/// # assert_eq!(world.resource::<Finished>().0, Some(e1));
+/// ```
+///
+/// [`Component`]: bevy_ecs::component::Component
+/// [`Trigger::entity`]: bevy_ecs::observer::Trigger::entity
+/// [`EntityWorldMut::observe`]: bevy_ecs::world::EntityWorldMut::observe
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TargetBoth(Entity, ComponentId);
+
+impl TargetBoth {
+    /// Creates a new target for the given entity and [`ComponentId`].
+    pub fn new(entity: Entity, component: ComponentId) -> Self {
+        Self(entity, component)
+    }
+
+    /// Returns the targeted entity.
+    pub fn entity(&self) -> Entity {
+        self.0
+    }
+
+    /// Returns the targeted [`ComponentId`].
+    pub fn component(&self) -> ComponentId {
+        self.1
+    }
+}
+
+impl TriggerTargets for TargetBoth {
+    fn components(&self) -> &[ComponentId] {
+        std::array::from_ref(&self.1)
+    }
+
+    fn entities(&self) -> &[Entity] {
+        std::array::from_ref(&self.0)
+    }
+}
@@ -0,0 +1,51 @@
+//! Utilities for testing code that uses timers.
+//!
+//! Requires the `test-util` feature, which is meant to be enabled only for
+//! tests, for example as a feature of the dev-dependency on this crate.
+
+use core::time::Duration;
+
+use bevy_ecs::world::World;
+use bevy_time::Time;
+
+use crate::core::tick_entity_timers;
+
+/// Advances the [`Time`] resource by `delta`, then runs
+/// [`tick_entity_timers`] and flushes commands, so that the events of every
+/// timer finishing during the tick have been triggered when it returns.
+///
+/// Inserts the [`Time`] resource if it is missing. This replaces the
+/// `advance_by` and `run_system_cached` boilerplate of tests driving timers
+/// manually; apps should schedule [`tick_entity_timers`] instead, for example
+/// with the [`ScheduleTimerTickPlugin`].
+///
+/// # Panics
+///
+/// Panics if [`tick_entity_timers`] cannot be run on the world.
+///
+/// # Example
+///
+/// ```
+/// # use std::time::Duration;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_observed_timers::{prelude::*, testing::advance_timers};
+/// # use bevy_time::prelude::*;
+/// # #[derive(Component)]
+/// # struct Cooldown;
+/// let mut world = World::new();
+/// let e1 = world.spawn_empty().id();
+/// world.commands().entity(e1)
+///     .start_timer::<Cooldown>(Timer::from_seconds(1., TimerMode::Once));
+///
+/// advance_timers(&mut world, Duration::from_secs(1));
+/// assert!(world.get::<Timers>(e1).unwrap().is_empty());
+/// ```
+///
+/// [`ScheduleTimerTickPlugin`]: crate::plugin::ScheduleTimerTickPlugin
+pub fn advance_timers(world: &mut World, delta: Duration) {
+    world.get_resource_or_init::<Time>().advance_by(delta);
+    world
+        .run_system_cached(tick_entity_timers)
+        .expect("failed to run tick_entity_timers");
+    world.flush();
+}
@@ -0,0 +1,36 @@
+//! Keeps the [`TimerIndex`], the [`HasTimers`] marker and the per-timer
+//! callbacks in sync with timers added or removed without triggering events.
+//!
+//! Timer events keep them up to date through observers. Code that inserts or
+//! removes timers without triggering events calls [`track`] or [`forget`]
+//! instead, so that no tracker is missed.
+//!
+//! [`TimerIndex`]: crate::index::TimerIndex
+//! [`HasTimers`]: crate::marker::HasTimers
+
+use bevy_ecs::{component::ComponentId, entity::Entity, world::World};
+
+use crate::{callback, index, marker};
+
+/// Records that the given timers of an entity were inserted, or moved to
+/// another tag, without triggering [`OnTimerStarted`].
+///
+/// [`OnTimerStarted`]: crate::event::OnTimerStarted
+pub(crate) fn track(world: &mut World, entity: Entity, components: &[ComponentId]) {
+    index::sync(world, entity, components);
+    marker::sync(world, entity);
+}
+
+/// Records that the given timers of an entity were removed without
+/// triggering [`OnTimerFinished`] or [`OnTimerCancelled`], and drops their
+/// callbacks.
+///
+/// [`OnTimerFinished`]: crate::event::OnTimerFinished
+/// [`OnTimerCancelled`]: crate::event::OnTimerCancelled
+pub(crate) fn forget(world: &mut World, entity: Entity, components: &[ComponentId]) {
+    for &component in components {
+        callback::remove(world, entity, component);
+    }
+    index::sync(world, entity, components);
+    marker::sync(world, entity);
+}
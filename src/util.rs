@@ -0,0 +1,42 @@
+//! Small helpers for displaying [`Timer`]s, such as cooldown text in a HUD.
+
+use bevy_time::Timer;
+
+/// Formats the remaining time of a [`Timer`] as minutes and seconds, such as
+/// `"1:03"`.
+///
+/// Seconds are rounded up, so the text only reads `"0:00"` once the timer has
+/// finished. Minutes are not wrapped into hours, so an hour and a half reads
+/// `"90:00"`.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_time::prelude::*;
+/// # use bevy_observed_timers::util::format_remaining;
+/// let timer = Timer::from_seconds(62.5, TimerMode::Once);
+/// assert_eq!(format_remaining(&timer), "1:03");
+/// ```
+pub fn format_remaining(timer: &Timer) -> String {
+    let secs = timer.remaining().as_nanos().div_ceil(1_000_000_000);
+    format!("{}:{:02}", secs / 60, secs % 60)
+}
+
+/// Formats the remaining time of a [`Timer`] in seconds with one decimal, such
+/// as `"1.3s"`.
+///
+/// Tenths of a second are rounded up, so the text only reads `"0.0s"` once
+/// the timer has finished.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_time::prelude::*;
+/// # use bevy_observed_timers::util::format_remaining_secs;
+/// let timer = Timer::from_seconds(1.25, TimerMode::Once);
+/// assert_eq!(format_remaining_secs(&timer), "1.3s");
+/// ```
+pub fn format_remaining_secs(timer: &Timer) -> String {
+    let tenths = timer.remaining().as_nanos().div_ceil(100_000_000);
+    format!("{}.{}s", tenths / 10, tenths % 10)
+}
@@ -0,0 +1,152 @@
+//! [`Future`]s that resolve when a [`Timer`] on an entity finishes.
+//!
+//! Requires the `async` feature.
+//!
+//! [`Timer`]: bevy_time::Timer
+
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll, Waker},
+};
+use std::sync::{Arc, Mutex};
+
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    observer::{Observer, Trigger},
+    system::Commands,
+    world::World,
+};
+
+use crate::{
+    core::Timers,
+    event::{OnTimerCancelled, OnTimerFinished},
+};
+
+/// The result of awaiting a [`TimerWait`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimerWaitResult {
+    /// The timer finished, and [`OnTimerFinished`] was triggered.
+    Finished,
+    /// The timer was cancelled, and [`OnTimerCancelled`] was triggered, or the
+    /// entity was despawned before the timer finished.
+    Cancelled,
+}
+
+impl Timers {
+    /// Returns a [`Future`] that resolves the next time the [`Timer`] tagged
+    /// with the [`Component`] `T` finishes on the given entity.
+    ///
+    /// The future is backed by a pair of entity-scoped observers, which are
+    /// spawned when the queued command is applied and despawned once the
+    /// future is resolved. It resolves with:
+    /// - [`TimerWaitResult::Finished`] when [`OnTimerFinished`] is triggered,
+    /// - [`TimerWaitResult::Cancelled`] when [`OnTimerCancelled`] is triggered,
+    ///   or when the entity does not exist or is despawned first.
+    ///
+    /// If no timer with the tag is ever started on the entity, the future
+    /// never resolves.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_observed_timers::prelude::*;
+    /// # use bevy_time::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Reload;
+    /// # let mut world = World::new();
+    /// # let mut commands = world.commands();
+    /// # let e1 = commands.spawn_empty().id();
+    /// commands.entity(e1)
+    ///     .start_timer::<Reload>(Timer::from_seconds(2., TimerMode::Once));
+    /// let reloaded = Timers::wait_for::<Reload>(&mut commands, e1);
+    /// // Later, in async code: `reloaded.await`.
+    /// ```
+    ///
+    /// [`Timer`]: bevy_time::Timer
+    pub fn wait_for<T: Component>(commands: &mut Commands, entity: Entity) -> TimerWait {
+        let shared = Arc::new(Mutex::new(WaitState::default()));
+        let completer = Completer(shared.clone());
+        commands.queue(move |world: &mut World| {
+            if world.get_entity(entity).is_err() {
+                // Dropping the completer resolves the future as cancelled.
+                return;
+            }
+
+            let finished = world.spawn_empty().id();
+            let cancelled = world.spawn_empty().id();
+            let on_cancel = Completer(completer.0.clone());
+
+            world.entity_mut(finished).insert(
+                Observer::new(
+                    move |_: Trigger<OnTimerFinished, T>, mut commands: Commands| {
+                        completer.complete(TimerWaitResult::Finished);
+                        commands.entity(finished).despawn();
+                        commands.entity(cancelled).despawn();
+                    },
+                )
+                .with_entity(entity),
+            );
+            world.entity_mut(cancelled).insert(
+                Observer::new(
+                    move |_: Trigger<OnTimerCancelled, T>, mut commands: Commands| {
+                        on_cancel.complete(TimerWaitResult::Cancelled);
+                        commands.entity(finished).despawn();
+                        commands.entity(cancelled).despawn();
+                    },
+                )
+                .with_entity(entity),
+            );
+        });
+        TimerWait(shared)
+    }
+}
+
+/// [`Future`] returned by [`Timers::wait_for`].
+pub struct TimerWait(Arc<Mutex<WaitState>>);
+
+impl Future for TimerWait {
+    type Output = TimerWaitResult;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.0.lock().unwrap();
+        match state.result {
+            Some(result) => Poll::Ready(result),
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+struct WaitState {
+    result: Option<TimerWaitResult>,
+    waker: Option<Waker>,
+}
+
+/// Resolves the shared [`WaitState`], at most once. Resolves as cancelled when
+/// dropped unresolved, which happens when the observers are despawned along
+/// with the watched entity.
+struct Completer(Arc<Mutex<WaitState>>);
+
+impl Completer {
+    fn complete(&self, result: TimerWaitResult) {
+        let mut state = self.0.lock().unwrap();
+        if state.result.is_none() {
+            state.result = Some(result);
+            if let Some(waker) = state.waker.take() {
+                waker.wake();
+            }
+        }
+    }
+}
+
+impl Drop for Completer {
+    fn drop(&mut self) {
+        self.complete(TimerWaitResult::Cancelled);
+    }
+}
@@ -0,0 +1,293 @@
+//! [`World`] extension methods for managing [`Timer`]s on entities
+//! immediately, instead of through [`Commands`].
+//!
+//! [`Timer`]: bevy_time::Timer
+//! [`Commands`]: bevy_ecs::system::Commands
+
+use core::time::Duration;
+
+use bevy_ecs::{
+    component::{Component, ComponentId},
+    entity::Entity,
+    observer::{Observer, Trigger},
+    system::{Commands, EntityCommand, IntoObserverSystem},
+    world::World,
+};
+use bevy_time::Timer;
+
+use crate::{
+    callback,
+    command::{cancel_timer, watch_gate, watch_link, StartTimer},
+    core::{tick_single, ConflictPolicy, Timers},
+    event::OnTimerFinished,
+    tracking,
+};
+
+/// [`World`] extension trait that provides methods for managing timers on
+/// entities immediately.
+pub trait WorldTimersExt {
+    /// Rewinds every [`Timer`] on the given entity by the given [`Duration`].
+    ///
+    /// Each timer's elapsed time is reduced by `by`, clamped at zero, and its
+    /// finished state is cleared. Finish events are never triggered while
+    /// rewinding. If the entity does not exist or has no timers, this does
+    /// nothing.
+    ///
+    /// See [`Timers::rewind`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_observed_timers::prelude::*;
+    /// # let mut world = World::new();
+    /// # let e1 = world.spawn_empty().id();
+    /// world.rewind_timers(e1, Duration::from_secs(2));
+    /// ```
+    ///
+    /// [`Timer`]: bevy_time::Timer
+    fn rewind_timers(&mut self, entity: Entity, by: Duration);
+
+    /// Adds an observer that runs the first time a [`Timer`] tagged with the
+    /// [`Component`] `T` finishes on the given entity, and then removes
+    /// itself.
+    ///
+    /// This only works with entity-scoped observers: finishes of `T` on other
+    /// entities are ignored. The observer is removed after its first run, even
+    /// if the timer keeps repeating, and along with the entity if it is
+    /// despawned first.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_observed_timers::prelude::*;
+    /// # use bevy_time::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Intro;
+    /// # let mut world = World::new();
+    /// let e1 = world.spawn_empty().id();
+    /// world.commands().entity(e1)
+    ///     .start_timer::<Intro>(Timer::from_seconds(3., TimerMode::Once));
+    /// world.observe_timer_once::<Intro, _>(e1, |t: Trigger<OnTimerFinished, Intro>| {
+    ///     println!("intro over for {}", t.entity());
+    /// });
+    /// ```
+    ///
+    /// [`Timer`]: bevy_time::Timer
+    fn observe_timer_once<T: Component, M>(
+        &mut self,
+        entity: Entity,
+        observer: impl IntoObserverSystem<OnTimerFinished, T, M>,
+    );
+
+    /// Ticks only the [`Timer`] tagged with the [`Component`] `T` on the given
+    /// entity by `delta`, leaving its other timers untouched.
+    ///
+    /// The timer is ticked like [`tick_entity_timers`] would: it triggers
+    /// [`OnTimerFinished`] and the other events, and one-shot timers are
+    /// removed when they finish. This bypasses the global tick's clock: the
+    /// [`Time`] resource and [`TimersPaused`] are ignored, and the finish is
+    /// not listed in [`TimersFinishedThisFrame`]. The timer's own pause and
+    /// gate are still respected. If the entity does not exist or has no such
+    /// timer, this does nothing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_observed_timers::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Poison;
+    /// # let mut world = World::new();
+    /// # let e1 = world.spawn_empty().id();
+    /// // Fast-forward the poison, but not the other effects.
+    /// world.tick_timer::<Poison>(e1, Duration::from_secs(5));
+    /// ```
+    ///
+    /// [`Timer`]: bevy_time::Timer
+    /// [`tick_entity_timers`]: crate::core::tick_entity_timers
+    /// [`Time`]: bevy_time::Time
+    /// [`TimersPaused`]: crate::core::TimersPaused
+    /// [`TimersFinishedThisFrame`]: crate::core::TimersFinishedThisFrame
+    fn tick_timer<T: Component>(&mut self, entity: Entity, delta: Duration);
+
+    /// Starts a [`Timer`] on the given entity immediately, and returns the
+    /// key identifying it. The [`Component`] `T` is used as a tag to identify
+    /// the timer.
+    ///
+    /// Behaves like [`EntityCommandTimersExt::start_timer`], without waiting
+    /// for commands to be applied. The key is the [`ComponentId`] of `T` in
+    /// this world, so it identifies the timer tagged with `T` on any entity
+    /// of the world, and can be passed to methods such as
+    /// [`Timers::get`] or [`cancel_timer_by_id`](Self::cancel_timer_by_id)
+    /// without naming `T` again. Starting another timer with the same tag
+    /// returns the same key, as it replaces the timer. If the entity does not
+    /// exist, no timer is started, but the key is still returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_observed_timers::prelude::*;
+    /// # use bevy_time::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Reload;
+    /// # let mut world = World::new();
+    /// # let e1 = world.spawn_empty().id();
+    /// let key = world.start_timer::<Reload>(e1, Timer::from_seconds(2., TimerMode::Once));
+    /// // ...
+    /// world.cancel_timer_by_id(e1, key);
+    /// ```
+    ///
+    /// [`Timer`]: bevy_time::Timer
+    /// [`EntityCommandTimersExt::start_timer`]: crate::command::EntityCommandTimersExt::start_timer
+    fn start_timer<T: Component>(&mut self, entity: Entity, timer: Timer) -> ComponentId;
+
+    /// Cancels the [`Timer`] identified by the given [`ComponentId`] on the
+    /// given entity immediately, triggering [`OnTimerCancelled`], and returns
+    /// `true` if there was such a timer.
+    ///
+    /// See [`start_timer`](Self::start_timer) for obtaining the key, and
+    /// [`EntityCommandTimersExt::cancel_timer`] for the deferred variant.
+    ///
+    /// [`Timer`]: bevy_time::Timer
+    /// [`OnTimerCancelled`]: crate::event::OnTimerCancelled
+    /// [`EntityCommandTimersExt::cancel_timer`]: crate::command::EntityCommandTimersExt::cancel_timer
+    fn cancel_timer_by_id(&mut self, entity: Entity, component: ComponentId) -> bool;
+
+    /// Moves every timer identified by `old` to the [`ComponentId`] `new`, on
+    /// every entity, and returns the number of entities whose timers moved.
+    ///
+    /// A [`Component`] type is registered once per world, so the id of a
+    /// Rust type never changes. Ids only change for components registered
+    /// dynamically, with [`World::register_component_with_descriptor`], such
+    /// as script-defined components re-registered on hot reload: timers keyed
+    /// by the previous id are orphaned, as observers watch the new one.
+    ///
+    /// Timers keep their state and callbacks, and the [`TimerIndex`] is
+    /// updated, but no events are triggered. Timers gated on `old`, or linked
+    /// to it, react to `new` afterwards. See [`Timers::remap_id`].
+    ///
+    /// [`TimerIndex`]: crate::index::TimerIndex
+    fn remap_timer_ids(&mut self, old: ComponentId, new: ComponentId) -> usize;
+
+    /// Moves every [`Timer`] of the entity `from` to the entity `into`,
+    /// resolving timers with the same tag with the given [`ConflictPolicy`].
+    /// The [`Timers`] component is removed from `from`.
+    ///
+    /// See [`Timers::merge`]. Unlike it, the callbacks of the timers move
+    /// with them, and the [`TimerIndex`] and [`HasTimers`] are updated. No
+    /// events are triggered. If either entity does not exist, or they are the
+    /// same entity, this does nothing.
+    ///
+    /// [`Timer`]: bevy_time::Timer
+    /// [`TimerIndex`]: crate::index::TimerIndex
+    /// [`HasTimers`]: crate::marker::HasTimers
+    fn merge_timers(&mut self, from: Entity, into: Entity, on_conflict: ConflictPolicy);
+}
+
+impl WorldTimersExt for World {
+    fn rewind_timers(&mut self, entity: Entity, by: Duration) {
+        let Some(mut timers) = self.get_mut::<Timers>(entity) else {
+            return;
+        };
+        timers.rewind(by);
+    }
+
+    fn observe_timer_once<T: Component, M>(
+        &mut self,
+        entity: Entity,
+        observer: impl IntoObserverSystem<OnTimerFinished, T, M>,
+    ) {
+        let observer = self.spawn(Observer::new(observer).with_entity(entity)).id();
+        // Despawning is deferred until every observer of the trigger ran, so
+        // the order of the two observers does not matter.
+        self.spawn(
+            Observer::new(
+                move |t: Trigger<OnTimerFinished, T>, mut commands: Commands| {
+                    commands.entity(observer).despawn();
+                    commands.entity(t.observer()).despawn();
+                },
+            )
+            .with_entity(entity),
+        );
+    }
+
+    fn tick_timer<T: Component>(&mut self, entity: Entity, delta: Duration) {
+        let component = self.register_component::<T>();
+        tick_single(self, entity, component, delta);
+    }
+
+    fn start_timer<T: Component>(&mut self, entity: Entity, timer: Timer) -> ComponentId {
+        StartTimer::<T>::new(timer).apply(entity, self);
+        self.flush();
+        self.register_component::<T>()
+    }
+
+    fn cancel_timer_by_id(&mut self, entity: Entity, component: ComponentId) -> bool {
+        let cancelled = cancel_timer(self, entity, component);
+        self.flush();
+        cancelled
+    }
+
+    fn remap_timer_ids(&mut self, old: ComponentId, new: ComponentId) -> usize {
+        let mut remapped = Vec::new();
+        let (mut gated, mut linked) = (false, false);
+        for (entity, mut timers) in self.query::<(Entity, &mut Timers)>().iter_mut(self) {
+            let had_gate = timers.has_gate(old);
+            if timers.remap_id(old, new) {
+                remapped.push(entity);
+                linked |= timers.is_linked(new) == Some(true);
+            }
+            gated |= had_gate;
+        }
+        // Gated and linked timers now react to `new` rather than `old`.
+        if gated {
+            watch_gate(self, new);
+        }
+        if linked {
+            watch_link(self, new);
+        }
+        for &entity in &remapped {
+            callback::transfer(self, (entity, old), (entity, new));
+            tracking::track(self, entity, &[old, new]);
+        }
+        remapped.len()
+    }
+
+    fn merge_timers(&mut self, from: Entity, into: Entity, on_conflict: ConflictPolicy) {
+        if from == into || self.get_entity(into).is_err() {
+            return;
+        }
+        let Some(other) = self
+            .get_entity_mut(from)
+            .ok()
+            .and_then(|mut emut| emut.take::<Timers>())
+        else {
+            return;
+        };
+        let components: Vec<ComponentId> = other.iter().map(|(&component, _)| component).collect();
+        let mut emut = self.entity_mut(into);
+        if !emut.contains::<Timers>() {
+            emut.insert(Timers::default());
+        }
+        let taken = emut
+            .get_mut::<Timers>()
+            .unwrap()
+            .merge_taken(other, on_conflict);
+
+        let mut dropped = Vec::new();
+        for &component in &components {
+            if taken.contains(&component) {
+                callback::transfer(self, (from, component), (into, component));
+            } else {
+                dropped.push(component);
+            }
+        }
+        tracking::forget(self, from, &dropped);
+        tracking::track(self, into, &components);
+    }
+}